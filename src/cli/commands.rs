@@ -31,5 +31,22 @@ pub enum Commands {
         /// Generate HTML documentation
         #[arg(long, value_name = "DIR")]
         html: Option<PathBuf>,
+
+        /// Write the documentation model as JSON, following the
+        /// GraphArch model JSON Schema
+        #[arg(long, value_name = "FILE")]
+        json: Option<PathBuf>,
+
+        /// Show the source file and line of each section and chapter,
+        /// like "Source: ontology/core.ttl:120"
+        #[arg(long)]
+        sources: bool,
+    },
+    /// Start an interactive SPARQL shell over the loaded data
+    Repl {
+        /// The file to keep the input history in, defaults to
+        /// '~/.grapharch_history'
+        #[arg(long, value_name = "FILE")]
+        history: Option<PathBuf>,
     },
 }
@@ -14,6 +14,16 @@ pub struct Cli {
     #[arg(short, long, value_name = "FILE")]
     pub config: Option<PathBuf>,
 
+    /// The languages to document in, in order of preference, like
+    /// `en,nl`, instead of the languages in the config file
+    #[arg(short, long, value_name = "LANGUAGE", value_delimiter = ',')]
+    pub language: Vec<String>,
+
+    /// Read the documentation model from the given JSON file (see
+    /// `generate --json`) instead of documenting the sources
+    #[arg(short, long, value_name = "FILE")]
+    pub model: Option<PathBuf>,
+
     /// Turn debugging information on
     #[arg(short, long, action = clap::ArgAction::Count)]
     pub debug: u8,
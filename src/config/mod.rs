@@ -0,0 +1,3 @@
+mod this;
+
+pub use this::{BookConfig, Config};
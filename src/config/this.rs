@@ -0,0 +1,116 @@
+use {crate::util::Languages, serde::Deserialize, std::path::Path};
+
+/// The configuration of GraphArch, read from the YAML file that is
+/// given with `--config`, like:
+///
+/// ```yaml
+/// books:
+///   - id: core
+///     title: Core Ontologies
+///     include:
+///       - https://example.com/ontology/core
+///       - https://example.com/ontology/extension
+/// languages: [en, nl, fr]
+/// book_per_language: true
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// The books to generate from the books of the documentors, the
+    /// books that are not included by any of them are left as they are
+    #[serde(default)]
+    pub books:             Vec<BookConfig>,
+    /// The languages to document in, in order of preference, see
+    /// `Languages`
+    #[serde(default)]
+    pub languages:         Vec<String>,
+    /// Whether to generate one book per language instead of one book
+    /// in the first available language of each text
+    #[serde(default)]
+    pub book_per_language: bool,
+}
+
+/// A book that merges the books of the documentors with the given keys
+/// into one book, see `Book::merge_books`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BookConfig {
+    /// The key of the book, which is also the key of the merged book
+    pub id:       String,
+    /// The title of the book, the title of the first of the merged
+    /// books if not set
+    pub title:    Option<String>,
+    pub subtitle: Option<String>,
+    /// The keys of the books that go into this book, like the IRI of
+    /// an ontology or the path of a Markdown file. The key `*` includes
+    /// all books that no other book includes.
+    #[serde(default)]
+    pub include:  Vec<String>,
+}
+
+impl Config {
+    /// Reads the configuration from the given YAML file.
+    pub fn from_file(path: &Path) -> anyhow::Result<Self> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            anyhow::anyhow!("Could not read config {}: {}", path.display(), e)
+        })?;
+        Self::from_yaml(&content).map_err(|e| {
+            anyhow::anyhow!("Invalid config {}: {}", path.display(), e)
+        })
+    }
+
+    pub fn from_yaml(content: &str) -> anyhow::Result<Self> {
+        let config: Self = serde_yaml::from_str(content)?;
+        let mut ids = std::collections::HashSet::new();
+        for book in &config.books {
+            if !ids.insert(book.id.as_str()) {
+                return Err(anyhow::anyhow!("Duplicate book id: {}", book.id));
+            }
+        }
+        Ok(config)
+    }
+
+    /// Returns the languages to document in: the given languages, like
+    /// the ones given on the command line, or otherwise the configured
+    /// languages.
+    pub fn languages(&self, languages: &[String]) -> Languages {
+        let preferred = if languages.is_empty() {
+            &self.languages
+        } else {
+            languages
+        };
+        Languages::new(preferred.iter().cloned())
+            .with_book_per_language(self.book_per_language)
+    }
+
+    /// Reads the configuration from the given file, or returns the
+    /// default configuration if no file is given.
+    pub fn load(path: Option<&Path>) -> anyhow::Result<Self> {
+        match path {
+            Some(path) => Self::from_file(path),
+            None => Ok(Self::default()),
+        }
+    }
+}
+
+impl BookConfig {
+    /// Returns the configured book that the book with the given key
+    /// goes into, if any: the book with the key as its id, otherwise
+    /// the book that includes the key and otherwise the book that
+    /// includes `*`.
+    pub fn find<'a>(books: &'a [BookConfig], key: &str) -> Option<&'a Self> {
+        books
+            .iter()
+            .find(|book| book.id == key)
+            .or_else(|| {
+                books
+                    .iter()
+                    .find(|book| book.include.iter().any(|k| k == key))
+            })
+            .or_else(|| {
+                books
+                    .iter()
+                    .find(|book| book.include.iter().any(|k| k == "*"))
+            })
+    }
+}
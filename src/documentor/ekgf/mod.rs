@@ -0,0 +1,3 @@
+mod use_case_documentor;
+
+pub use use_case_documentor::EKGFUseCaseDocumentorImpl;
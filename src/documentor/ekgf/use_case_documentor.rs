@@ -0,0 +1,324 @@
+use {
+    crate::{
+        documentor::{
+            Documentor,
+            DocumentorCreator,
+            render::{identity_definitions, no_description},
+            source_locator::SourceLocator,
+        },
+        model::{
+            BlockContent,
+            Book,
+            Buildable,
+            Chapter,
+            Element,
+            Inline,
+            Model,
+            Section,
+        },
+        source::FileSourceImplementor,
+        store::{
+            EKGFDescription,
+            EKGFKind,
+            EKGFThing,
+            LoaderStore,
+            PrefixRegistry,
+        },
+        util::{FileType, FileTypeSliceStatic},
+    },
+    async_trait::async_trait,
+    std::{
+        collections::BTreeMap,
+        path::{Path, PathBuf},
+        sync::{Arc, LazyLock},
+    },
+    tracing::info,
+};
+
+static EKGF_USE_CASE_DOCUMENTOR_FILE_TYPES: LazyLock<FileTypeSliceStatic> =
+    LazyLock::new(|| {
+        let file_types = vec![
+            &FileType::RdfXml,
+            &FileType::NTriples,
+            &FileType::JSONLD,
+            &FileType::Turtle,
+            &FileType::NQuads,
+            &FileType::N3,
+            &FileType::TriG,
+        ];
+        Box::leak(Box::new(file_types))
+    });
+
+/// A documentor for EKGF use cases.
+///
+/// Adds a use case book with a section per kind of thing of the EKGF
+/// use case ontologies (use cases, personas, outcomes, outcome
+/// stereotypes, stories and workflow definitions) and a chapter per
+/// thing. Each chapter lists the things that it is related to, such
+/// as the persona, outcome and input and output concepts of a story or
+/// the stories of a workflow definition, referring to their chapters.
+#[derive(Debug, Clone)]
+pub struct EKGFUseCaseDocumentorImpl {
+    file_source:  Option<FileSourceImplementor>,
+    file_name:    Option<PathBuf>,
+    loader_store: LoaderStore,
+    /// The given target documentation model that the
+    /// EKGFUseCaseDocumentor will add its documentation to.
+    doc_model:    Arc<Model>,
+}
+
+impl DocumentorCreator for EKGFUseCaseDocumentorImpl {
+    fn new(
+        file_source: Option<FileSourceImplementor>,
+        file_name: Option<&Path>,
+        loader_store: LoaderStore,
+        doc_model: Arc<Model>,
+    ) -> Self {
+        Self {
+            file_source,
+            file_name: file_name.map(|f| f.to_path_buf()),
+            loader_store,
+            doc_model,
+        }
+    }
+}
+
+#[async_trait]
+impl Documentor for EKGFUseCaseDocumentorImpl {
+    fn file_types(&self) -> FileTypeSliceStatic {
+        *EKGF_USE_CASE_DOCUMENTOR_FILE_TYPES
+    }
+
+    async fn generate(&self) -> anyhow::Result<()> {
+        let graph = match &self.file_name {
+            Some(file_name) => self.loader_store.graph_for_file(file_name)?,
+            None => None,
+        };
+        let mut things: BTreeMap<EKGFKind, Vec<EKGFThing>> = BTreeMap::new();
+        for kind in EKGFKind::ALL {
+            let found =
+                self.loader_store.find_ekgf_things(kind, graph.as_ref())?;
+            info!("Found {} {}", found.len(), kind.section_title());
+            if !found.is_empty() {
+                things.insert(kind, found);
+            }
+        }
+        if things.is_empty() {
+            return Ok(());
+        }
+        let prefixes = self.loader_store.prefix_registry()?;
+        let locator =
+            SourceLocator::new(&self.loader_store, self.file_source.as_ref());
+
+        // Name the book after the use case if there is only one
+        let title =
+            match things.get(&EKGFKind::UseCase).map(|u| u.as_slice()) {
+                Some([use_case]) => use_case.label.clone(),
+                _ => None,
+            }
+            .or_else(|| {
+                self.file_name
+                    .as_ref()
+                    .and_then(|f| f.file_stem())
+                    .and_then(|s| s.to_str())
+                    .map(|s| format!("{} Use Cases", s))
+            })
+            .unwrap_or_else(|| "Use Cases".to_string());
+        let book = Book::builder_in_model::<Book>(&self.doc_model)?
+            .key(
+                graph
+                    .as_ref()
+                    .map(|graph| format!("use-cases:{}", graph.as_str())),
+            )
+            .title(Some(title))
+            .author(Some("GraphArch".to_string()))
+            .build()?;
+
+        // Every thing in the book by IRI, to refer to its chapter
+        let known: BTreeMap<&str, &EKGFThing> = things
+            .values()
+            .flatten()
+            .map(|thing| (thing.iri.as_str(), thing))
+            .collect();
+
+        for (kind, things_of_kind) in &things {
+            let section =
+                Section::builder_in_model::<Section>(&self.doc_model)?
+                    .title(Some(kind.section_title().to_string()))
+                    .description(Some(format!(
+                        "This section contains documentation for all {}.",
+                        kind.section_title().to_lowercase()
+                    )))
+                    .book(Some(&book))
+                    .build()?;
+            for thing in things_of_kind {
+                info!("Documenting {} {}", kind.name(), thing.iri);
+                let description =
+                    self.loader_store.describe_ekgf_thing(thing)?;
+                Chapter::builder_in_model::<Chapter>(&self.doc_model)?
+                    .title(Some(thing_title(thing, &prefixes)))
+                    .provenance(Some(locator.locate(&thing.iri)?))
+                    .blocks(Self::content(&description, &known, &prefixes))
+                    .section(Some(&section))
+                    .build()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn file_name(&self) -> Option<&Path> { self.file_name.as_deref() }
+}
+
+impl EKGFUseCaseDocumentorImpl {
+    fn content(
+        description: &EKGFDescription,
+        known: &BTreeMap<&str, &EKGFThing>,
+        prefixes: &PrefixRegistry,
+    ) -> Vec<BlockContent> {
+        let thing = &description.thing;
+        let mut blocks = vec![BlockContent::DefinitionList(
+            identity_definitions(&thing.iri, prefixes),
+        )];
+        blocks.push(match &thing.description {
+            Some(text) => BlockContent::paragraph(text.clone()),
+            None => no_description("No description available."),
+        });
+        for relation in &description.relations {
+            if relation.iris.is_empty() {
+                continue;
+            }
+            blocks.push(BlockContent::heading(relation.heading));
+            blocks.push(BlockContent::List {
+                numbered: false,
+                items:    relation
+                    .iris
+                    .iter()
+                    .map(|iri| {
+                        match known.get(iri.as_str()) {
+                            Some(related) => {
+                                vec![
+                                    Inline::text(format!(
+                                        "{} ",
+                                        related.kind.name()
+                                    )),
+                                    Inline::reference(
+                                        iri,
+                                        thing_title(related, prefixes),
+                                    ),
+                                    Inline::text(format!(
+                                        " ({})",
+                                        prefixes.curie_or_iri(iri)
+                                    )),
+                                ]
+                            },
+                            None => {
+                                vec![Inline::text(prefixes.curie_or_iri(iri))]
+                            },
+                        }
+                    })
+                    .collect(),
+            });
+        }
+        blocks
+    }
+}
+
+/// Returns the label of the thing, or otherwise its CURIE.
+fn thing_title(thing: &EKGFThing, prefixes: &PrefixRegistry) -> String {
+    thing
+        .label
+        .clone()
+        .unwrap_or_else(|| prefixes.curie_or_iri(&thing.iri))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USE_CASES: &str = r#"
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+        @prefix use-case: <https://ekgf.org/ontology/use-case/> .
+        @prefix persona: <https://ekgf.org/ontology/persona/> .
+        @prefix user-story: <https://ekgf.org/ontology/user-story/> .
+        @prefix ex: <http://example.com/> .
+
+        ex:onboarding a use-case:UseCase ; rdfs:label "Client onboarding" .
+        ex:analyst a persona:Persona ; rdfs:label "Analyst" .
+        ex:review a user-story:UserStory ;
+            rdfs:label "Review the client" ;
+            user-story:isPartOf ex:onboarding ;
+            user-story:hasPersona ex:analyst ;
+            user-story:hasInput ex:Client .
+    "#;
+
+    #[tokio::test]
+    async fn test_use_case_book() -> anyhow::Result<()> {
+        let loader_store = LoaderStore::new_in_memory()?;
+        loader_store.load_turtle("urn:test:use-cases", USE_CASES)?;
+        let model = Arc::new(Model::new()?);
+        EKGFUseCaseDocumentorImpl::new(None, None, loader_store, model.clone())
+            .generate()
+            .await?;
+
+        // The book is named after its only use case
+        let books = Book::get_books(model.clone())?;
+        assert_eq!(books.len(), 1);
+        assert_eq!(
+            books[0].title.as_deref(),
+            Some("Client onboarding")
+        );
+        let mut tree = Vec::new();
+        for section in books[0].get_sections()? {
+            let chapters: Vec<String> = section
+                .get_chapters()?
+                .into_iter()
+                .filter_map(|chapter| chapter.title)
+                .collect();
+            tree.push((section.title.unwrap_or_default(), chapters));
+        }
+        assert_eq!(tree, [
+            ("Use Cases".to_string(), vec![
+                "Client onboarding".to_string()
+            ]),
+            ("Personas".to_string(), vec![
+                "Analyst".to_string()
+            ]),
+            ("Stories".to_string(), vec![
+                "Review the client".to_string()
+            ]),
+        ]);
+
+        // The story refers to the chapters of the things in the book,
+        // and lists other related things by IRI as there is no prefix
+        // for them
+        let story = books[0].get_sections()?[2].get_chapters()?.remove(0);
+        let lists: Vec<Vec<Vec<Inline>>> = story
+            .get_blocks()?
+            .into_iter()
+            .filter_map(|block| {
+                match block.content {
+                    BlockContent::List { items, .. } => Some(items),
+                    _ => None,
+                }
+            })
+            .collect();
+        assert_eq!(lists, [
+            vec![vec![
+                Inline::text("Use Case "),
+                Inline::reference(
+                    "http://example.com/onboarding",
+                    "Client onboarding"
+                ),
+                Inline::text(" (http://example.com/onboarding)"),
+            ]],
+            vec![vec![
+                Inline::text("Persona "),
+                Inline::reference("http://example.com/analyst", "Analyst"),
+                Inline::text(" (http://example.com/analyst)"),
+            ]],
+            vec![vec![Inline::text("http://example.com/Client")]],
+        ]);
+        Ok(())
+    }
+}
@@ -0,0 +1,214 @@
+use {
+    crate::{
+        documentor::{Documentor, DocumentorCreator},
+        model::{
+            BlockContent,
+            Book,
+            Buildable,
+            Chapter,
+            Definition,
+            Element,
+            Inline,
+            Model,
+            Section,
+        },
+        source::FileSourceImplementor,
+        store::{DeclaredLicense, LicenseFile, LoaderStore},
+        util::{FileType, FileTypeSliceStatic, SPDXLicense},
+    },
+    async_trait::async_trait,
+    std::{
+        path::{Path, PathBuf},
+        sync::{Arc, LazyLock},
+    },
+    tracing::{info, warn},
+};
+
+static LICENSE_DOCUMENTOR_FILE_TYPES: LazyLock<FileTypeSliceStatic> =
+    LazyLock::new(|| Box::leak(Box::new(vec![&FileType::License])));
+
+/// A documentor for the licenses of the sources.
+///
+/// Adds a "License" section to each book, once all other documentors
+/// created their books, with a chapter per license file (such as
+/// `LICENSE` or `COPYING`) and a chapter with the licenses that are
+/// declared with `dcterms:license` or `cc:license`. Licenses are
+/// identified by their SPDX license ID. A declared license that is not
+/// the license of the license file(s) is reported as a conflict.
+#[derive(Debug, Clone)]
+pub struct LicenseDocumentorImpl {
+    #[allow(unused)]
+    file_source:  Option<FileSourceImplementor>,
+    file_name:    Option<PathBuf>,
+    loader_store: LoaderStore,
+    /// The given target documentation model that the
+    /// LicenseDocumentor will add its documentation to.
+    doc_model:    Arc<Model>,
+}
+
+impl DocumentorCreator for LicenseDocumentorImpl {
+    fn new(
+        file_source: Option<FileSourceImplementor>,
+        file_name: Option<&Path>,
+        loader_store: LoaderStore,
+        doc_model: Arc<Model>,
+    ) -> Self {
+        Self {
+            file_source,
+            file_name: file_name.map(|f| f.to_path_buf()),
+            loader_store,
+            doc_model,
+        }
+    }
+}
+
+#[async_trait]
+impl Documentor for LicenseDocumentorImpl {
+    fn file_types(&self) -> FileTypeSliceStatic {
+        *LICENSE_DOCUMENTOR_FILE_TYPES
+    }
+
+    fn file_name(&self) -> Option<&Path> { self.file_name.as_deref() }
+
+    fn finishes_books(&self) -> bool { true }
+
+    async fn generate(&self) -> anyhow::Result<()> {
+        let files = self.loader_store.find_license_files()?;
+        let declared = self.loader_store.find_declared_licenses()?;
+        info!(
+            "Found {} license files and {} declared licenses",
+            files.len(),
+            declared.len()
+        );
+        if files.is_empty() && declared.is_empty() {
+            return Ok(());
+        }
+
+        // The licenses of the repository, as found in its license files
+        let mut repository_licenses: Vec<&SPDXLicense> = Vec::new();
+        for license in files.iter().filter_map(|file| file.license) {
+            if !repository_licenses.contains(&license) {
+                repository_licenses.push(license);
+            }
+        }
+        let conflicts: Vec<&DeclaredLicense> = declared
+            .iter()
+            .filter(|declaration| {
+                declaration.license.is_some_and(|license| {
+                    !repository_licenses.is_empty() &&
+                        !repository_licenses.contains(&license)
+                })
+            })
+            .collect();
+        for conflict in &conflicts {
+            warn!(
+                "The declared license {} of {} in {} conflicts with the \
+                 license of the repository: {}",
+                conflict.reference,
+                conflict.subject,
+                conflict.file_name.as_deref().unwrap_or("unknown file"),
+                names(&repository_licenses)
+            );
+        }
+
+        for book in Book::get_books(self.doc_model.clone())? {
+            let section =
+                Section::builder_in_model::<Section>(&self.doc_model)?
+                    .title(Some("License".to_string()))
+                    .description(Some(Self::summary(&repository_licenses)))
+                    .book(Some(&book))
+                    .build()?;
+            for file in &files {
+                Chapter::builder_in_model::<Chapter>(&self.doc_model)?
+                    .title(Some(file.file_name.clone()))
+                    .blocks(Self::file_content(file))
+                    .section(Some(&section))
+                    .build()?;
+            }
+            if !declared.is_empty() {
+                Chapter::builder_in_model::<Chapter>(&self.doc_model)?
+                    .title(Some("Declared Licenses".to_string()))
+                    .blocks(Self::declared_content(&declared, &conflicts))
+                    .section(Some(&section))
+                    .build()?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl LicenseDocumentorImpl {
+    fn summary(repository_licenses: &[&SPDXLicense]) -> String {
+        if repository_licenses.is_empty() {
+            "The license of the sources, as declared in the data.".to_string()
+        } else {
+            format!(
+                "The sources are licensed under the {}.",
+                names(repository_licenses)
+            )
+        }
+    }
+
+    fn file_content(file: &LicenseFile) -> Vec<BlockContent> {
+        match file.license {
+            Some(license) => {
+                let url = license.url();
+                vec![BlockContent::DefinitionList(vec![
+                    Definition::new("License", license.name),
+                    Definition {
+                        term:        "SPDX ID".to_string(),
+                        description: vec![Inline::code(license.id)],
+                    },
+                    Definition {
+                        term:        "See".to_string(),
+                        description: vec![Inline::Link {
+                            content: vec![Inline::text(url.clone())],
+                            url,
+                        }],
+                    },
+                ])]
+            },
+            None => {
+                vec![BlockContent::paragraph(
+                    "The license in this file could not be identified.",
+                )]
+            },
+        }
+    }
+
+    fn declared_content(
+        declared: &[DeclaredLicense],
+        conflicts: &[&DeclaredLicense],
+    ) -> Vec<BlockContent> {
+        let items = declared.iter().map(|declaration| {
+            let license = match declaration.license {
+                Some(license) => license.to_string(),
+                None => declaration.reference.clone(),
+            };
+            let mut line = format!("{}: {}", declaration.subject, license);
+            if let Some(file_name) = &declaration.file_name {
+                line.push_str(&format!(" in {}", file_name));
+            }
+            if conflicts
+                .iter()
+                .any(|conflict| std::ptr::eq(*conflict, declaration))
+            {
+                line.push_str(
+                    " (conflicts with the license of the repository)",
+                );
+            }
+            line
+        });
+        vec![BlockContent::bullets(items)]
+    }
+}
+
+/// Returns the names of the given licenses, like "MIT License (MIT)".
+fn names(licenses: &[&SPDXLicense]) -> String {
+    licenses
+        .iter()
+        .map(|license| license.to_string())
+        .collect::<Vec<_>>()
+        .join(" and ")
+}
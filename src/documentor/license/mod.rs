@@ -0,0 +1,3 @@
+mod license_documentor;
+
+pub use license_documentor::LicenseDocumentorImpl;
@@ -1,10 +1,15 @@
 use {
+    super::site::{JekyllSite, NavNode, SitePage},
     crate::{
-        documentor::{Documentor, DocumentorCreator},
-        model::{Book, Buildable, Element, Model},
+        documentor::{
+            Documentor,
+            DocumentorCreator,
+            source_locator::SourceLocator,
+        },
+        model::{Book, Buildable, Chapter, Element, Model},
         source::{FileSource, FileSourceImplementor},
         store::LoaderStore,
-        util::{FileType, FileTypeSliceStatic},
+        util::{FileType, FileTypeSliceStatic, relative_path},
     },
     async_trait::async_trait,
     serde::Deserialize,
@@ -25,6 +30,8 @@ static JEKYLL_MARKDOWN_DOCUMENTOR_FILE_TYPES: LazyLock<
 /// Struct to deserialize the Jekyll _config.yml file.
 #[derive(Deserialize, Debug)]
 struct JekyllConfig {
+    title:           Option<String>,
+    baseurl:         Option<String>,
     #[allow(unused)]
     title_separator: Option<String>,
     #[allow(unused)]
@@ -64,13 +71,10 @@ impl AuthorField {
 #[derive(Debug, Clone)]
 pub struct JekyllMarkdownDocumentorImpl {
     file_source:  FileSourceImplementor,
-    #[allow(unused)]
     file_name:    Option<PathBuf>,
-    #[allow(unused)]
     loader_store: LoaderStore,
     /// The given target documentation model that the
     /// MarkdownDocumentor will add its documentation to.
-    #[allow(unused)]
     doc_model:    Arc<Model>,
 }
 
@@ -103,22 +107,44 @@ impl Documentor for JekyllMarkdownDocumentorImpl {
         // be in the root.
         let config: JekyllConfig = self.get_config().await?;
 
-        // Set the BookBuilder attributes based on the config
-        Book::builder_in_model::<Book>(&self.doc_model)?
-            .title(Some("Markdown Documentation".to_string()))
-            .repository(config.repository)
-            .url(config.url)
+        // Set the BookBuilder attributes based on the config, the book
+        // is identified by the path of the config file
+        let provenance = self
+            .file_name
+            .as_deref()
+            .map(|file_name| self.locator().locate_file(file_name, None));
+        let book = Book::builder_in_model::<Book>(&self.doc_model)?
+            .key(provenance.as_ref().and_then(|p| p.file.clone()))
+            .title(Some(config.title.clone().unwrap_or_else(|| {
+                "Markdown Documentation".to_string()
+            })))
+            .repository(config.repository.clone())
+            .url(config.url.clone())
             .author(config.author.as_string())
+            .provenance(provenance)
             .build()?;
 
         // Process the rest
-        self.process().await?;
+        self.process(&config, &book).await?;
 
         Ok(())
     }
 }
 
 impl JekyllMarkdownDocumentorImpl {
+    fn locator(&self) -> SourceLocator {
+        SourceLocator::new(&self.loader_store, Some(&self.file_source))
+    }
+
+    /// Returns the directory of the _config.yml file.
+    fn site_root(&self) -> PathBuf {
+        self.file_name
+            .as_ref()
+            .and_then(|file_name| file_name.parent())
+            .map(|path| path.to_path_buf())
+            .unwrap_or_default()
+    }
+
     async fn get_config(&self) -> anyhow::Result<JekyllConfig> {
         let config_file = self
             .file_source
@@ -127,31 +153,96 @@ impl JekyllMarkdownDocumentorImpl {
         Ok(serde_yaml::from_str(&config_file)?)
     }
 
-    /// Processes the provided markdown files.
-    pub async fn process(&self) -> anyhow::Result<()> {
-        // Scan again for only the markdown files.
+    /// Processes the markdown files of the site, adding them to the
+    /// given book in the order of the navigation of the site: a
+    /// section per top-level page and a chapter per page below it.
+    async fn process(
+        &self,
+        config: &JekyllConfig,
+        book: &Book,
+    ) -> anyhow::Result<()> {
+        let site = self.load_site(config).await?;
+        for root in site.nav_tree() {
+            self.add_to_model(&site, &root, book)?;
+        }
+        Ok(())
+    }
+
+    /// Reads the markdown files in the directory of the _config.yml
+    /// file, resolves the links between them and runs their SPARQL
+    /// queries.
+    async fn load_site(
+        &self,
+        config: &JekyllConfig,
+    ) -> anyhow::Result<JekyllSite> {
+        let site_root = self.site_root();
         let markdown_files =
             self.file_source.scan(&[&FileType::Markdown]).await?;
 
+        let mut site = JekyllSite {
+            base_url: config.baseurl.clone(),
+            pages:    Vec::new(),
+        };
         for file_path in markdown_files {
+            if !file_path.starts_with(&site_root) {
+                continue;
+            }
+            info!("Processing markdown file {}", file_path.display());
             let content = self.file_source.content_of(&file_path).await?;
-            self.process_markdown(&content).await?;
+            site.pages.push(SitePage::new(
+                relative_path(&file_path, &site_root),
+                &content,
+            ));
         }
-        Ok(())
-    }
 
-    /// Processes the content of a markdown file and adds it to the
-    /// documentation model.
-    async fn process_markdown(&self, content: &str) -> anyhow::Result<()> {
-        // Log the length of the markdown content being processed
-        info!(
-            "Processing markdown content of length: {}",
-            content.len()
-        );
-
-        // Example: Add a section to the documentation model
-        // self.doc_model.add_section().await?;
+        let titles: Vec<String> =
+            site.pages.iter().map(|page| page.title()).collect();
+        let resolved: Vec<_> = (0..site.pages.len())
+            .map(|from| {
+                let mut page = site.pages[from].page.clone();
+                page.resolve_links(&|url| {
+                    site.resolve_link(from, url).map(|to| titles[to].clone())
+                });
+                page.run_queries(&self.loader_store);
+                page
+            })
+            .collect();
+        for (site_page, page) in site.pages.iter_mut().zip(resolved) {
+            site_page.page = page;
+        }
+        Ok(site)
+    }
 
+    /// Adds the given top-level page to the documentation model as a
+    /// section of the given book, with the pages below it as its
+    /// chapters, depth first.
+    fn add_to_model(
+        &self,
+        site: &JekyllSite,
+        root: &NavNode,
+        book: &Book,
+    ) -> anyhow::Result<()> {
+        let locator = self.locator();
+        let site_root = self.site_root();
+        let provenance = |page: &SitePage| {
+            locator.locate_file(&site_root.join(&page.path), None)
+        };
+        let root_page = &site.pages[root.page];
+        let section = root_page.page.add_to_model(
+            &self.doc_model,
+            &root_page.title(),
+            book,
+            Some(provenance(root_page)),
+        )?;
+        for descendant in root.descendants() {
+            let page = &site.pages[descendant];
+            Chapter::builder_in_model::<Chapter>(&self.doc_model)?
+                .title(Some(page.title()))
+                .blocks(page.page.to_blocks())
+                .section(Some(&section))
+                .provenance(Some(provenance(page)))
+                .build()?;
+        }
         Ok(())
     }
 }
@@ -1,8 +1,13 @@
 use {
+    super::page::MarkdownPage,
     crate::{
-        documentor::{Documentor, DocumentorCreator},
-        model::Model,
-        source::FileSourceImplementor,
+        documentor::{
+            Documentor,
+            DocumentorCreator,
+            source_locator::SourceLocator,
+        },
+        model::{Book, Buildable, Element, Model},
+        source::{FileSource, FileSourceImplementor},
         store::LoaderStore,
         util::{FileType, FileTypeSliceStatic},
     },
@@ -11,6 +16,7 @@ use {
         path::{Path, PathBuf},
         sync::{Arc, LazyLock},
     },
+    tracing::info,
 };
 
 static MARKDOWN_DOCUMENTOR_FILE_TYPES: LazyLock<FileTypeSliceStatic> =
@@ -19,18 +25,19 @@ static MARKDOWN_DOCUMENTOR_FILE_TYPES: LazyLock<FileTypeSliceStatic> =
         Box::leak(Box::new(file_types))
     });
 
-/// A documentor for Markdown files.
+/// A documentor for a Markdown file that is not part of a Jekyll
+/// site, such as a hand-written guide next to an ontology.
+///
+/// Adds a book named after the first level 1 heading of the file (or
+/// otherwise its file name), with a section for the page and a chapter
+/// per level 2 heading, see `MarkdownPage`.
 #[derive(Debug, Clone)]
 pub struct MarkdownDocumentorImpl {
-    #[allow(unused)]
     file_source:  FileSourceImplementor,
-    #[allow(unused)]
     file_name:    Option<PathBuf>,
-    #[allow(unused)]
     loader_store: LoaderStore,
     /// The given target documentation model that the
     /// MarkdownDocumentor will add its documentation to.
-    #[allow(unused)]
     doc_model:    Arc<Model>,
 }
 
@@ -43,9 +50,37 @@ impl Documentor for MarkdownDocumentorImpl {
     fn file_name(&self) -> Option<&Path> { self.file_name.as_deref() }
 
     async fn generate(&self) -> anyhow::Result<()> {
-        Err(anyhow::anyhow!(
-            "Markdown documentation generation not yet implemented"
-        ))
+        let Some(file_name) = &self.file_name else {
+            return Ok(());
+        };
+        info!(
+            "Documenting Markdown file {}",
+            file_name.display()
+        );
+        let content = self.file_source.content_of(file_name).await?;
+        let mut page = MarkdownPage::parse(&content);
+        page.run_queries(&self.loader_store);
+        let title = page
+            .title
+            .clone()
+            .or_else(|| {
+                file_name
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .map(|s| s.to_string())
+            })
+            .unwrap_or_else(|| "Markdown Documentation".to_string());
+        let provenance =
+            SourceLocator::new(&self.loader_store, Some(&self.file_source))
+                .locate_file(file_name, None);
+        let book = Book::builder_in_model::<Book>(&self.doc_model)?
+            .key(provenance.file.clone())
+            .title(Some(title.clone()))
+            .author(Some("GraphArch".to_string()))
+            .provenance(Some(provenance.clone()))
+            .build()?;
+        page.add_to_model(&self.doc_model, &title, &book, Some(provenance))?;
+        Ok(())
     }
 }
 
@@ -1,5 +1,7 @@
 mod jekyll_markdown_documentor;
 mod markdown_documentor;
+mod page;
+mod site;
 
 pub use {
     jekyll_markdown_documentor::JekyllMarkdownDocumentorImpl,
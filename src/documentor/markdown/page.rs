@@ -0,0 +1,956 @@
+use {
+    crate::{
+        model::{
+            AdmonitionKind,
+            BlockContent,
+            Book,
+            Buildable,
+            Chapter,
+            Element,
+            Inline,
+            Model,
+            Provenance,
+            Section,
+        },
+        store::LoaderStore,
+    },
+    oxigraph::{model::Term, sparql::QueryResults},
+    pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag},
+    std::{iter::Peekable, sync::Arc},
+    tracing::warn,
+};
+
+/// The maximum number of results of a SPARQL query in the page, see
+/// `MarkdownPage::run_queries`
+const MAX_QUERY_RESULTS: usize = 100;
+
+/// An inline element of a Markdown paragraph, list item, heading or
+/// table cell.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum MarkdownInline {
+    Text(String),
+    Emphasis(Vec<MarkdownInline>),
+    Strong(Vec<MarkdownInline>),
+    Code(String),
+    Link {
+        url:     String,
+        content: Vec<MarkdownInline>,
+    },
+    /// A link to another page of the same site, see
+    /// `MarkdownPage::resolve_links`
+    PageLink {
+        title:   String,
+        content: Vec<MarkdownInline>,
+    },
+    Image {
+        url: String,
+        alt: String,
+    },
+    LineBreak,
+}
+
+/// A block of a Markdown page.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum MarkdownBlock {
+    Heading(u8, Vec<MarkdownInline>),
+    Paragraph(Vec<MarkdownInline>),
+    /// A bullet list, or a numbered list if it has a start number
+    List {
+        start: Option<u64>,
+        items: Vec<Vec<MarkdownBlock>>,
+    },
+    Code {
+        language: Option<String>,
+        code:     String,
+        /// True for a SPARQL query that is marked to be executed, like
+        /// ```` ```sparql run ````, see `MarkdownPage::run_queries`
+        run:      bool,
+    },
+    Table {
+        header: Vec<Vec<MarkdownInline>>,
+        rows:   Vec<Vec<Vec<MarkdownInline>>>,
+    },
+    Quote(Vec<MarkdownBlock>),
+    Rule,
+}
+
+/// A chapter of a Markdown page, the blocks under one level 2
+/// heading.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct MarkdownChapter {
+    pub title:  String,
+    pub blocks: Vec<MarkdownBlock>,
+}
+
+/// A Markdown page (CommonMark with GitHub tables, strikethrough and
+/// task lists), parsed into its title (the first level 1 heading),
+/// the blocks before the first level 2 heading and a chapter per level
+/// 2 heading. Deeper headings stay within their chapter. YAML front
+/// matter is skipped.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct MarkdownPage {
+    pub title:    Option<String>,
+    pub intro:    Vec<MarkdownBlock>,
+    pub chapters: Vec<MarkdownChapter>,
+}
+
+impl MarkdownPage {
+    pub fn parse(markdown: &str) -> Self {
+        let options = Options::ENABLE_TABLES |
+            Options::ENABLE_STRIKETHROUGH |
+            Options::ENABLE_TASKLISTS |
+            Options::ENABLE_YAML_STYLE_METADATA_BLOCKS;
+        let mut reader = EventReader {
+            events: Parser::new_ext(markdown, options).peekable(),
+        };
+        let mut page = MarkdownPage {
+            title:    None,
+            intro:    Vec::new(),
+            chapters: Vec::new(),
+        };
+        for block in reader.blocks() {
+            match block {
+                MarkdownBlock::Heading(1, content) if page.title.is_none() => {
+                    page.title = Some(plain_text(&content));
+                },
+                MarkdownBlock::Heading(2, content) => {
+                    page.chapters.push(MarkdownChapter {
+                        title:  plain_text(&content),
+                        blocks: Vec::new(),
+                    });
+                },
+                block => {
+                    match page.chapters.last_mut() {
+                        Some(chapter) => chapter.blocks.push(block),
+                        None => page.intro.push(block),
+                    }
+                },
+            }
+        }
+        page
+    }
+
+    /// Replaces the links that the given function resolves to the
+    /// title of another page with a `MarkdownInline::PageLink`, since
+    /// the URLs of the site do not work in the generated documentation.
+    pub fn resolve_links(&mut self, resolve: &impl Fn(&str) -> Option<String>) {
+        resolve_block_links(&mut self.intro, resolve);
+        for chapter in &mut self.chapters {
+            resolve_block_links(&mut chapter.blocks, resolve);
+        }
+    }
+
+    /// Executes the SPARQL queries of the page that are marked with
+    /// `run` against the given loader store and adds their results
+    /// below them: a table for a `SELECT` query, the answer to an
+    /// `ASK` query or the triples of a `CONSTRUCT` or `DESCRIBE`
+    /// query. A query that fails is reported and gets the error below
+    /// it, so the examples in the documentation are validated.
+    pub fn run_queries(&mut self, loader_store: &LoaderStore) {
+        run_block_queries(&mut self.intro, loader_store);
+        for chapter in &mut self.chapters {
+            run_block_queries(&mut chapter.blocks, loader_store);
+        }
+    }
+
+    /// Returns the blocks of the whole page, without its title, with
+    /// a heading per chapter.
+    pub fn to_blocks(&self) -> Vec<BlockContent> {
+        let mut blocks = block_contents(&self.intro);
+        for chapter in &self.chapters {
+            blocks.push(BlockContent::heading(chapter.title.clone()));
+            blocks.extend(block_contents(&chapter.blocks));
+        }
+        blocks
+    }
+
+    /// Adds the page to the given book as a section with the given
+    /// title, with the blocks before the first chapter as its
+    /// description and a chapter per chapter of the page, all with the
+    /// given provenance (the file of the page).
+    pub fn add_to_model(
+        &self,
+        doc_model: &Arc<Model>,
+        title: &str,
+        book: &Book,
+        provenance: Option<Provenance>,
+    ) -> anyhow::Result<Section> {
+        let section = Section::builder_in_model::<Section>(doc_model)?
+            .title(Some(title.to_string()))
+            .description(
+                Some(render_blocks(&self.intro)).filter(|d| !d.is_empty()),
+            )
+            .book(Some(book))
+            .provenance(provenance.clone())
+            .build()?;
+        for chapter in &self.chapters {
+            Chapter::builder_in_model::<Chapter>(doc_model)?
+                .title(Some(chapter.title.clone()))
+                .blocks(block_contents(&chapter.blocks))
+                .section(Some(&section))
+                .provenance(provenance.clone())
+                .build()?;
+        }
+        Ok(section)
+    }
+}
+
+fn run_block_queries(
+    blocks: &mut Vec<MarkdownBlock>,
+    loader_store: &LoaderStore,
+) {
+    let mut i = 0;
+    while i < blocks.len() {
+        match &mut blocks[i] {
+            MarkdownBlock::Code { code, run: true, .. } => {
+                let results = query_results(code, loader_store);
+                let count = results.len();
+                blocks.splice(i + 1..i + 1, results);
+                i += count;
+            },
+            MarkdownBlock::List { items, .. } => {
+                for item in items {
+                    run_block_queries(item, loader_store);
+                }
+            },
+            MarkdownBlock::Quote(quoted) => {
+                run_block_queries(quoted, loader_store)
+            },
+            _ => {},
+        }
+        i += 1;
+    }
+}
+
+/// Returns the blocks that show the results of the given query.
+fn query_results(
+    query: &str,
+    loader_store: &LoaderStore,
+) -> Vec<MarkdownBlock> {
+    match query_results_or_error(query, loader_store) {
+        Ok(blocks) => blocks,
+        Err(error) => {
+            warn!("The SPARQL query failed: {}", error);
+            vec![MarkdownBlock::Paragraph(vec![MarkdownInline::Emphasis(
+                vec![MarkdownInline::Text(format!(
+                    "The query failed: {}",
+                    error
+                ))],
+            )])]
+        },
+    }
+}
+
+fn query_results_or_error(
+    query: &str,
+    loader_store: &LoaderStore,
+) -> anyhow::Result<Vec<MarkdownBlock>> {
+    let prefixes = loader_store.prefix_registry()?;
+    let term = |term: &Term| -> MarkdownInline {
+        match term {
+            Term::NamedNode(node) => {
+                MarkdownInline::Code(prefixes.curie_or_iri(node.as_str()))
+            },
+            Term::Literal(literal) => {
+                MarkdownInline::Text(literal.value().to_string())
+            },
+            term => MarkdownInline::Code(term.to_string()),
+        }
+    };
+    let paragraph = |text: String| {
+        MarkdownBlock::Paragraph(vec![MarkdownInline::Text(text)])
+    };
+    match loader_store.query(query)? {
+        QueryResults::Boolean(answer) => {
+            Ok(vec![paragraph(format!("Result: {}", answer))])
+        },
+        QueryResults::Solutions(solutions) => {
+            let variables: Vec<String> = solutions
+                .variables()
+                .iter()
+                .map(|variable| variable.as_str().to_string())
+                .collect();
+            let mut rows = Vec::new();
+            let mut count = 0;
+            for solution in solutions {
+                let solution = solution?;
+                count += 1;
+                if rows.len() < MAX_QUERY_RESULTS {
+                    rows.push(
+                        variables
+                            .iter()
+                            .map(|variable| {
+                                solution
+                                    .get(variable.as_str())
+                                    .map(|value| vec![term(value)])
+                                    .unwrap_or_default()
+                            })
+                            .collect(),
+                    );
+                }
+            }
+            if count == 0 {
+                return Ok(vec![paragraph("No results.".to_string())]);
+            }
+            let mut blocks = vec![MarkdownBlock::Table {
+                header: variables
+                    .iter()
+                    .map(|variable| {
+                        vec![MarkdownInline::Text(format!("?{}", variable))]
+                    })
+                    .collect(),
+                rows,
+            }];
+            if count > MAX_QUERY_RESULTS {
+                blocks.push(paragraph(format!(
+                    "Showing {} of {} results.",
+                    MAX_QUERY_RESULTS, count
+                )));
+            }
+            Ok(blocks)
+        },
+        QueryResults::Graph(triples) => {
+            let mut lines = Vec::new();
+            for triple in triples {
+                let triple = triple?;
+                lines.push(format!("{} .", triple));
+            }
+            if lines.is_empty() {
+                return Ok(vec![paragraph("No results.".to_string())]);
+            }
+            Ok(vec![MarkdownBlock::Code {
+                language: Some("turtle".to_string()),
+                code:     lines.join("\n"),
+                run:      false,
+            }])
+        },
+    }
+}
+
+fn resolve_block_links(
+    blocks: &mut [MarkdownBlock],
+    resolve: &impl Fn(&str) -> Option<String>,
+) {
+    for block in blocks {
+        match block {
+            MarkdownBlock::Heading(_, content) |
+            MarkdownBlock::Paragraph(content) => {
+                resolve_inline_links(content, resolve)
+            },
+            MarkdownBlock::List { items, .. } => {
+                for item in items {
+                    resolve_block_links(item, resolve);
+                }
+            },
+            MarkdownBlock::Table { header, rows } => {
+                for cell in header.iter_mut().chain(rows.iter_mut().flatten()) {
+                    resolve_inline_links(cell, resolve);
+                }
+            },
+            MarkdownBlock::Quote(blocks) => {
+                resolve_block_links(blocks, resolve)
+            },
+            MarkdownBlock::Code { .. } | MarkdownBlock::Rule => {},
+        }
+    }
+}
+
+fn resolve_inline_links(
+    inlines: &mut [MarkdownInline],
+    resolve: &impl Fn(&str) -> Option<String>,
+) {
+    for inline in inlines {
+        match inline {
+            MarkdownInline::Link { url, content } => {
+                match resolve(url) {
+                    Some(title) => {
+                        *inline = MarkdownInline::PageLink {
+                            title,
+                            content: std::mem::take(content),
+                        };
+                    },
+                    None => resolve_inline_links(content, resolve),
+                }
+            },
+            MarkdownInline::Emphasis(content) |
+            MarkdownInline::Strong(content) |
+            MarkdownInline::PageLink { content, .. } => {
+                resolve_inline_links(content, resolve)
+            },
+            _ => {},
+        }
+    }
+}
+
+/// Turns the flat stream of pulldown-cmark events into a tree of
+/// blocks and inlines.
+struct EventReader<'a> {
+    events: Peekable<Parser<'a>>,
+}
+
+impl EventReader<'_> {
+    /// Reads blocks up to and including the end of the enclosing
+    /// container, or up to the end of the page.
+    fn blocks(&mut self) -> Vec<MarkdownBlock> {
+        let mut blocks = Vec::new();
+        while let Some(event) = self.events.peek() {
+            if is_inline(event) {
+                // The content of the items of tight lists is not
+                // wrapped in a paragraph
+                let content = self.inline_run();
+                blocks.push(MarkdownBlock::Paragraph(content));
+                continue;
+            }
+            match self.events.next() {
+                Some(Event::Start(tag)) => {
+                    if let Some(block) = self.block(tag) {
+                        blocks.push(block);
+                    }
+                },
+                Some(Event::Rule) => blocks.push(MarkdownBlock::Rule),
+                Some(Event::End(_)) | None => break,
+                Some(_) => {},
+            }
+        }
+        blocks
+    }
+
+    fn block(&mut self, tag: Tag) -> Option<MarkdownBlock> {
+        match tag {
+            Tag::Paragraph => Some(MarkdownBlock::Paragraph(self.inlines())),
+            Tag::Heading { level, .. } => {
+                Some(MarkdownBlock::Heading(
+                    level as u8,
+                    self.inlines(),
+                ))
+            },
+            Tag::BlockQuote(_) => Some(MarkdownBlock::Quote(self.blocks())),
+            Tag::CodeBlock(kind) => {
+                let (language, run) = match kind {
+                    CodeBlockKind::Fenced(info) => {
+                        let mut words = info
+                            .split(|c: char| c.is_whitespace() || c == ',')
+                            .filter(|word| !word.is_empty());
+                        let language = words.next().map(str::to_string);
+                        let run = language.as_deref() == Some("sparql") &&
+                            words.any(|word| word == "run");
+                        (language, run)
+                    },
+                    CodeBlockKind::Indented => (None, false),
+                };
+                Some(MarkdownBlock::Code { language, code: self.text(), run })
+            },
+            Tag::List(start) => {
+                let mut items = Vec::new();
+                while let Some(event) = self.events.next() {
+                    match event {
+                        Event::Start(Tag::Item) => items.push(self.blocks()),
+                        Event::End(_) => break,
+                        _ => {},
+                    }
+                }
+                Some(MarkdownBlock::List { start, items })
+            },
+            Tag::Table(_) => {
+                let mut header = Vec::new();
+                let mut rows = Vec::new();
+                while let Some(event) = self.events.next() {
+                    match event {
+                        Event::Start(Tag::TableHead) => header = self.cells(),
+                        Event::Start(Tag::TableRow) => rows.push(self.cells()),
+                        Event::End(_) => break,
+                        _ => {},
+                    }
+                }
+                Some(MarkdownBlock::Table { header, rows })
+            },
+            // HTML blocks, front matter, footnote definitions and
+            // definition lists are not documented
+            _ => {
+                self.skip();
+                None
+            },
+        }
+    }
+
+    fn cells(&mut self) -> Vec<Vec<MarkdownInline>> {
+        let mut cells = Vec::new();
+        while let Some(event) = self.events.next() {
+            match event {
+                Event::Start(Tag::TableCell) => cells.push(self.inlines()),
+                Event::End(_) => break,
+                _ => {},
+            }
+        }
+        cells
+    }
+
+    /// Reads inlines up to and including the end of the enclosing
+    /// block or span.
+    fn inlines(&mut self) -> Vec<MarkdownInline> {
+        let mut inlines = Vec::new();
+        while let Some(event) = self.events.next() {
+            match event {
+                Event::End(_) => break,
+                event => self.inline(event, &mut inlines),
+            }
+        }
+        inlines
+    }
+
+    /// Reads the inlines that are not wrapped in a block.
+    fn inline_run(&mut self) -> Vec<MarkdownInline> {
+        let mut inlines = Vec::new();
+        while self.events.peek().is_some_and(is_inline) {
+            let event = self.events.next().unwrap();
+            self.inline(event, &mut inlines);
+        }
+        inlines
+    }
+
+    fn inline(&mut self, event: Event, inlines: &mut Vec<MarkdownInline>) {
+        match event {
+            Event::Text(text) => {
+                inlines.push(MarkdownInline::Text(text.to_string()))
+            },
+            Event::Code(code) => {
+                inlines.push(MarkdownInline::Code(code.to_string()))
+            },
+            Event::SoftBreak => {
+                inlines.push(MarkdownInline::Text(" ".to_string()))
+            },
+            Event::HardBreak => inlines.push(MarkdownInline::LineBreak),
+            Event::TaskListMarker(checked) => {
+                inlines.push(MarkdownInline::Text(
+                    if checked { "[x] " } else { "[ ] " }.to_string(),
+                ))
+            },
+            Event::Start(Tag::Emphasis) => {
+                inlines.push(MarkdownInline::Emphasis(self.inlines()))
+            },
+            Event::Start(Tag::Strong) => {
+                inlines.push(MarkdownInline::Strong(self.inlines()))
+            },
+            Event::Start(Tag::Link { dest_url, .. }) => {
+                inlines.push(MarkdownInline::Link {
+                    url:     dest_url.to_string(),
+                    content: self.inlines(),
+                })
+            },
+            Event::Start(Tag::Image { dest_url, .. }) => {
+                inlines.push(MarkdownInline::Image {
+                    url: dest_url.to_string(),
+                    alt: plain_text(&self.inlines()),
+                })
+            },
+            // Strikethrough and other spans keep their content only
+            Event::Start(_) => inlines.extend(self.inlines()),
+            _ => {},
+        }
+    }
+
+    /// Reads the text up to and including the end of the enclosing
+    /// block, like the code of a code block.
+    fn text(&mut self) -> String {
+        let mut text = String::new();
+        for event in self.events.by_ref() {
+            match event {
+                Event::Text(t) => text.push_str(&t),
+                Event::End(_) => break,
+                _ => {},
+            }
+        }
+        text
+    }
+
+    /// Skips the events up to and including the end of the enclosing
+    /// block.
+    fn skip(&mut self) {
+        let mut depth = 0;
+        for event in self.events.by_ref() {
+            match event {
+                Event::Start(_) => depth += 1,
+                Event::End(_) if depth == 0 => break,
+                Event::End(_) => depth -= 1,
+                _ => {},
+            }
+        }
+    }
+}
+
+fn is_inline(event: &Event) -> bool {
+    match event {
+        Event::Text(_) |
+        Event::Code(_) |
+        Event::SoftBreak |
+        Event::HardBreak |
+        Event::TaskListMarker(_) |
+        Event::InlineHtml(_) |
+        Event::InlineMath(_) |
+        Event::FootnoteReference(_) => true,
+        Event::Start(tag) => {
+            matches!(
+                tag,
+                Tag::Emphasis |
+                    Tag::Strong |
+                    Tag::Strikethrough |
+                    Tag::Superscript |
+                    Tag::Subscript |
+                    Tag::Link { .. } |
+                    Tag::Image { .. }
+            )
+        },
+        _ => false,
+    }
+}
+
+/// Returns the text of the given inlines without any markup.
+pub(super) fn plain_text(inlines: &[MarkdownInline]) -> String {
+    inlines
+        .iter()
+        .map(|inline| {
+            match inline {
+                MarkdownInline::Text(text) | MarkdownInline::Code(text) => {
+                    text.clone()
+                },
+                MarkdownInline::Emphasis(content) |
+                MarkdownInline::Strong(content) |
+                MarkdownInline::Link { content, .. } |
+                MarkdownInline::PageLink { content, .. } => plain_text(content),
+                MarkdownInline::Image { alt, .. } => alt.clone(),
+                MarkdownInline::LineBreak => " ".to_string(),
+            }
+        })
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+/// Returns the given Markdown blocks as blocks of the documentation
+/// model. Rules are left out, nested blocks of list items and quotes
+/// are flattened into their text and a quote that is a GitHub alert
+/// like `> [!NOTE]` becomes an admonition.
+pub(super) fn block_contents(blocks: &[MarkdownBlock]) -> Vec<BlockContent> {
+    blocks.iter().filter_map(block_content).collect()
+}
+
+fn block_content(block: &MarkdownBlock) -> Option<BlockContent> {
+    let content = match block {
+        MarkdownBlock::Heading(_, content) => {
+            BlockContent::Heading(inlines(content))
+        },
+        MarkdownBlock::Paragraph(content) => {
+            match content.as_slice() {
+                [MarkdownInline::Image { url, alt }] => {
+                    BlockContent::Image {
+                        url:     url.clone(),
+                        alt:     alt.clone(),
+                        caption: None,
+                    }
+                },
+                _ => BlockContent::Paragraph(inlines(content)),
+            }
+        },
+        MarkdownBlock::List { start, items } => {
+            BlockContent::List {
+                numbered: start.is_some(),
+                items:    items.iter().map(|item| flat_inlines(item)).collect(),
+            }
+        },
+        MarkdownBlock::Code { language, code, .. } => {
+            BlockContent::Code {
+                language: language.clone(),
+                code:     code.clone(),
+            }
+        },
+        MarkdownBlock::Table { header, rows } => {
+            BlockContent::Table {
+                header: header.iter().map(|cell| inlines(cell)).collect(),
+                rows:   rows
+                    .iter()
+                    .map(|row| row.iter().map(|cell| inlines(cell)).collect())
+                    .collect(),
+            }
+        },
+        MarkdownBlock::Quote(blocks) => quote(blocks),
+        MarkdownBlock::Rule => return None,
+    };
+    Some(content)
+}
+
+/// Returns a quote, or an admonition if the quote starts with the
+/// marker of a GitHub alert, like `[!WARNING]`.
+fn quote(blocks: &[MarkdownBlock]) -> BlockContent {
+    let mut content = flat_inlines(blocks);
+    let kind = match content.first() {
+        Some(Inline::Text(text)) => {
+            text.strip_prefix("[!")
+                .and_then(|rest| rest.split_once(']'))
+                .and_then(|(name, _)| AdmonitionKind::from_name(name))
+        },
+        _ => None,
+    };
+    let Some(kind) = kind else {
+        return BlockContent::Quote(content);
+    };
+    if let Some(Inline::Text(text)) = content.first_mut() {
+        let rest = text.split_once(']').map(|(_, rest)| rest).unwrap_or("");
+        *text = rest.trim_start().to_string();
+    }
+    content.retain(|inline| !matches!(inline, Inline::Text(t) if t.is_empty()));
+    BlockContent::Admonition { kind, content }
+}
+
+/// Returns the inlines of the given blocks as one run, with a space
+/// between the blocks.
+fn flat_inlines(blocks: &[MarkdownBlock]) -> Vec<Inline> {
+    let mut flat = Vec::new();
+    for block in blocks {
+        if !flat.is_empty() {
+            flat.push(Inline::text(" "));
+        }
+        match block {
+            MarkdownBlock::Heading(_, content) |
+            MarkdownBlock::Paragraph(content) => flat.extend(inlines(content)),
+            MarkdownBlock::Code { code, .. } => {
+                flat.push(Inline::code(code.trim_end_matches('\n')))
+            },
+            block => flat.push(Inline::Text(render_block(block))),
+        }
+    }
+    flat
+}
+
+/// Returns the given Markdown inlines as inlines of the documentation
+/// model, joining adjacent runs of text.
+fn inlines(inlines: &[MarkdownInline]) -> Vec<Inline> {
+    let mut joined: Vec<Inline> = Vec::new();
+    for inline in inlines.iter().map(|inline| {
+        match inline {
+            MarkdownInline::Text(text) => Inline::Text(text.clone()),
+            MarkdownInline::Emphasis(content) => {
+                Inline::Emphasis(self::inlines(content))
+            },
+            MarkdownInline::Strong(content) => {
+                Inline::Strong(self::inlines(content))
+            },
+            MarkdownInline::Code(code) => Inline::Code(code.clone()),
+            MarkdownInline::Link { url, content } => {
+                Inline::Link {
+                    url:     url.clone(),
+                    content: self::inlines(content),
+                }
+            },
+            MarkdownInline::PageLink { title, content } => {
+                Inline::Text(format!(
+                    "{} (see \"{title}\")",
+                    plain_text(content)
+                ))
+            },
+            MarkdownInline::Image { url, alt } => {
+                Inline::Link {
+                    url:     url.clone(),
+                    content: vec![Inline::Text(alt.clone())],
+                }
+            },
+            MarkdownInline::LineBreak => Inline::text(" "),
+        }
+    }) {
+        match (joined.last_mut(), inline) {
+            (Some(Inline::Text(last)), Inline::Text(text)) => {
+                last.push_str(&text)
+            },
+            (_, inline) => joined.push(inline),
+        }
+    }
+    joined
+}
+
+/// Renders the given blocks as the content of a chapter, as normalized
+/// Markdown.
+pub(super) fn render_blocks(blocks: &[MarkdownBlock]) -> String {
+    blocks
+        .iter()
+        .map(render_block)
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn render_block(block: &MarkdownBlock) -> String {
+    match block {
+        MarkdownBlock::Heading(level, content) => {
+            format!(
+                "{} {}",
+                "#".repeat(*level as usize),
+                render_inlines(content)
+            )
+        },
+        MarkdownBlock::Paragraph(content) => render_inlines(content),
+        MarkdownBlock::List { start, items } => {
+            items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| {
+                    let marker = match start {
+                        Some(start) => format!("{}. ", start + i as u64),
+                        None => "- ".to_string(),
+                    };
+                    let indent = " ".repeat(marker.len());
+                    let content = render_blocks(item)
+                        .replace('\n', &format!("\n{indent}"));
+                    format!("{marker}{content}")
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        },
+        MarkdownBlock::Code { language, code, .. } => {
+            format!(
+                "```{}\n{}\n```",
+                language.as_deref().unwrap_or_default(),
+                code.trim_end_matches('\n')
+            )
+        },
+        MarkdownBlock::Table { header, rows } => {
+            let row = |cells: &Vec<Vec<MarkdownInline>>| {
+                format!(
+                    "| {} |",
+                    cells
+                        .iter()
+                        .map(|cell| render_inlines(cell))
+                        .collect::<Vec<_>>()
+                        .join(" | ")
+                )
+            };
+            let mut lines = vec![
+                row(header),
+                format!("|{}", " --- |".repeat(header.len())),
+            ];
+            lines.extend(rows.iter().map(row));
+            lines.join("\n")
+        },
+        MarkdownBlock::Quote(blocks) => {
+            render_blocks(blocks)
+                .lines()
+                .map(|line| format!("> {line}").trim_end().to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        },
+        MarkdownBlock::Rule => "---".to_string(),
+    }
+}
+
+fn render_inlines(inlines: &[MarkdownInline]) -> String {
+    inlines
+        .iter()
+        .map(|inline| {
+            match inline {
+                MarkdownInline::Text(text) => text.clone(),
+                MarkdownInline::Emphasis(content) => {
+                    format!("*{}*", render_inlines(content))
+                },
+                MarkdownInline::Strong(content) => {
+                    format!("**{}**", render_inlines(content))
+                },
+                MarkdownInline::Code(code) => format!("`{code}`"),
+                MarkdownInline::Link { url, content } => {
+                    format!("[{}]({url})", render_inlines(content))
+                },
+                MarkdownInline::PageLink { title, content } => {
+                    format!("{} (see \"{title}\")", render_inlines(content))
+                },
+                MarkdownInline::Image { url, alt } => {
+                    format!("![{alt}]({url})")
+                },
+                MarkdownInline::LineBreak => "\n".to_string(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_page() {
+        let page = MarkdownPage::parse(
+            "---\ntitle: Guide\n---\n# Guide\n\nAn *intro*.\n\n## Usage\n\n\
+             - one\n- two with [a link](https://example.com)\n\n### \
+             Details\n\n```turtle\n:a :b :c .\n```\n\n## Table\n\n| A | B \
+             |\n|---|---|\n| 1 | `2` |\n\n![logo](logo.png)\n",
+        );
+        assert_eq!(page.title.as_deref(), Some("Guide"));
+        assert_eq!(page.intro, vec![MarkdownBlock::Paragraph(vec![
+            MarkdownInline::Text("An ".to_string()),
+            MarkdownInline::Emphasis(vec![MarkdownInline::Text(
+                "intro".to_string()
+            )]),
+            MarkdownInline::Text(".".to_string()),
+        ])]);
+        assert_eq!(page.chapters.len(), 2);
+
+        let usage = &page.chapters[0];
+        assert_eq!(usage.title, "Usage");
+        assert_eq!(
+            render_blocks(&usage.blocks),
+            "- one\n- two with [a link](https://example.com)\n\n### \
+             Details\n\n```turtle\n:a :b :c .\n```"
+        );
+
+        let table = &page.chapters[1];
+        assert_eq!(
+            render_blocks(&table.blocks),
+            "| A | B |\n| --- | --- |\n| 1 | `2` |\n\n![logo](logo.png)"
+        );
+        assert_eq!(
+            block_contents(&table.blocks)[1],
+            BlockContent::Image {
+                url:     "logo.png".to_string(),
+                alt:     "logo".to_string(),
+                caption: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_admonition() {
+        let page = MarkdownPage::parse(
+            "> [!WARNING]\n> Do not *panic*.\n\n> Just a quote.\n",
+        );
+        assert_eq!(block_contents(&page.intro), vec![
+            BlockContent::Admonition {
+                kind:    AdmonitionKind::Warning,
+                content: vec![
+                    Inline::text("Do not "),
+                    Inline::Emphasis(vec![Inline::text("panic")]),
+                    Inline::text("."),
+                ],
+            },
+            BlockContent::Quote(vec![Inline::text("Just a quote.")]),
+        ]);
+    }
+
+    #[test]
+    fn test_run_queries() -> anyhow::Result<()> {
+        use oxigraph::model::{Literal, NamedNode, Quad};
+
+        let loader_store = LoaderStore::new_in_memory()?;
+        loader_store.store().insert(&Quad::new(
+            NamedNode::new("http://example.com/a")?,
+            NamedNode::new("http://example.com/b")?,
+            Literal::new_simple_literal("c"),
+            NamedNode::new("urn:sha256:abc")?,
+        ))?;
+        let mut page = MarkdownPage::parse(
+            "# Queries\n\n```sparql run\nSELECT ?o WHERE { ?s ?p ?o \
+             }\n```\n\n```sparql\nASK { ?s ?p ?o }\n```\n",
+        );
+        page.run_queries(&loader_store);
+        assert_eq!(
+            render_blocks(&page.intro),
+            "```sparql\nSELECT ?o WHERE { ?s ?p ?o }\n```\n\n| ?o |\n| --- \
+             |\n| c |\n\n```sparql\nASK { ?s ?p ?o }\n```"
+        );
+        Ok(())
+    }
+}
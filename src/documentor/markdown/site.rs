@@ -0,0 +1,438 @@
+use {
+    super::page::MarkdownPage,
+    serde::Deserialize,
+    std::{
+        cmp::Ordering,
+        collections::HashMap,
+        path::{Component, Path, PathBuf},
+    },
+    tracing::warn,
+};
+
+/// The YAML front matter of a page of a Jekyll site, with the fields
+/// that the "Just the Docs" theme uses to build its navigation.
+#[derive(Deserialize, Debug, Default, Clone, PartialEq)]
+pub(super) struct FrontMatter {
+    pub title:        Option<String>,
+    /// The title of the parent page
+    pub parent:       Option<String>,
+    /// The title of the parent of the parent page, to tell apart
+    /// parent pages with the same title
+    pub grand_parent: Option<String>,
+    pub nav_order:    Option<NavOrder>,
+    pub permalink:    Option<String>,
+    #[serde(default)]
+    pub nav_exclude:  bool,
+}
+
+/// The `nav_order` of a page, pages with a number come before pages
+/// with a text, like in "Just the Docs".
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub(super) enum NavOrder {
+    Number(f64),
+    Text(String),
+}
+
+impl FrontMatter {
+    /// Returns the front matter at the start of the given page, if
+    /// any. Front matter that is not valid YAML is reported and
+    /// ignored.
+    pub fn parse(content: &str) -> Option<FrontMatter> {
+        let rest = content
+            .strip_prefix("---\n")
+            .or_else(|| content.strip_prefix("---\r\n"))?;
+        let yaml = if rest.starts_with("---") {
+            ""
+        } else {
+            let end =
+                rest.match_indices("\n---").map(|(i, _)| i + 1).find(|i| {
+                    rest[*i + 3..].starts_with(['\n', '\r']) ||
+                        rest.len() == *i + 3
+                })?;
+            &rest[..end]
+        };
+        match serde_yaml::from_str::<Option<FrontMatter>>(yaml) {
+            Ok(front_matter) => Some(front_matter.unwrap_or_default()),
+            Err(error) => {
+                warn!("Ignoring invalid front matter: {}", error);
+                None
+            },
+        }
+    }
+}
+
+/// A Markdown page of a Jekyll site.
+#[derive(Debug, Clone)]
+pub(super) struct SitePage {
+    /// The path of the page, relative to the root of the site
+    pub path:         PathBuf,
+    pub front_matter: FrontMatter,
+    pub page:         MarkdownPage,
+}
+
+impl SitePage {
+    pub fn new(path: PathBuf, content: &str) -> Self {
+        Self {
+            path,
+            front_matter: FrontMatter::parse(content).unwrap_or_default(),
+            page: MarkdownPage::parse(content),
+        }
+    }
+
+    /// Returns the title from the front matter, or otherwise the first
+    /// level 1 heading or the file name.
+    pub fn title(&self) -> String {
+        self.front_matter
+            .title
+            .clone()
+            .or_else(|| self.page.title.clone())
+            .or_else(|| {
+                self.path
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .map(|s| s.to_string())
+            })
+            .unwrap_or_else(|| "Untitled".to_string())
+    }
+
+    /// Returns the URL path of the page on the site: its permalink or
+    /// otherwise the path that Jekyll derives from its file name, like
+    /// `/features/` for `features/README.md` or `/other/faq.html` for
+    /// `other/faq.md`.
+    pub fn url_path(&self) -> String {
+        if let Some(permalink) = &self.front_matter.permalink {
+            return normalize_url_path(permalink);
+        }
+        let stem = self.path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+        let directory = self
+            .path
+            .parent()
+            .map(|p| p.to_string_lossy().replace('\\', "/"))
+            .unwrap_or_default();
+        if stem.eq_ignore_ascii_case("readme") || stem == "index" {
+            normalize_url_path(&directory)
+        } else if directory.is_empty() {
+            normalize_url_path(&format!("{}.html", stem))
+        } else {
+            normalize_url_path(&format!("{}/{}.html", directory, stem))
+        }
+    }
+}
+
+/// A page in the navigation tree of a site, see `JekyllSite::nav_tree`.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct NavNode {
+    /// The index of the page in `JekyllSite::pages`
+    pub page:     usize,
+    pub children: Vec<NavNode>,
+}
+
+impl NavNode {
+    /// Returns the indexes of the pages below this one, depth first in
+    /// navigation order.
+    pub fn descendants(&self) -> Vec<usize> {
+        self.children
+            .iter()
+            .flat_map(|child| {
+                std::iter::once(child.page).chain(child.descendants())
+            })
+            .collect()
+    }
+}
+
+/// The Markdown pages of a Jekyll site.
+#[derive(Debug, Clone, Default)]
+pub(super) struct JekyllSite {
+    /// The `baseurl` of the site, which precedes the permalinks in
+    /// links
+    pub base_url: Option<String>,
+    pub pages:    Vec<SitePage>,
+}
+
+impl JekyllSite {
+    /// Rebuilds the navigation of the site from the `parent`,
+    /// `grand_parent` and `nav_order` of its pages. Pages with
+    /// `nav_exclude` are left out, pages whose parent does not exist
+    /// and pages that are their own ancestor are shown at the top
+    /// level.
+    pub fn nav_tree(&self) -> Vec<NavNode> {
+        let included: Vec<usize> = (0..self.pages.len())
+            .filter(|i| !self.pages[*i].front_matter.nav_exclude)
+            .collect();
+        let parent_of = |i: usize| -> Option<usize> {
+            let front_matter = &self.pages[i].front_matter;
+            let parent_title = front_matter.parent.as_deref()?;
+            let parent =
+                included.iter().copied().find(|candidate| {
+                    *candidate != i &&
+                        self.pages[*candidate].title() == parent_title &&
+                        match &front_matter.grand_parent {
+                            Some(grand_parent) => {
+                                self.pages[*candidate]
+                                    .front_matter
+                                    .parent
+                                    .as_ref() ==
+                                    Some(grand_parent)
+                            },
+                            None => true,
+                        }
+                });
+            if parent.is_none() {
+                warn!(
+                    "The parent \"{}\" of {} does not exist",
+                    parent_title,
+                    self.pages[i].path.display()
+                );
+            }
+            parent
+        };
+        let mut parents: HashMap<usize, Option<usize>> =
+            included.iter().map(|i| (*i, parent_of(*i))).collect();
+        // Pages whose parents lead back to themselves would never be
+        // reached from the top level
+        let cyclic: Vec<usize> = included
+            .iter()
+            .copied()
+            .filter(|i| {
+                let mut ancestor = parents[i];
+                for _ in 0..included.len() {
+                    match ancestor {
+                        Some(a) if a == *i => return true,
+                        Some(a) => ancestor = parents[&a],
+                        None => return false,
+                    }
+                }
+                false
+            })
+            .collect();
+        for i in cyclic {
+            warn!(
+                "{} is its own ancestor through its parent \"{}\"",
+                self.pages[i].path.display(),
+                self.pages[i]
+                    .front_matter
+                    .parent
+                    .as_deref()
+                    .unwrap_or_default()
+            );
+            parents.insert(i, None);
+        }
+        self.children_of(None, &included, &parents)
+    }
+
+    fn children_of(
+        &self,
+        parent: Option<usize>,
+        included: &[usize],
+        parents: &HashMap<usize, Option<usize>>,
+    ) -> Vec<NavNode> {
+        let mut children: Vec<usize> = included
+            .iter()
+            .copied()
+            .filter(|i| parents.get(i).copied().flatten() == parent)
+            .collect();
+        children.sort_by(|a, b| self.compare(*a, *b));
+        children
+            .into_iter()
+            .map(|page| {
+                NavNode {
+                    page,
+                    children: self.children_of(Some(page), included, parents),
+                }
+            })
+            .collect()
+    }
+
+    /// Compares pages by `nav_order` and then by title.
+    fn compare(&self, a: usize, b: usize) -> Ordering {
+        let order = |i: usize| self.pages[i].front_matter.nav_order.clone();
+        let by_order = match (order(a), order(b)) {
+            (Some(NavOrder::Number(a)), Some(NavOrder::Number(b))) => {
+                a.total_cmp(&b)
+            },
+            (Some(NavOrder::Text(a)), Some(NavOrder::Text(b))) => a.cmp(&b),
+            (Some(NavOrder::Number(_)), _) => Ordering::Less,
+            (_, Some(NavOrder::Number(_))) => Ordering::Greater,
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        };
+        by_order.then_with(|| self.pages[a].title().cmp(&self.pages[b].title()))
+    }
+
+    /// Returns the index of the page that the given link on the given
+    /// page points to, if it points to a page of the site: either a
+    /// relative link to its Markdown file or a link to its URL path
+    /// (with or without the `baseurl`).
+    pub fn resolve_link(&self, from: usize, url: &str) -> Option<usize> {
+        if url.contains("://") ||
+            url.starts_with('#') ||
+            url.starts_with("mailto:")
+        {
+            return None;
+        }
+        let url = url.split(['#', '?']).next().unwrap_or(url);
+        if url.is_empty() {
+            return None;
+        }
+        if url.starts_with('/') {
+            let base_url = self.base_url.as_deref().map(normalize_url_path);
+            let path = normalize_url_path(url);
+            let path = match base_url.as_deref() {
+                Some(base) if base != "/" => {
+                    path.strip_prefix(base.trim_end_matches('/'))
+                        .map(normalize_url_path)
+                        .unwrap_or(path.clone())
+                },
+                _ => path.clone(),
+            };
+            return self.pages.iter().position(|page| {
+                page.url_path() == path ||
+                    page.url_path() == normalize_url_path(url)
+            });
+        }
+        let directory = self.pages[from].path.parent().unwrap_or(Path::new(""));
+        let target = normalize_path(&directory.join(url));
+        self.pages.iter().position(|page| page.path == target)
+    }
+}
+
+/// Returns the given URL path with a leading slash and, unless it
+/// points to a file like `faq.html`, a trailing slash.
+fn normalize_url_path(path: &str) -> String {
+    let trimmed = path.trim_matches('/');
+    if trimmed.is_empty() {
+        "/".to_string()
+    } else if trimmed
+        .rsplit('/')
+        .next()
+        .is_some_and(|last| last.contains('.'))
+    {
+        format!("/{}", trimmed)
+    } else {
+        format!("/{}/", trimmed)
+    }
+}
+
+/// Resolves the `.` and `..` components of the given relative path.
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {},
+            Component::ParentDir => {
+                normalized.pop();
+            },
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn site(pages: &[(&str, &str)]) -> JekyllSite {
+        JekyllSite {
+            base_url: Some("/grapharch".to_string()),
+            pages:    pages
+                .iter()
+                .map(|(path, content)| {
+                    SitePage::new(PathBuf::from(path), content)
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_front_matter() {
+        let front_matter = FrontMatter::parse(
+            "---\ntitle: \"License\"\nparent: Other\nnav_order: \
+             99\npermalink: /license\n---\n# MIT License\n",
+        )
+        .unwrap();
+        assert_eq!(front_matter.title.as_deref(), Some("License"));
+        assert_eq!(front_matter.parent.as_deref(), Some("Other"));
+        assert_eq!(
+            front_matter.nav_order,
+            Some(NavOrder::Number(99.0))
+        );
+        assert_eq!(
+            front_matter.permalink.as_deref(),
+            Some("/license")
+        );
+        assert_eq!(FrontMatter::parse("# No front matter\n"), None);
+    }
+
+    #[test]
+    fn test_nav_tree_and_links() {
+        let site = site(&[
+            (
+                "index.md",
+                "---\ntitle: Home\nnav_order: 1\n---\n",
+            ),
+            (
+                "other/README.md",
+                "---\ntitle: Other\nnav_order: 3\n---\n",
+            ),
+            (
+                "other/LICENSE.md",
+                "---\ntitle: License\nparent: Other\nnav_order: \
+                 99\npermalink: /license\n---\n",
+            ),
+            (
+                "other/faq.md",
+                "---\ntitle: FAQ\nparent: Other\nnav_order: 2\n---\n",
+            ),
+            (
+                "features/README.md",
+                "---\ntitle: Features\nnav_order: 2\n---\n",
+            ),
+            (
+                "hidden.md",
+                "---\ntitle: Hidden\nnav_exclude: true\n---\n",
+            ),
+            (
+                "loop/a.md",
+                "---\ntitle: A\nparent: B\nnav_order: 4\n---\n",
+            ),
+            (
+                "loop/b.md",
+                "---\ntitle: B\nparent: A\nnav_order: 5\n---\n",
+            ),
+            ("loop/c.md", "---\ntitle: C\nparent: A\n---\n"),
+        ]);
+        // Pages that are their own ancestor are shown at the top level,
+        // with the pages below them
+        assert_eq!(site.nav_tree(), vec![
+            NavNode { page: 0, children: vec![] },
+            NavNode { page: 4, children: vec![] },
+            NavNode {
+                page:     1,
+                children: vec![
+                    NavNode { page: 3, children: vec![] },
+                    NavNode { page: 2, children: vec![] },
+                ],
+            },
+            NavNode {
+                page:     6,
+                children: vec![NavNode { page: 8, children: vec![] }],
+            },
+            NavNode { page: 7, children: vec![] },
+        ]);
+        assert_eq!(
+            site.resolve_link(0, "/grapharch/license"),
+            Some(2)
+        );
+        assert_eq!(site.resolve_link(0, "/license/"), Some(2));
+        assert_eq!(site.resolve_link(0, "./other/faq.md"), Some(3));
+        assert_eq!(
+            site.resolve_link(3, "../features/README.md#goals"),
+            Some(4)
+        );
+        assert_eq!(site.resolve_link(0, "/features/"), Some(4));
+        assert_eq!(site.resolve_link(0, "https://example.com/"), None);
+    }
+}
@@ -1,10 +1,27 @@
+mod ekgf;
+mod license;
 mod markdown;
 mod owl;
+mod profile;
+mod render;
+mod shacl;
+mod skos;
+mod source_locator;
 mod this;
 
 pub use {
+    ekgf::EKGFUseCaseDocumentorImpl,
+    license::LicenseDocumentorImpl,
     markdown::{JekyllMarkdownDocumentorImpl, MarkdownDocumentorImpl},
-    owl::{OWLClassDocumentorImpl, OWLOntologyDocumentorImpl},
+    owl::{
+        OWLClassDocumentorImpl,
+        OWLIndividualDocumentorImpl,
+        OWLOntologyDocumentorImpl,
+        OWLPropertyDocumentorImpl,
+    },
+    profile::DatasetProfileDocumentorImpl,
+    shacl::ShaclShapesDocumentorImpl,
+    skos::SKOSConceptSchemeDocumentorImpl,
     this::{
         Documentor,
         DocumentorCreator,
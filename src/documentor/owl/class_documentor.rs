@@ -1,16 +1,34 @@
 use {
     crate::{
-        documentor::{Documentor, DocumentorCreator},
-        model::Model,
+        documentor::{
+            Documentor,
+            DocumentorCreator,
+            render::{
+                documented_term,
+                header_blocks,
+                push_list,
+                push_references,
+                title,
+            },
+            source_locator::SourceLocator,
+        },
+        model::{BlockContent, Buildable, Chapter, Element, Model, Section},
         source::FileSourceImplementor,
-        store::LoaderStore,
-        util::{FileType, FileTypeSliceStatic},
+        store::{
+            AnnotationValue,
+            LoaderStore,
+            OWLClass,
+            OWLClassDescription,
+            PrefixRegistry,
+        },
+        util::{FileType, FileTypeSliceStatic, Languages},
     },
     async_trait::async_trait,
     std::{
         path::{Path, PathBuf},
         sync::{Arc, LazyLock},
     },
+    tracing::info,
 };
 
 static OWL_CLASS_DOCUMENTOR_FILE_TYPES: LazyLock<&'static [&'static FileType]> =
@@ -28,18 +46,30 @@ static OWL_CLASS_DOCUMENTOR_FILE_TYPES: LazyLock<&'static [&'static FileType]> =
     });
 
 /// A documentor for OWL classes.
+///
+/// Adds a chapter per class with its IRI and CURIE, its labels and
+/// definitions, its direct and indirect superclasses and subclasses,
+/// its equivalent and disjoint classes, its restrictions in
+/// Manchester syntax and the properties that have the class as
+/// their domain or range.
+///
+/// Documents the class given with `with_class`, or all classes in
+/// the graph of the file (or in the whole `LoaderStore` if there is no
+/// file) if no class was given.
 #[derive(Debug, Clone)]
 pub struct OWLClassDocumentorImpl {
-    #[allow(unused)]
     file_source:  Option<FileSourceImplementor>,
-    #[allow(unused)]
     file_name:    Option<PathBuf>,
-    #[allow(unused)]
     loader_store: LoaderStore,
     /// The given target documentation model that the
     /// OWLClassDocumentor will add its documentation to.
-    #[allow(unused)]
     doc_model:    Arc<Model>,
+    /// The class to document, if not all of them
+    class:        Option<OWLClass>,
+    /// The section to add the chapters to, if any
+    section:      Option<Section>,
+    /// The languages to pick the labels and definitions in
+    languages:    Languages,
 }
 
 impl DocumentorCreator for OWLClassDocumentorImpl {
@@ -53,7 +83,10 @@ impl DocumentorCreator for OWLClassDocumentorImpl {
             file_source,
             file_name: file_name.map(|f| f.to_path_buf()),
             loader_store,
+            languages: doc_model.languages().clone(),
             doc_model,
+            class: None,
+            section: None,
         }
     }
 }
@@ -65,10 +98,169 @@ impl Documentor for OWLClassDocumentorImpl {
     }
 
     async fn generate(&self) -> anyhow::Result<()> {
-        Err(anyhow::anyhow!(
-            "OWL class documentation generation not yet implemented"
-        ))
+        let classes = match &self.class {
+            Some(class) => vec![class.clone()],
+            None => {
+                let graph = match &self.file_name {
+                    Some(file_name) => {
+                        self.loader_store.graph_for_file(file_name)?
+                    },
+                    None => None,
+                };
+                self.loader_store.find_owl_classes(graph.as_ref())?
+            },
+        };
+        let prefixes = self.loader_store.prefix_registry()?;
+        let locator =
+            SourceLocator::new(&self.loader_store, self.file_source.as_ref());
+
+        for class in classes {
+            info!("Documenting OWL class {}", class.iri);
+            let description =
+                self.loader_store.describe_owl_class(&class, &prefixes)?;
+            let label = self.languages.pick(&class.labels);
+            let chapter_title = title(
+                label.map(|label| label.value.as_str()),
+                &class.iri,
+                &prefixes,
+            );
+            let term = documented_term(
+                "Class",
+                &class.iri,
+                &chapter_title,
+                &description.labels,
+                &description.definitions,
+                &self.languages,
+                &prefixes,
+            );
+            Chapter::builder_in_model::<Chapter>(&self.doc_model)?
+                .title(Some(chapter_title))
+                .language(label.and_then(|label| label.language.clone()))
+                .provenance(Some(locator.locate(&class.iri)?))
+                .term(Some(term))
+                .blocks(Self::content(
+                    &description,
+                    &self.languages,
+                    &prefixes,
+                ))
+                .section(self.section.as_ref())
+                .build()?;
+        }
+
+        Ok(())
     }
 
     fn file_name(&self) -> Option<&Path> { self.file_name.as_deref() }
 }
+
+impl OWLClassDocumentorImpl {
+    /// Only document the given class.
+    pub fn with_class(mut self, class: OWLClass) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    /// Adds the chapters to the given section.
+    pub fn with_section(mut self, section: Section) -> Self {
+        self.section = Some(section);
+        self
+    }
+
+    /// Picks the labels and definitions in the given languages instead
+    /// of the languages of the documentation model.
+    pub fn with_languages(mut self, languages: Languages) -> Self {
+        self.languages = languages;
+        self
+    }
+
+    fn content(
+        description: &OWLClassDescription,
+        languages: &Languages,
+        prefixes: &PrefixRegistry,
+    ) -> Vec<BlockContent> {
+        let mut blocks = header_blocks(
+            &description.class.iri,
+            &description.labels,
+            &description.definitions,
+            languages,
+            prefixes,
+        );
+        push_references(
+            &mut blocks,
+            "Superclasses",
+            &description.direct_superclasses,
+            prefixes,
+        );
+        push_references(
+            &mut blocks,
+            "Indirect superclasses",
+            &description.indirect_superclasses,
+            prefixes,
+        );
+        push_references(
+            &mut blocks,
+            "Subclasses",
+            &description.direct_subclasses,
+            prefixes,
+        );
+        push_references(
+            &mut blocks,
+            "Indirect subclasses",
+            &description.indirect_subclasses,
+            prefixes,
+        );
+        push_list(
+            &mut blocks,
+            "Equivalent to",
+            description.equivalent_classes.clone(),
+        );
+        push_list(
+            &mut blocks,
+            "Disjoint with",
+            description.disjoint_classes.clone(),
+        );
+        push_list(
+            &mut blocks,
+            "Restrictions",
+            description.restrictions.clone(),
+        );
+        push_references(
+            &mut blocks,
+            "Domain of",
+            &description.domain_of,
+            prefixes,
+        );
+        push_references(
+            &mut blocks,
+            "Range of",
+            &description.range_of,
+            prefixes,
+        );
+        if !description.enumeration.is_empty() {
+            blocks.push(BlockContent::heading("Enumeration (owl:oneOf)"));
+            blocks.push(BlockContent::table(
+                &["Value", "Label", "Description"],
+                description
+                    .enumeration
+                    .iter()
+                    .map(|member| {
+                        let value = |annotations| {
+                            languages
+                                .pick(annotations)
+                                .map(|annotation: &AnnotationValue| {
+                                    annotation.value.clone()
+                                })
+                                .unwrap_or_default()
+                        };
+                        vec![
+                            prefixes.curie_or_iri(&member.value),
+                            value(&member.labels),
+                            value(&member.descriptions),
+                        ]
+                    })
+                    .collect(),
+            ));
+        }
+        blocks
+    }
+}
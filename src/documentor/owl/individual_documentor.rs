@@ -0,0 +1,216 @@
+use {
+    crate::{
+        documentor::{
+            Documentor,
+            DocumentorCreator,
+            render::{
+                documented_term,
+                header_blocks,
+                push_list,
+                push_references,
+                title,
+            },
+            source_locator::SourceLocator,
+        },
+        model::{BlockContent, Buildable, Chapter, Element, Model, Section},
+        source::FileSourceImplementor,
+        store::{
+            LoaderStore,
+            OWLIndividual,
+            OWLIndividualDescription,
+            PrefixRegistry,
+        },
+        util::{FileType, FileTypeSliceStatic, Languages},
+    },
+    async_trait::async_trait,
+    std::{
+        path::{Path, PathBuf},
+        sync::{Arc, LazyLock},
+    },
+    tracing::info,
+};
+
+static OWL_INDIVIDUAL_DOCUMENTOR_FILE_TYPES: LazyLock<FileTypeSliceStatic> =
+    LazyLock::new(|| {
+        let file_types = vec![
+            &FileType::RdfXml,
+            &FileType::NTriples,
+            &FileType::JSONLD,
+            &FileType::Turtle,
+            &FileType::NQuads,
+            &FileType::N3,
+            &FileType::TriG,
+        ];
+        Box::leak(Box::new(file_types))
+    });
+
+/// A documentor for `owl:NamedIndividual`s.
+///
+/// Adds a chapter per individual with its types, its asserted
+/// property values (showing the labels of the resources they refer
+/// to) and its `owl:sameAs` and `owl:differentFrom` links.
+///
+/// Documents the individual given with `with_individual`, or all
+/// individuals in the graph of the file (or in the whole
+/// `LoaderStore` if there is no file) if no individual was given.
+#[derive(Debug, Clone)]
+pub struct OWLIndividualDocumentorImpl {
+    file_source:  Option<FileSourceImplementor>,
+    file_name:    Option<PathBuf>,
+    loader_store: LoaderStore,
+    /// The given target documentation model that the
+    /// OWLIndividualDocumentor will add its documentation to.
+    doc_model:    Arc<Model>,
+    /// The individual to document, if not all of them
+    individual:   Option<OWLIndividual>,
+    /// The section to add the chapters to, if any
+    section:      Option<Section>,
+    /// The languages to pick the labels and definitions in
+    languages:    Languages,
+}
+
+impl DocumentorCreator for OWLIndividualDocumentorImpl {
+    fn new(
+        file_source: Option<FileSourceImplementor>,
+        file_name: Option<&Path>,
+        loader_store: LoaderStore,
+        doc_model: Arc<Model>,
+    ) -> Self {
+        Self {
+            file_source,
+            file_name: file_name.map(|f| f.to_path_buf()),
+            loader_store,
+            languages: doc_model.languages().clone(),
+            doc_model,
+            individual: None,
+            section: None,
+        }
+    }
+}
+
+#[async_trait]
+impl Documentor for OWLIndividualDocumentorImpl {
+    fn file_types(&self) -> FileTypeSliceStatic {
+        *OWL_INDIVIDUAL_DOCUMENTOR_FILE_TYPES
+    }
+
+    async fn generate(&self) -> anyhow::Result<()> {
+        let individuals = match &self.individual {
+            Some(individual) => vec![individual.clone()],
+            None => {
+                let graph = match &self.file_name {
+                    Some(file_name) => {
+                        self.loader_store.graph_for_file(file_name)?
+                    },
+                    None => None,
+                };
+                self.loader_store.find_owl_individuals(graph.as_ref())?
+            },
+        };
+        let prefixes = self.loader_store.prefix_registry()?;
+        let locator =
+            SourceLocator::new(&self.loader_store, self.file_source.as_ref());
+
+        for individual in individuals {
+            info!("Documenting individual {}", individual.iri);
+            let description = self
+                .loader_store
+                .describe_owl_individual(&individual, &prefixes)?;
+            let label = self.languages.pick(&individual.labels);
+            let chapter_title = title(
+                label.map(|label| label.value.as_str()),
+                &individual.iri,
+                &prefixes,
+            );
+            let term = documented_term(
+                "Individual",
+                &individual.iri,
+                &chapter_title,
+                &description.labels,
+                &description.definitions,
+                &self.languages,
+                &prefixes,
+            );
+            Chapter::builder_in_model::<Chapter>(&self.doc_model)?
+                .title(Some(chapter_title))
+                .language(label.and_then(|label| label.language.clone()))
+                .provenance(Some(locator.locate(&individual.iri)?))
+                .term(Some(term))
+                .blocks(Self::content(
+                    &description,
+                    &self.languages,
+                    &prefixes,
+                ))
+                .section(self.section.as_ref())
+                .build()?;
+        }
+
+        Ok(())
+    }
+
+    fn file_name(&self) -> Option<&Path> { self.file_name.as_deref() }
+}
+
+impl OWLIndividualDocumentorImpl {
+    /// Only document the given individual.
+    pub fn with_individual(mut self, individual: OWLIndividual) -> Self {
+        self.individual = Some(individual);
+        self
+    }
+
+    /// Adds the chapters to the given section.
+    pub fn with_section(mut self, section: Section) -> Self {
+        self.section = Some(section);
+        self
+    }
+
+    /// Picks the labels and definitions in the given languages instead
+    /// of the languages of the documentation model.
+    pub fn with_languages(mut self, languages: Languages) -> Self {
+        self.languages = languages;
+        self
+    }
+
+    fn content(
+        description: &OWLIndividualDescription,
+        languages: &Languages,
+        prefixes: &PrefixRegistry,
+    ) -> Vec<BlockContent> {
+        let mut blocks = header_blocks(
+            &description.individual.iri,
+            &description.labels,
+            &description.definitions,
+            languages,
+            prefixes,
+        );
+        push_references(&mut blocks, "Types", &description.types, prefixes);
+        push_list(
+            &mut blocks,
+            "Property values",
+            description
+                .property_values
+                .iter()
+                .map(|pv| {
+                    format!(
+                        "{}: {}",
+                        prefixes.curie_or_iri(&pv.property),
+                        pv.label(languages)
+                    )
+                })
+                .collect(),
+        );
+        push_references(
+            &mut blocks,
+            "Same as",
+            &description.same_as,
+            prefixes,
+        );
+        push_references(
+            &mut blocks,
+            "Different from",
+            &description.different_from,
+            prefixes,
+        );
+        blocks
+    }
+}
@@ -1,7 +1,11 @@
 mod class_documentor;
+mod individual_documentor;
 mod ontology_documentor;
+mod property_documentor;
 
 pub use {
     class_documentor::OWLClassDocumentorImpl,
+    individual_documentor::OWLIndividualDocumentorImpl,
     ontology_documentor::OWLOntologyDocumentorImpl,
+    property_documentor::OWLPropertyDocumentorImpl,
 };
@@ -1,18 +1,45 @@
 use {
+    super::{
+        OWLClassDocumentorImpl,
+        OWLIndividualDocumentorImpl,
+        OWLPropertyDocumentorImpl,
+    },
     crate::{
-        documentor::{Documentor, DocumentorCreator},
-        model::{Book, Buildable, Chapter, Element, Model, Section},
+        documentor::{
+            Documentor,
+            DocumentorCreator,
+            render::{identity_definitions, names, push_list},
+            source_locator::SourceLocator,
+        },
+        model::{
+            BlockContent,
+            Book,
+            Buildable,
+            Chapter,
+            Definition,
+            Element,
+            Model,
+            Provenance,
+            Section,
+        },
         source::FileSourceImplementor,
-        store::LoaderStore,
-        util::{FileType, FileTypeSliceStatic},
+        store::{LoaderStore, OWLOntology, OWLPropertyKind, PrefixRegistry},
+        util::{
+            FileType,
+            FileTypeSliceStatic,
+            Languages,
+            Localized,
+            LocalizedText,
+        },
     },
     async_trait::async_trait,
     futures::future::{Future, join_all},
+    oxigraph::model::NamedNode,
     std::{
         path::{Path, PathBuf},
         sync::{Arc, LazyLock},
     },
-    tracing::info,
+    tracing::{info, warn},
 };
 
 static OWL_ONTOLOGY_DOCUMENTOR_FILE_TYPES: LazyLock<FileTypeSliceStatic> =
@@ -32,7 +59,6 @@ static OWL_ONTOLOGY_DOCUMENTOR_FILE_TYPES: LazyLock<FileTypeSliceStatic> =
 /// A documentor for OWL ontologies.
 #[derive(Debug, Clone)]
 pub struct OWLOntologyDocumentorImpl {
-    #[allow(unused)]
     file_source:  Option<FileSourceImplementor>,
     #[allow(unused)]
     file_name:    Option<PathBuf>,
@@ -67,64 +93,413 @@ impl Documentor for OWLOntologyDocumentorImpl {
     async fn generate(&self) -> anyhow::Result<()> {
         info!("Starting OWL ontology documentation generation");
 
-        // Create a book for the ontology
-        let title = self
-            .file_name
-            .as_ref()
-            .and_then(|f| f.file_stem())
-            .and_then(|s| s.to_str())
-            .unwrap_or("OWL Ontology")
-            .to_string();
+        let prefixes = self.loader_store.prefix_registry()?;
+        let ontology = self.ontology()?;
+        let locator =
+            SourceLocator::new(&self.loader_store, self.file_source.as_ref());
+
+        // One book, or one book per language, see `Languages::books`
+        for languages in self.doc_model.languages().books() {
+            self.document_book(
+                ontology.as_ref(),
+                &prefixes,
+                &locator,
+                &languages,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    fn file_name(&self) -> Option<&Path> { self.file_name.as_deref() }
+}
+
+impl OWLOntologyDocumentorImpl {
+    /// Adds a book about the ontology with the given header, if any,
+    /// in the given languages.
+    async fn document_book(
+        &self,
+        ontology: Option<&OWLOntology>,
+        prefixes: &PrefixRegistry,
+        locator: &SourceLocator,
+        languages: &Languages,
+    ) -> anyhow::Result<()> {
+        // Create a book for the ontology, titled and authored after its
+        // header if it has one
+        let title = ontology
+            .and_then(|o| languages.pick(&o.title))
+            .map(|title| title.value.clone())
+            .or_else(|| {
+                self.file_name
+                    .as_ref()
+                    .and_then(|f| f.file_stem())
+                    .and_then(|s| s.to_str())
+                    .map(|s| s.to_string())
+            })
+            .unwrap_or_else(|| "OWL Ontology".to_string());
         info!("Creating book with title: {}", title);
 
-        let _book = Book::builder_in_model::<Book>(&self.doc_model)?
-            .title(Some(title))
-            .author(Some("GraphArch".to_string()))
-            .build()?;
+        let provenance = match ontology {
+            Some(ontology) => {
+                let provenance = locator.locate(&ontology.iri)?;
+                // Files with the same content share their graph, so the
+                // ontology may have been located in another file than
+                // the one of this book
+                Some(match self.file_name.as_deref() {
+                    Some(file_name) => {
+                        Provenance {
+                            source: provenance.source,
+                            graph: provenance.graph,
+                            line: provenance.line,
+                            ..locator.locate_file(file_name, provenance.line)
+                        }
+                    },
+                    None => provenance,
+                })
+            },
+            None => {
+                self.file_name
+                    .as_deref()
+                    .map(|file_name| locator.locate_file(file_name, None))
+            },
+        };
+        // The same ontology in several files ends up in one book, see
+        // `Book::merge_books`
+        let key = match ontology {
+            Some(ontology) => Some(ontology.iri.clone()),
+            None => provenance.as_ref().and_then(|p| p.file.clone()),
+        };
+        let mut book =
+            Book::builder_in_model::<Book>(&self.doc_model)?
+                .key(key)
+                .title(Some(title.clone()))
+                .subtitle(ontology.map(|o| o.iri.clone()))
+                .version(ontology.and_then(|o| {
+                    o.version_info.clone().or(o.version_iri.clone())
+                }))
+                .language(
+                    languages
+                        .primary()
+                        .filter(|_| languages.book_per_language())
+                        .map(str::to_string),
+                )
+                .provenance(provenance);
+        let authors = ontology
+            .map(|o| o.creators.clone())
+            .filter(|creators| !creators.is_empty())
+            .unwrap_or_else(|| vec!["GraphArch".to_string()]);
+        for author in authors {
+            book = book.author(Some(author));
+        }
+        let book = book.build()?;
 
         info!("Book created successfully");
 
-        // Find all OWL classes in the loader store
+        if let Some(ontology) = ontology {
+            self.document_header(ontology, prefixes, languages, &book)?;
+        }
+
+        // Find all OWL classes in the graph of the file
         info!("Finding OWL classes in loader store");
-        let classes = self.loader_store.find_owl_classes()?;
+        let graph = self.graph()?;
+        let classes = self.loader_store.find_owl_classes(graph.as_ref())?;
         info!("Found {} OWL classes", classes.len());
 
         // Create a section for OWL classes
         info!("Creating section for OWL classes");
-        Section::builder_in_model::<Section>(&self.doc_model)?
+        let section = Section::builder_in_model::<Section>(&self.doc_model)?
             .title(Some("OWL Classes".to_string()))
             .description(Some(
                 "This section contains documentation for all OWL classes in \
                  the ontology."
                     .to_string(),
             ))
+            .book(Some(&book))
             .build()?;
         info!("Section created successfully");
 
-        // Create chapters for each OWL class
-        info!("Creating chapters for OWL classes");
+        // Document each OWL class in its own chapter, and keep track of
+        // the labels and definitions that are not translated
+        let required = languages.required();
+        let mut missing = Vec::new();
         for class in classes {
-            Chapter::builder_in_model::<Chapter>(&self.doc_model)?
-                .title(Some(
-                    class.label.unwrap_or_else(|| class.iri.clone()),
-                ))
-                .content(Some(
-                    class
-                        .comment
-                        .as_deref()
-                        .unwrap_or("No description available."),
-                ))
-                .build()?;
+            missing.extend(missing_translations(
+                "owl:Class",
+                &class.iri,
+                &class.labels,
+                &class.comments,
+                &required,
+                prefixes,
+            ));
+            OWLClassDocumentorImpl::new(
+                self.file_source.clone(),
+                self.file_name.as_deref(),
+                self.loader_store.clone(),
+                self.doc_model.clone(),
+            )
+            .with_class(class)
+            .with_section(section.clone())
+            .with_languages(languages.clone())
+            .generate()
+            .await?;
         }
         info!("Chapters created successfully");
 
+        // Create a section per kind of property, each property in its
+        // own chapter
+        for kind in OWLPropertyKind::ALL {
+            let properties = self
+                .loader_store
+                .find_owl_properties(kind, graph.as_ref())?;
+            info!(
+                "Found {} {}",
+                properties.len(),
+                kind.section_title()
+            );
+            if properties.is_empty() {
+                continue;
+            }
+            let section =
+                Section::builder_in_model::<Section>(&self.doc_model)?
+                    .title(Some(kind.section_title().to_string()))
+                    .description(Some(format!(
+                        "This section contains documentation for all {} in \
+                         the ontology.",
+                        kind.section_title().to_lowercase()
+                    )))
+                    .book(Some(&book))
+                    .build()?;
+            for property in properties {
+                missing.extend(missing_translations(
+                    &prefixes.curie_or_iri(kind.class_iri()),
+                    &property.iri,
+                    &property.labels,
+                    &property.comments,
+                    &required,
+                    prefixes,
+                ));
+                OWLPropertyDocumentorImpl::new(
+                    self.file_source.clone(),
+                    self.file_name.as_deref(),
+                    self.loader_store.clone(),
+                    self.doc_model.clone(),
+                )
+                .with_property(property)
+                .with_section(section.clone())
+                .with_languages(languages.clone())
+                .generate()
+                .await?;
+            }
+        }
+
+        // Create a section for the named individuals
+        let individuals =
+            self.loader_store.find_owl_individuals(graph.as_ref())?;
+        info!("Found {} named individuals", individuals.len());
+        if !individuals.is_empty() {
+            let section =
+                Section::builder_in_model::<Section>(&self.doc_model)?
+                    .title(Some("Named Individuals".to_string()))
+                    .description(Some(
+                        "This section contains documentation for all named \
+                         individuals in the ontology."
+                            .to_string(),
+                    ))
+                    .book(Some(&book))
+                    .build()?;
+            for individual in individuals {
+                missing.extend(missing_translations(
+                    "owl:NamedIndividual",
+                    &individual.iri,
+                    &individual.labels,
+                    &individual.comments,
+                    &required,
+                    prefixes,
+                ));
+                OWLIndividualDocumentorImpl::new(
+                    self.file_source.clone(),
+                    self.file_name.as_deref(),
+                    self.loader_store.clone(),
+                    self.doc_model.clone(),
+                )
+                .with_individual(individual)
+                .with_section(section.clone())
+                .with_languages(languages.clone())
+                .generate()
+                .await?;
+            }
+        }
+
+        if !missing.is_empty() {
+            warn!(
+                "{} resources in {} miss a label or comment in {}",
+                missing.len(),
+                title,
+                required.preferred().join(", ")
+            );
+            self.document_missing_translations(missing, &required, &book)?;
+        }
+
+        self.document_namespaces(prefixes, &book)?;
+
         Ok(())
     }
 
-    fn file_name(&self) -> Option<&Path> { self.file_name.as_deref() }
-}
+    /// Adds the "Namespaces" section with a table of the namespaces
+    /// that the graph of the ontology uses, or that the sources declare
+    /// if the ontology was not read from a file of its own.
+    fn document_namespaces(
+        &self,
+        prefixes: &PrefixRegistry,
+        book: &Book,
+    ) -> anyhow::Result<()> {
+        let namespaces = match self.graph()? {
+            Some(graph) => {
+                self.loader_store.used_namespaces(&graph, prefixes)?
+            },
+            None => prefixes.declared_mappings().into_iter().cloned().collect(),
+        };
+        if namespaces.is_empty() {
+            return Ok(());
+        }
+        let section = Section::builder_in_model::<Section>(&self.doc_model)?
+            .title(Some("Namespaces".to_string()))
+            .description(Some(
+                "This section lists the namespaces that the ontology uses."
+                    .to_string(),
+            ))
+            .book(Some(book))
+            .build()?;
+        Chapter::builder_in_model::<Chapter>(&self.doc_model)?
+            .title(Some("Namespaces".to_string()))
+            .blocks(vec![BlockContent::table(
+                &["Prefix", "Namespace"],
+                namespaces
+                    .into_iter()
+                    .map(|m| vec![m.prefix, m.namespace])
+                    .collect(),
+            )])
+            .section(Some(&section))
+            .build()?;
+        Ok(())
+    }
+
+    /// Adds the "Missing Translations" section with a table of the
+    /// given rows, see `missing_translations`.
+    fn document_missing_translations(
+        &self,
+        rows: Vec<Vec<String>>,
+        required: &Languages,
+        book: &Book,
+    ) -> anyhow::Result<()> {
+        let section = Section::builder_in_model::<Section>(&self.doc_model)?
+            .title(Some("Missing Translations".to_string()))
+            .description(Some(format!(
+                "This section lists the resources that lack a label or a \
+                 comment in {}.",
+                required.preferred().join(", ")
+            )))
+            .book(Some(book))
+            .build()?;
+        Chapter::builder_in_model::<Chapter>(&self.doc_model)?
+            .title(Some("Missing Translations".to_string()))
+            .blocks(vec![BlockContent::table(
+                &["Resource", "Kind", "Missing labels", "Missing comments"],
+                rows,
+            )])
+            .section(Some(&section))
+            .build()?;
+        Ok(())
+    }
+
+    /// Returns the named graph of the file that is documented, if any.
+    fn graph(&self) -> anyhow::Result<Option<NamedNode>> {
+        match &self.file_name {
+            Some(file_name) => self.loader_store.graph_for_file(file_name),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the header of the ontology in the file that this
+    /// documentor is about, or of the first ontology in the store if
+    /// there is no file.
+    fn ontology(&self) -> anyhow::Result<Option<OWLOntology>> {
+        let graph = self.graph()?;
+        if self.file_name.is_some() && graph.is_none() {
+            return Ok(None);
+        }
+        Ok(self
+            .loader_store
+            .find_owl_ontologies(graph.as_ref())?
+            .into_iter()
+            .next())
+    }
+
+    /// Adds the "About this ontology" section with the metadata from
+    /// the header of the ontology, described in the given languages.
+    fn document_header(
+        &self,
+        ontology: &OWLOntology,
+        prefixes: &PrefixRegistry,
+        languages: &Languages,
+        book: &Book,
+    ) -> anyhow::Result<()> {
+        let section = Section::builder_in_model::<Section>(&self.doc_model)?
+            .title(Some("About this ontology".to_string()))
+            .description(Some(
+                languages
+                    .pick(&ontology.description)
+                    .map(|description| description.value.clone())
+                    .unwrap_or_else(|| "No description available.".to_string()),
+            ))
+            .book(Some(book))
+            .build()?;
+
+        let mut entries = identity_definitions(&ontology.iri, prefixes);
+        let fields = [
+            ("Version IRI", &ontology.version_iri),
+            ("Version", &ontology.version_info),
+            ("License", &ontology.license),
+            ("Issued", &ontology.issued),
+            ("Modified", &ontology.modified),
+            ("Preferred prefix", &ontology.preferred_prefix),
+            (
+                "Preferred namespace",
+                &ontology.preferred_namespace,
+            ),
+        ];
+        for (name, value) in fields {
+            if let Some(value) = value {
+                entries.push(Definition::new(name, value.clone()));
+            }
+        }
+        let mut blocks = vec![BlockContent::DefinitionList(entries)];
+        push_list(&mut blocks, "Creators", ontology.creators.clone());
+        push_list(
+            &mut blocks,
+            "Contributors",
+            ontology.contributors.clone(),
+        );
+        push_list(
+            &mut blocks,
+            "Imports",
+            names(&ontology.imports, prefixes),
+        );
+        push_list(
+            &mut blocks,
+            "Prior versions",
+            names(&ontology.prior_versions, prefixes),
+        );
+
+        Chapter::builder_in_model::<Chapter>(&self.doc_model)?
+            .title(Some("Ontology Metadata".to_string()))
+            .blocks(blocks)
+            .section(Some(&section))
+            .build()?;
+        Ok(())
+    }
 
-impl OWLOntologyDocumentorImpl {
     /// Iterate over all OWL classes in the model and call the
     /// given closure or function with an OWLOntologyDocumentor for
     /// each class.
@@ -138,7 +513,8 @@ impl OWLOntologyDocumentorImpl {
         F: FnMut(OWLOntologyDocumentorImpl) -> Fut,
         Fut: Future<Output = anyhow::Result<()>>,
     {
-        let classes = self.loader_store.find_owl_classes()?;
+        let classes =
+            self.loader_store.find_owl_classes(self.graph()?.as_ref())?;
         let mut futures = Vec::new();
 
         for _class in classes {
@@ -156,3 +532,36 @@ impl OWLOntologyDocumentorImpl {
         Ok(())
     }
 }
+
+/// Returns the row of the "Missing Translations" table for the resource
+/// with the given IRI if its labels or its comments are not in all the
+/// given languages. Texts without a language tag are left out, an
+/// ontology that has no language tags is not translated.
+fn missing_translations(
+    kind: &str,
+    iri: &str,
+    labels: &[LocalizedText],
+    comments: &[LocalizedText],
+    required: &Languages,
+    prefixes: &PrefixRegistry,
+) -> Option<Vec<String>> {
+    let missing = |texts: &[LocalizedText]| {
+        let tags: Vec<&str> =
+            texts.iter().filter_map(|text| text.language()).collect();
+        if tags.is_empty() {
+            Vec::new()
+        } else {
+            required.missing(&tags)
+        }
+    };
+    let (labels, comments) = (missing(labels), missing(comments));
+    if labels.is_empty() && comments.is_empty() {
+        return None;
+    }
+    Some(vec![
+        prefixes.curie_or_iri(iri),
+        kind.to_string(),
+        labels.join(", "),
+        comments.join(", "),
+    ])
+}
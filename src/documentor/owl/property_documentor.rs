@@ -0,0 +1,288 @@
+use {
+    crate::{
+        documentor::{
+            Documentor,
+            DocumentorCreator,
+            render::{
+                documented_term,
+                header_blocks,
+                push_list,
+                push_references,
+                title,
+            },
+            source_locator::SourceLocator,
+        },
+        model::{
+            BlockContent,
+            Buildable,
+            Chapter,
+            Element,
+            Inline,
+            Model,
+            Section,
+        },
+        source::FileSourceImplementor,
+        store::{
+            LoaderStore,
+            OWLProperty,
+            OWLPropertyDescription,
+            OWLPropertyKind,
+            PrefixRegistry,
+        },
+        util::{FileType, FileTypeSliceStatic, Languages},
+    },
+    async_trait::async_trait,
+    oxrdf::NamedNodeRef,
+    std::{
+        path::{Path, PathBuf},
+        sync::{Arc, LazyLock},
+    },
+    tracing::info,
+};
+
+static OWL_PROPERTY_DOCUMENTOR_FILE_TYPES: LazyLock<FileTypeSliceStatic> =
+    LazyLock::new(|| {
+        let file_types = vec![
+            &FileType::RdfXml,
+            &FileType::NTriples,
+            &FileType::JSONLD,
+            &FileType::Turtle,
+            &FileType::NQuads,
+            &FileType::N3,
+            &FileType::TriG,
+        ];
+        Box::leak(Box::new(file_types))
+    });
+
+/// A documentor for object, datatype and annotation properties and
+/// plain `rdf:Property`s.
+///
+/// Adds a chapter per property with its domains and ranges, its
+/// super- and sub-properties, its inverses, its characteristics
+/// (functional, transitive, symmetric, etc.), its property chains and
+/// the number of triples that use it.
+///
+/// Documents the property given with `with_property`, or all
+/// properties in the graph of the file (or in the whole `LoaderStore`
+/// if there is no file) if no property was given.
+#[derive(Debug, Clone)]
+pub struct OWLPropertyDocumentorImpl {
+    file_source:  Option<FileSourceImplementor>,
+    file_name:    Option<PathBuf>,
+    loader_store: LoaderStore,
+    /// The given target documentation model that the
+    /// OWLPropertyDocumentor will add its documentation to.
+    doc_model:    Arc<Model>,
+    /// The property to document, if not all of them
+    property:     Option<OWLProperty>,
+    /// The section to add the chapters to, if any
+    section:      Option<Section>,
+    /// The languages to pick the labels and definitions in
+    languages:    Languages,
+}
+
+impl DocumentorCreator for OWLPropertyDocumentorImpl {
+    fn new(
+        file_source: Option<FileSourceImplementor>,
+        file_name: Option<&Path>,
+        loader_store: LoaderStore,
+        doc_model: Arc<Model>,
+    ) -> Self {
+        Self {
+            file_source,
+            file_name: file_name.map(|f| f.to_path_buf()),
+            loader_store,
+            languages: doc_model.languages().clone(),
+            doc_model,
+            property: None,
+            section: None,
+        }
+    }
+}
+
+#[async_trait]
+impl Documentor for OWLPropertyDocumentorImpl {
+    fn file_types(&self) -> FileTypeSliceStatic {
+        *OWL_PROPERTY_DOCUMENTOR_FILE_TYPES
+    }
+
+    async fn generate(&self) -> anyhow::Result<()> {
+        let properties = match &self.property {
+            Some(property) => vec![property.clone()],
+            None => {
+                let graph = match &self.file_name {
+                    Some(file_name) => {
+                        self.loader_store.graph_for_file(file_name)?
+                    },
+                    None => None,
+                };
+                let mut properties = Vec::new();
+                for kind in OWLPropertyKind::ALL {
+                    properties.extend(
+                        self.loader_store
+                            .find_owl_properties(kind, graph.as_ref())?,
+                    );
+                }
+                properties
+            },
+        };
+        let prefixes = self.loader_store.prefix_registry()?;
+        let locator =
+            SourceLocator::new(&self.loader_store, self.file_source.as_ref());
+
+        for property in properties {
+            info!("Documenting property {}", property.iri);
+            let description = self
+                .loader_store
+                .describe_owl_property(&property, &prefixes)?;
+            let label = self.languages.pick(&property.labels);
+            let chapter_title = title(
+                label.map(|label| label.value.as_str()),
+                &property.iri,
+                &prefixes,
+            );
+            let term = documented_term(
+                property.kind.term_kind(),
+                &property.iri,
+                &chapter_title,
+                &description.labels,
+                &description.definitions,
+                &self.languages,
+                &prefixes,
+            );
+            Chapter::builder_in_model::<Chapter>(&self.doc_model)?
+                .title(Some(chapter_title))
+                .language(label.and_then(|label| label.language.clone()))
+                .provenance(Some(locator.locate(&property.iri)?))
+                .term(Some(term))
+                .blocks(Self::content(
+                    &description,
+                    &self.languages,
+                    &prefixes,
+                ))
+                .section(self.section.as_ref())
+                .build()?;
+        }
+
+        Ok(())
+    }
+
+    fn file_name(&self) -> Option<&Path> { self.file_name.as_deref() }
+}
+
+impl OWLPropertyDocumentorImpl {
+    /// Only document the given property.
+    pub fn with_property(mut self, property: OWLProperty) -> Self {
+        self.property = Some(property);
+        self
+    }
+
+    /// Adds the chapters to the given section.
+    pub fn with_section(mut self, section: Section) -> Self {
+        self.section = Some(section);
+        self
+    }
+
+    /// Picks the labels and definitions in the given languages instead
+    /// of the languages of the documentation model.
+    pub fn with_languages(mut self, languages: Languages) -> Self {
+        self.languages = languages;
+        self
+    }
+
+    fn content(
+        description: &OWLPropertyDescription,
+        languages: &Languages,
+        prefixes: &PrefixRegistry,
+    ) -> Vec<BlockContent> {
+        let mut blocks = header_blocks(
+            &description.property.iri,
+            &description.labels,
+            &description.definitions,
+            languages,
+            prefixes,
+        );
+        if !description.characteristics.is_empty() {
+            blocks.push(BlockContent::paragraph(format!(
+                "Characteristics: {}",
+                description.characteristics.join(", ")
+            )));
+        }
+        push_class_expressions(
+            &mut blocks,
+            "Domain",
+            &description.domains,
+            &description.domain_iris,
+            prefixes,
+        );
+        push_class_expressions(
+            &mut blocks,
+            "Range",
+            &description.ranges,
+            &description.range_iris,
+            prefixes,
+        );
+        push_references(
+            &mut blocks,
+            "Super-properties",
+            &description.super_properties,
+            prefixes,
+        );
+        push_references(
+            &mut blocks,
+            "Sub-properties",
+            &description.sub_properties,
+            prefixes,
+        );
+        push_list(
+            &mut blocks,
+            "Inverse of",
+            description.inverses.clone(),
+        );
+        push_list(
+            &mut blocks,
+            "Property chains",
+            description.property_chains.clone(),
+        );
+        blocks.push(BlockContent::paragraph(format!(
+            "Used in {} triples.",
+            description.usage_count
+        )));
+        blocks
+    }
+}
+
+/// Adds a heading with a bulleted list of the given class expressions,
+/// with a cross-reference for each named class, unless there are no
+/// expressions.
+fn push_class_expressions(
+    blocks: &mut Vec<BlockContent>,
+    heading: &str,
+    expressions: &[String],
+    iris: &[String],
+    prefixes: &PrefixRegistry,
+) {
+    if expressions.is_empty() {
+        return;
+    }
+    blocks.push(BlockContent::heading(heading));
+    blocks.push(BlockContent::List {
+        numbered: false,
+        items:    expressions
+            .iter()
+            .map(|expression| {
+                let named = iris.iter().find(|iri| {
+                    NamedNodeRef::new(iri.as_str()).is_ok_and(|iri| {
+                        prefixes.display_name(iri) == *expression
+                    })
+                });
+                match named {
+                    Some(iri) => {
+                        vec![Inline::reference(iri, expression.clone())]
+                    },
+                    None => vec![Inline::text(expression.clone())],
+                }
+            })
+            .collect(),
+    });
+}
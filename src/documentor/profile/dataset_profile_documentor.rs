@@ -0,0 +1,227 @@
+use {
+    crate::{
+        documentor::{Documentor, DocumentorCreator},
+        model::{
+            BlockContent,
+            Book,
+            Buildable,
+            Chapter,
+            Element,
+            Model,
+            Section,
+        },
+        source::FileSourceImplementor,
+        store::{GraphProfile, LoaderStore, PrefixRegistry, TermCount},
+    },
+    async_trait::async_trait,
+    oxigraph::model::Term,
+    std::{
+        path::{Path, PathBuf},
+        sync::Arc,
+    },
+    tracing::info,
+};
+
+/// The maximum number of example values shown per property.
+const TOP_N_EXAMPLE_VALUES: usize = 5;
+
+/// A documentor that profiles the instance data in the
+/// `LoaderStore`.
+///
+/// It adds a "Data Profile" book with a section per loaded graph,
+/// covering triple counts, class instance counts, predicate usage,
+/// literal datatype and language distributions, value cardinalities
+/// per class/property pair and the most used values per property.
+/// This is the code behind the "Business-Oriented Profiling" feature.
+#[derive(Debug, Clone)]
+pub struct DatasetProfileDocumentorImpl {
+    #[allow(unused)]
+    file_source:  Option<FileSourceImplementor>,
+    file_name:    Option<PathBuf>,
+    loader_store: LoaderStore,
+    /// The given target documentation model that the
+    /// DatasetProfileDocumentor will add its documentation to.
+    doc_model:    Arc<Model>,
+}
+
+impl DocumentorCreator for DatasetProfileDocumentorImpl {
+    fn new(
+        file_source: Option<FileSourceImplementor>,
+        file_name: Option<&Path>,
+        loader_store: LoaderStore,
+        doc_model: Arc<Model>,
+    ) -> Self {
+        Self {
+            file_source,
+            file_name: file_name.map(|f| f.to_path_buf()),
+            loader_store,
+            doc_model,
+        }
+    }
+}
+
+#[async_trait]
+impl Documentor for DatasetProfileDocumentorImpl {
+    fn file_name(&self) -> Option<&Path> { self.file_name.as_deref() }
+
+    async fn generate(&self) -> anyhow::Result<()> {
+        info!("Starting dataset profiling");
+
+        let graphs = self.loader_store.data_graphs()?;
+        if graphs.is_empty() {
+            return Ok(());
+        }
+        let prefixes = self.loader_store.prefix_registry()?;
+
+        let book = Book::builder_in_model::<Book>(&self.doc_model)?
+            .key(Some("data-profile".to_string()))
+            .title(Some("Data Profile".to_string()))
+            .author(Some("GraphArch".to_string()))
+            .build()?;
+
+        for graph in graphs {
+            info!("Profiling graph {}", graph.graph);
+            let profile = self
+                .loader_store
+                .profile_graph(&graph, TOP_N_EXAMPLE_VALUES)?;
+            self.document_profile(&profile, &prefixes, &book)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl DatasetProfileDocumentorImpl {
+    fn document_profile(
+        &self,
+        profile: &GraphProfile,
+        prefixes: &PrefixRegistry,
+        book: &Book,
+    ) -> anyhow::Result<()> {
+        let graph_name = profile
+            .graph
+            .file_name
+            .clone()
+            .unwrap_or_else(|| profile.graph.graph.as_str().to_string());
+
+        let section = Section::builder_in_model::<Section>(&self.doc_model)?
+            .title(Some(format!("Data Profile: {}", graph_name)))
+            .description(Some(format!(
+                "The graph contains {} triples, {} classes with instances and \
+                 {} predicates.",
+                profile.triple_count,
+                profile.class_instance_counts.len(),
+                profile.predicate_usage.len()
+            )))
+            .book(Some(book))
+            .build()?;
+
+        self.chapter(
+            &section,
+            "Class Instances",
+            &["Class", "Count"],
+            Self::term_count_rows(&profile.class_instance_counts, prefixes),
+        )?;
+        self.chapter(
+            &section,
+            "Predicate Usage",
+            &["Predicate", "Count"],
+            Self::term_count_rows(&profile.predicate_usage, prefixes),
+        )?;
+        self.chapter(
+            &section,
+            "Literal Datatypes",
+            &["Datatype", "Count"],
+            Self::term_count_rows(&profile.datatype_distribution, prefixes),
+        )?;
+        self.chapter(
+            &section,
+            "Literal Languages",
+            &["Language", "Count"],
+            Self::term_count_rows(&profile.language_distribution, prefixes),
+        )?;
+        self.chapter(
+            &section,
+            "Value Cardinalities",
+            &["Class", "Property", "Instances", "Min", "Max"],
+            profile
+                .property_cardinalities
+                .iter()
+                .map(|c| {
+                    vec![
+                        prefixes.curie_or_iri(&c.class),
+                        prefixes.curie_or_iri(&c.property),
+                        c.instances.to_string(),
+                        c.min.to_string(),
+                        c.max.to_string(),
+                    ]
+                })
+                .collect(),
+        )?;
+        self.chapter(
+            &section,
+            "Example Values",
+            &["Property", "Values"],
+            profile
+                .example_values
+                .iter()
+                .map(|e| {
+                    vec![
+                        prefixes.curie_or_iri(&e.property),
+                        e.values
+                            .iter()
+                            .map(|(value, count)| {
+                                format!(
+                                    "{} ({})",
+                                    Self::value_name(value, prefixes),
+                                    count
+                                )
+                            })
+                            .collect::<Vec<String>>()
+                            .join(", "),
+                    ]
+                })
+                .collect(),
+        )?;
+
+        Ok(())
+    }
+
+    /// Adds a chapter with a table of the given rows as its content,
+    /// unless there are no rows.
+    fn chapter(
+        &self,
+        section: &Section,
+        title: &str,
+        header: &[&str],
+        rows: Vec<Vec<String>>,
+    ) -> anyhow::Result<()> {
+        if rows.is_empty() {
+            return Ok(());
+        }
+        Chapter::builder_in_model::<Chapter>(&self.doc_model)?
+            .title(Some(title.to_string()))
+            .blocks(vec![BlockContent::table(header, rows)])
+            .section(Some(section))
+            .build()?;
+        Ok(())
+    }
+
+    fn term_count_rows(
+        counts: &[TermCount],
+        prefixes: &PrefixRegistry,
+    ) -> Vec<Vec<String>> {
+        counts
+            .iter()
+            .map(|c| vec![prefixes.curie_or_iri(&c.term), c.count.to_string()])
+            .collect()
+    }
+
+    fn value_name(value: &Term, prefixes: &PrefixRegistry) -> String {
+        match value {
+            Term::NamedNode(node) => prefixes.curie_or_iri(node.as_str()),
+            Term::Literal(literal) => format!("\"{}\"", literal.value()),
+            other => other.to_string(),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod dataset_profile_documentor;
+
+pub use dataset_profile_documentor::DatasetProfileDocumentorImpl;
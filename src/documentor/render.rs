@@ -0,0 +1,204 @@
+use {
+    crate::{
+        model::{BlockContent, Definition, DocumentedTerm, Inline},
+        store::{AnnotationValue, PrefixRegistry},
+        util::Languages,
+    },
+    oxrdf::NamedNodeRef,
+};
+
+/// Returns the given label, or otherwise the CURIE or local name of
+/// the given IRI.
+pub(super) fn title(
+    label: Option<&str>,
+    iri: &str,
+    prefixes: &PrefixRegistry,
+) -> String {
+    label.map(|l| l.to_string()).unwrap_or_else(|| {
+        NamedNodeRef::new(iri)
+            .map(|iri| prefixes.display_name(iri))
+            .unwrap_or_else(|_| iri.to_string())
+    })
+}
+
+/// Returns the term that a chapter about the resource with the given
+/// IRI and title documents, for the glossary and index of its book:
+/// its CURIE, its other labels in the given languages, leaving out
+/// hidden labels, and its definition in the most preferred language.
+pub(super) fn documented_term(
+    kind: &str,
+    iri: &str,
+    title: &str,
+    labels: &[AnnotationValue],
+    definitions: &[AnnotationValue],
+    languages: &Languages,
+    prefixes: &PrefixRegistry,
+) -> DocumentedTerm {
+    let required = languages.required();
+    let mut alternative_labels: Vec<String> = Vec::new();
+    for label in labels {
+        let in_language = match &label.language {
+            Some(tag) => {
+                required.is_empty() ||
+                    required
+                        .preferred()
+                        .iter()
+                        .any(|language| Languages::matches(tag, language))
+            },
+            None => true,
+        };
+        if in_language &&
+            !label.property.ends_with("#hiddenLabel") &&
+            label.value != title &&
+            !alternative_labels.contains(&label.value)
+        {
+            alternative_labels.push(label.value.clone());
+        }
+    }
+    DocumentedTerm {
+        kind: kind.to_string(),
+        curie: prefixes.curie(iri),
+        alternative_labels,
+        definition: languages
+            .pick(definitions)
+            .map(|definition| definition.value.clone()),
+    }
+}
+
+/// Returns the first blocks of the content of a chapter about the
+/// resource with the given IRI: a definition list with its IRI, its
+/// CURIE and its labels and definitions in the given languages.
+pub(super) fn header_blocks(
+    iri: &str,
+    labels: &[AnnotationValue],
+    definitions: &[AnnotationValue],
+    languages: &Languages,
+    prefixes: &PrefixRegistry,
+) -> Vec<BlockContent> {
+    let mut entries = identity_definitions(iri, prefixes);
+    entries.extend(
+        preferred_annotations(labels, languages)
+            .iter()
+            .chain(&preferred_annotations(definitions, languages))
+            .map(|annotation| annotation_definition(annotation, prefixes)),
+    );
+    let mut blocks = vec![BlockContent::DefinitionList(entries)];
+    if definitions.is_empty() {
+        blocks.push(no_description("No description available."));
+    }
+    blocks
+}
+
+/// Returns the annotation in the most preferred language of each
+/// annotation property, like the Dutch `skos:prefLabel` and the Dutch
+/// `rdfs:label` for `nl`, or all annotations if no languages are
+/// given.
+pub(super) fn preferred_annotations(
+    annotations: &[AnnotationValue],
+    languages: &Languages,
+) -> Vec<AnnotationValue> {
+    if languages.is_empty() {
+        return annotations.to_vec();
+    }
+    let mut properties: Vec<&str> = Vec::new();
+    for annotation in annotations {
+        if !properties.contains(&annotation.property.as_str()) {
+            properties.push(&annotation.property);
+        }
+    }
+    properties
+        .into_iter()
+        .filter_map(|property| {
+            let values: Vec<AnnotationValue> = annotations
+                .iter()
+                .filter(|annotation| annotation.property == property)
+                .cloned()
+                .collect();
+            languages.pick(&values).cloned()
+        })
+        .collect()
+}
+
+/// Returns the definitions of the IRI and, if there is one, the CURIE
+/// of a resource.
+pub(super) fn identity_definitions(
+    iri: &str,
+    prefixes: &PrefixRegistry,
+) -> Vec<Definition> {
+    let mut entries = vec![Definition {
+        term:        "IRI".to_string(),
+        description: vec![Inline::code(iri)],
+    }];
+    if let Some(curie) = prefixes.curie(iri) {
+        entries.push(Definition {
+            term:        "CURIE".to_string(),
+            description: vec![Inline::Code(curie)],
+        });
+    }
+    entries
+}
+
+/// Returns an emphasized paragraph that says that something is
+/// missing, like a description.
+pub(super) fn no_description(text: &str) -> BlockContent {
+    BlockContent::Paragraph(vec![Inline::Emphasis(vec![Inline::text(text)])])
+}
+
+/// Adds a heading with a bulleted list of the given items, unless
+/// there are no items.
+pub(super) fn push_list(
+    blocks: &mut Vec<BlockContent>,
+    heading: &str,
+    items: Vec<String>,
+) {
+    if items.is_empty() {
+        return;
+    }
+    blocks.push(BlockContent::heading(heading));
+    blocks.push(BlockContent::bullets(items));
+}
+
+/// Adds a heading with a bulleted list of cross-references to the
+/// chapters that document the given IRIs, each shown as its CURIE,
+/// unless there are no IRIs.
+pub(super) fn push_references(
+    blocks: &mut Vec<BlockContent>,
+    heading: &str,
+    iris: &[String],
+    prefixes: &PrefixRegistry,
+) {
+    if iris.is_empty() {
+        return;
+    }
+    blocks.push(BlockContent::heading(heading));
+    blocks.push(BlockContent::List {
+        numbered: false,
+        items:    iris
+            .iter()
+            .map(|iri| vec![Inline::reference(iri, prefixes.curie_or_iri(iri))])
+            .collect(),
+    });
+}
+
+/// Returns the CURIEs (or IRIs) of the given IRIs.
+pub(super) fn names(iris: &[String], prefixes: &PrefixRegistry) -> Vec<String> {
+    iris.iter().map(|iri| prefixes.curie_or_iri(iri)).collect()
+}
+
+/// Returns an annotation as a definition with the CURIE of the
+/// annotation property as its term, like `skos:definition` with the
+/// description `A car. @en`.
+pub(super) fn annotation_definition(
+    annotation: &AnnotationValue,
+    prefixes: &PrefixRegistry,
+) -> Definition {
+    let mut description = vec![Inline::text(annotation.value.clone())];
+    if let Some(language) = &annotation.language {
+        description.push(Inline::text(" "));
+        description.push(Inline::Code(format!("@{}", language)));
+    }
+    Definition {
+        term: prefixes.curie_or_iri(&annotation.property),
+        description,
+    }
+}
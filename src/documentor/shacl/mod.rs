@@ -0,0 +1,3 @@
+mod shapes_documentor;
+
+pub use shapes_documentor::ShaclShapesDocumentorImpl;
@@ -0,0 +1,356 @@
+use {
+    crate::{
+        documentor::{
+            Documentor,
+            DocumentorCreator,
+            source_locator::SourceLocator,
+        },
+        model::{
+            AdmonitionKind,
+            BlockContent,
+            Book,
+            Buildable,
+            Chapter,
+            Definition,
+            Element,
+            Inline,
+            Model,
+            Section,
+        },
+        source::FileSourceImplementor,
+        store::{
+            LoaderStore,
+            NodeShape,
+            PrefixRegistry,
+            PropertyShape,
+            ShapeTarget,
+        },
+        util::{FileType, FileTypeSliceStatic},
+    },
+    async_trait::async_trait,
+    oxrdf::{NamedNode, Subject, Term},
+    std::{
+        path::{Path, PathBuf},
+        sync::{Arc, LazyLock},
+    },
+    tracing::info,
+};
+
+static SHACL_SHAPES_DOCUMENTOR_FILE_TYPES: LazyLock<FileTypeSliceStatic> =
+    LazyLock::new(|| {
+        let file_types = vec![
+            &FileType::RdfXml,
+            &FileType::NTriples,
+            &FileType::JSONLD,
+            &FileType::Turtle,
+            &FileType::NQuads,
+            &FileType::N3,
+            &FileType::TriG,
+        ];
+        Box::leak(Box::new(file_types))
+    });
+
+/// A documentor for SHACL shapes graphs.
+///
+/// Adds a book with a chapter per `sh:NodeShape` that lists its
+/// targets, whether it is closed, its messages and a table with the
+/// constraints of its property shapes. Class targets that are
+/// documented as OWL classes refer to the chapter of that class.
+#[derive(Debug, Clone)]
+pub struct ShaclShapesDocumentorImpl {
+    file_source:  Option<FileSourceImplementor>,
+    file_name:    Option<PathBuf>,
+    loader_store: LoaderStore,
+    /// The given target documentation model that the
+    /// ShaclShapesDocumentor will add its documentation to.
+    doc_model:    Arc<Model>,
+}
+
+impl DocumentorCreator for ShaclShapesDocumentorImpl {
+    fn new(
+        file_source: Option<FileSourceImplementor>,
+        file_name: Option<&Path>,
+        loader_store: LoaderStore,
+        doc_model: Arc<Model>,
+    ) -> Self {
+        Self {
+            file_source,
+            file_name: file_name.map(|f| f.to_path_buf()),
+            loader_store,
+            doc_model,
+        }
+    }
+}
+
+#[async_trait]
+impl Documentor for ShaclShapesDocumentorImpl {
+    fn file_types(&self) -> FileTypeSliceStatic {
+        *SHACL_SHAPES_DOCUMENTOR_FILE_TYPES
+    }
+
+    async fn generate(&self) -> anyhow::Result<()> {
+        let graph = match &self.file_name {
+            Some(file_name) => self.loader_store.graph_for_file(file_name)?,
+            None => None,
+        };
+        let shapes = self.loader_store.find_shacl_node_shapes(graph.as_ref());
+        info!("Found {} SHACL node shapes", shapes.len());
+        if shapes.is_empty() {
+            return Ok(());
+        }
+        let prefixes = self.loader_store.prefix_registry()?;
+        let locator =
+            SourceLocator::new(&self.loader_store, self.file_source.as_ref());
+
+        let title = self
+            .file_name
+            .as_ref()
+            .and_then(|f| f.file_stem())
+            .and_then(|s| s.to_str())
+            .map(|s| format!("{} Shapes", s))
+            .unwrap_or_else(|| "SHACL Shapes".to_string());
+        let book = Book::builder_in_model::<Book>(&self.doc_model)?
+            .key(
+                graph
+                    .as_ref()
+                    .map(|graph| format!("shapes:{}", graph.as_str())),
+            )
+            .title(Some(title))
+            .author(Some("GraphArch".to_string()))
+            .build()?;
+        let section = Section::builder_in_model::<Section>(&self.doc_model)?
+            .title(Some("Node Shapes".to_string()))
+            .description(Some(
+                "This section contains documentation for all SHACL node \
+                 shapes in the shapes graph."
+                    .to_string(),
+            ))
+            .book(Some(&book))
+            .build()?;
+
+        for shape in shapes {
+            info!("Documenting SHACL node shape {}", shape.node);
+            Chapter::builder_in_model::<Chapter>(&self.doc_model)?
+                .title(Some(shape.label.clone().unwrap_or_else(|| {
+                    subject_name(&shape.node, &prefixes)
+                })))
+                .provenance(
+                    Self::source(&shape)
+                        .map(|iri| locator.locate(&iri))
+                        .transpose()?,
+                )
+                .blocks(Self::content(&shape, &prefixes))
+                .section(Some(&section))
+                .build()?;
+        }
+
+        Ok(())
+    }
+
+    fn file_name(&self) -> Option<&Path> { self.file_name.as_deref() }
+}
+
+impl ShaclShapesDocumentorImpl {
+    fn content(
+        shape: &NodeShape,
+        prefixes: &PrefixRegistry,
+    ) -> Vec<BlockContent> {
+        let mut entries = vec![Definition {
+            term:        "Shape".to_string(),
+            description: vec![Inline::Code(subject_name(
+                &shape.node,
+                prefixes,
+            ))],
+        }];
+        entries.push(Definition::new(
+            "Closed",
+            if shape.closed { "yes" } else { "no" },
+        ));
+        if !shape.ignored_properties.is_empty() {
+            entries.push(Definition::new(
+                "Ignored properties",
+                shape
+                    .ignored_properties
+                    .iter()
+                    .map(|p| prefixes.curie_or_iri(p.as_str()))
+                    .collect::<Vec<String>>()
+                    .join(", "),
+            ));
+        }
+        if let Some(severity) = &shape.severity {
+            entries.push(Definition::new(
+                "Severity",
+                severity_name(severity),
+            ));
+        }
+        for message in &shape.messages {
+            entries.push(Definition::new("Message", message.clone()));
+        }
+
+        let mut blocks = vec![BlockContent::DefinitionList(entries)];
+        if let Some(description) = &shape.description {
+            blocks.push(BlockContent::paragraph(description.clone()));
+        }
+        if shape.deactivated {
+            blocks.push(BlockContent::Admonition {
+                kind:    AdmonitionKind::Warning,
+                content: vec![Inline::text("This shape is deactivated.")],
+            });
+        }
+
+        if shape.targets.is_empty() {
+            blocks.push(BlockContent::paragraph("Targets: none"));
+        } else {
+            blocks.push(BlockContent::heading("Targets"));
+            blocks.push(BlockContent::List {
+                numbered: false,
+                items:    shape
+                    .targets
+                    .iter()
+                    .map(|target| Self::target(target, prefixes))
+                    .collect(),
+            });
+        }
+
+        if !shape.properties.is_empty() {
+            blocks.push(BlockContent::heading("Property constraints"));
+            blocks.push(BlockContent::table(
+                &[
+                    "Path",
+                    "Datatype/Class",
+                    "Min",
+                    "Max",
+                    "Pattern",
+                    "In",
+                    "Severity",
+                    "Message",
+                ],
+                shape
+                    .properties
+                    .iter()
+                    .map(|property| property_row(property, prefixes))
+                    .collect(),
+            ));
+        }
+        blocks
+    }
+
+    /// Returns the IRI of the shape as the source of its chapter,
+    /// unless the shape is also a class (with an implicit class
+    /// target), whose references go to the chapter of the OWL class.
+    fn source(shape: &NodeShape) -> Option<String> {
+        let is_class = shape
+            .targets
+            .iter()
+            .any(|target| matches!(target, ShapeTarget::ImplicitClass(_)));
+        match &shape.node {
+            Subject::NamedNode(node) if !is_class => {
+                Some(node.as_str().to_string())
+            },
+            _ => None,
+        }
+    }
+
+    /// Renders a target, with a cross-reference to the chapter of
+    /// the targeted class (or the property whose subjects or objects
+    /// are targeted) if that is documented.
+    fn target(target: &ShapeTarget, prefixes: &PrefixRegistry) -> Vec<Inline> {
+        let reference = |kind: &str, iri: &NamedNode| {
+            vec![
+                Inline::text(format!("{}: ", kind)),
+                Inline::reference(
+                    iri.as_str(),
+                    prefixes.curie_or_iri(iri.as_str()),
+                ),
+            ]
+        };
+        match target {
+            ShapeTarget::Class(class) => reference("Class", class),
+            ShapeTarget::ImplicitClass(class) => {
+                reference("Class (implicit)", class)
+            },
+            ShapeTarget::Node(node) => {
+                vec![Inline::text(format!(
+                    "Node: {}",
+                    term_name(node, prefixes)
+                ))]
+            },
+            ShapeTarget::SubjectsOf(predicate) => {
+                reference("Subjects of", predicate)
+            },
+            ShapeTarget::ObjectsOf(predicate) => {
+                reference("Objects of", predicate)
+            },
+        }
+    }
+}
+
+/// Renders a property shape as a row of the constraints table.
+fn property_row(
+    property: &PropertyShape,
+    prefixes: &PrefixRegistry,
+) -> Vec<String> {
+    let optional =
+        |value: Option<usize>| value.map(|v| v.to_string()).unwrap_or_default();
+    let value_type = property
+        .datatype
+        .as_ref()
+        .or(property.class.as_ref())
+        .map(|iri| prefixes.curie_or_iri(iri.as_str()))
+        .or_else(|| {
+            property
+                .node_kind
+                .as_ref()
+                .map(|kind| prefixes.curie_or_iri(kind.as_str()))
+        })
+        .unwrap_or_default();
+    let in_values = property
+        .in_values
+        .iter()
+        .map(|value| term_name(value, prefixes))
+        .collect::<Vec<String>>()
+        .join(", ");
+    vec![
+        property
+            .path
+            .as_ref()
+            .map(|path| path.to_sparql(Some(prefixes)))
+            .unwrap_or_default(),
+        value_type,
+        optional(property.min_count),
+        optional(property.max_count),
+        property.pattern.clone().unwrap_or_default(),
+        in_values,
+        property
+            .severity
+            .as_ref()
+            .map(severity_name)
+            .unwrap_or_default(),
+        property.messages.join("; "),
+    ]
+}
+
+/// Returns the local name of a severity such as `sh:Violation`.
+fn severity_name(severity: &oxrdf::NamedNode) -> String {
+    severity
+        .as_str()
+        .rsplit(['#', '/'])
+        .next()
+        .unwrap_or(severity.as_str())
+        .to_string()
+}
+
+fn subject_name(subject: &Subject, prefixes: &PrefixRegistry) -> String {
+    match subject {
+        Subject::NamedNode(node) => prefixes.curie_or_iri(node.as_str()),
+        _ => "(anonymous shape)".to_string(),
+    }
+}
+
+fn term_name(term: &Term, prefixes: &PrefixRegistry) -> String {
+    match term {
+        Term::NamedNode(node) => prefixes.curie_or_iri(node.as_str()),
+        Term::Literal(literal) => literal.value().to_string(),
+        _ => "[]".to_string(),
+    }
+}
@@ -0,0 +1,365 @@
+use {
+    crate::{
+        documentor::{
+            Documentor,
+            DocumentorCreator,
+            render::{
+                annotation_definition,
+                documented_term,
+                identity_definitions,
+                no_description,
+                push_list,
+                title,
+            },
+            source_locator::SourceLocator,
+        },
+        model::{
+            BlockContent,
+            Book,
+            Buildable,
+            Chapter,
+            Definition,
+            Element,
+            Model,
+            Section,
+        },
+        source::FileSourceImplementor,
+        store::{
+            LoaderStore,
+            PrefixRegistry,
+            SKOSConcept,
+            SKOSConceptSchemeDescription,
+        },
+        util::{FileType, FileTypeSliceStatic, Languages},
+    },
+    async_trait::async_trait,
+    std::{
+        path::{Path, PathBuf},
+        sync::{Arc, LazyLock},
+    },
+    tracing::info,
+};
+
+static SKOS_CONCEPT_SCHEME_DOCUMENTOR_FILE_TYPES: LazyLock<
+    FileTypeSliceStatic,
+> = LazyLock::new(|| {
+    let file_types = vec![
+        &FileType::RdfXml,
+        &FileType::NTriples,
+        &FileType::JSONLD,
+        &FileType::Turtle,
+        &FileType::NQuads,
+        &FileType::N3,
+        &FileType::TriG,
+    ];
+    Box::leak(Box::new(file_types))
+});
+
+/// A documentor for SKOS vocabularies.
+///
+/// Adds a book with a chapter per `skos:ConceptScheme` that shows its
+/// top concepts, its broader/narrower tree and the collections that
+/// its concepts are members of, with a subchapter per concept that
+/// shows its labels, notations, definitions, scope notes, related
+/// concepts and mappings.
+/// Concepts that hang loose from the hierarchy and cycles in
+/// `skos:broader` are flagged.
+#[derive(Debug, Clone)]
+pub struct SKOSConceptSchemeDocumentorImpl {
+    file_source:  Option<FileSourceImplementor>,
+    file_name:    Option<PathBuf>,
+    loader_store: LoaderStore,
+    /// The given target documentation model that the
+    /// SKOSConceptSchemeDocumentor will add its documentation to.
+    doc_model:    Arc<Model>,
+}
+
+impl DocumentorCreator for SKOSConceptSchemeDocumentorImpl {
+    fn new(
+        file_source: Option<FileSourceImplementor>,
+        file_name: Option<&Path>,
+        loader_store: LoaderStore,
+        doc_model: Arc<Model>,
+    ) -> Self {
+        Self {
+            file_source,
+            file_name: file_name.map(|f| f.to_path_buf()),
+            loader_store,
+            doc_model,
+        }
+    }
+}
+
+#[async_trait]
+impl Documentor for SKOSConceptSchemeDocumentorImpl {
+    fn file_types(&self) -> FileTypeSliceStatic {
+        *SKOS_CONCEPT_SCHEME_DOCUMENTOR_FILE_TYPES
+    }
+
+    async fn generate(&self) -> anyhow::Result<()> {
+        let graph = match &self.file_name {
+            Some(file_name) => self.loader_store.graph_for_file(file_name)?,
+            None => None,
+        };
+        let schemes = self
+            .loader_store
+            .find_skos_concept_schemes(graph.as_ref())?;
+        info!("Found {} SKOS concept schemes", schemes.len());
+        if schemes.is_empty() {
+            return Ok(());
+        }
+        let prefixes = self.loader_store.prefix_registry()?;
+        let languages = self.doc_model.languages();
+        let locator =
+            SourceLocator::new(&self.loader_store, self.file_source.as_ref());
+
+        // Name the book after the concept scheme if there is only one
+        let book_title = match schemes.as_slice() {
+            [scheme] => scheme.label.clone(),
+            _ => None,
+        }
+        .or_else(|| {
+            self.file_name
+                .as_ref()
+                .and_then(|f| f.file_stem())
+                .and_then(|s| s.to_str())
+                .map(|s| format!("{} Vocabulary", s))
+        })
+        .unwrap_or_else(|| "SKOS Vocabulary".to_string());
+        let book = Book::builder_in_model::<Book>(&self.doc_model)?
+            .key(
+                graph
+                    .as_ref()
+                    .map(|graph| format!("vocabulary:{}", graph.as_str())),
+            )
+            .title(Some(book_title))
+            .author(Some("GraphArch".to_string()))
+            .build()?;
+        let section = Section::builder_in_model::<Section>(&self.doc_model)?
+            .title(Some("Concept Schemes".to_string()))
+            .description(Some(
+                "This section contains documentation for all SKOS concept \
+                 schemes in the vocabulary."
+                    .to_string(),
+            ))
+            .book(Some(&book))
+            .build()?;
+
+        for scheme in schemes {
+            info!("Documenting SKOS concept scheme {}", scheme.iri);
+            let description =
+                self.loader_store.describe_skos_concept_scheme(&scheme)?;
+            let chapter =
+                Chapter::builder_in_model::<Chapter>(&self.doc_model)?
+                    .title(Some(scheme.label.clone().unwrap_or_else(|| {
+                        prefixes.curie_or_iri(&scheme.iri)
+                    })))
+                    .provenance(Some(locator.locate(&scheme.iri)?))
+                    .blocks(Self::content(&description, languages, &prefixes))
+                    .section(Some(&section))
+                    .build()?;
+
+            // Each concept gets a subchapter, which makes it an entry of
+            // the glossary and the index of the book
+            let name = Self::namer(&description, languages, &prefixes);
+            for concept in &description.concepts {
+                let concept_title = title(
+                    concept.pref_label(languages),
+                    &concept.iri,
+                    &prefixes,
+                );
+                let term = documented_term(
+                    "Concept",
+                    &concept.iri,
+                    &concept_title,
+                    &concept.labels,
+                    &concept.definitions,
+                    languages,
+                    &prefixes,
+                );
+                Chapter::builder_in_model::<Chapter>(&self.doc_model)?
+                    .title(Some(concept_title))
+                    .provenance(Some(locator.locate(&concept.iri)?))
+                    .term(Some(term))
+                    .blocks(Self::concept_blocks(concept, &name, &prefixes))
+                    .chapter(Some(&chapter))
+                    .build()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn file_name(&self) -> Option<&Path> { self.file_name.as_deref() }
+}
+
+impl SKOSConceptSchemeDocumentorImpl {
+    /// Returns a function that names a concept of the scheme by its
+    /// preferred label in the given languages and CURIE, or by its CURIE
+    /// alone.
+    fn namer<'a>(
+        description: &'a SKOSConceptSchemeDescription,
+        languages: &'a Languages,
+        prefixes: &'a PrefixRegistry,
+    ) -> impl Fn(&String) -> String + 'a {
+        move |iri: &String| -> String {
+            match description
+                .concept(iri)
+                .and_then(|c| c.pref_label(languages))
+            {
+                Some(label) => {
+                    format!("{} ({})", label, prefixes.curie_or_iri(iri))
+                },
+                None => prefixes.curie_or_iri(iri),
+            }
+        }
+    }
+
+    /// Returns the content of the chapter about the scheme; its
+    /// concepts are documented in subchapters.
+    fn content(
+        description: &SKOSConceptSchemeDescription,
+        languages: &Languages,
+        prefixes: &PrefixRegistry,
+    ) -> Vec<BlockContent> {
+        let name = &Self::namer(description, languages, prefixes);
+        let scheme = &description.scheme;
+        let mut entries = identity_definitions(&scheme.iri, prefixes);
+        entries.push(Definition::new(
+            "Concepts",
+            description.concepts.len().to_string(),
+        ));
+        let mut blocks = vec![BlockContent::DefinitionList(entries)];
+        blocks.push(match &scheme.description {
+            Some(text) => BlockContent::paragraph(text.clone()),
+            None => no_description("No description available."),
+        });
+
+        push_list(
+            &mut blocks,
+            "Top concepts",
+            description.top_concepts.iter().map(name).collect(),
+        );
+
+        let tree = description
+            .hierarchy
+            .tree(&description.hierarchy.roots(&description.top_concepts));
+        if !tree.is_empty() {
+            blocks.push(BlockContent::heading("Hierarchy"));
+            blocks.push(BlockContent::Code {
+                language: None,
+                code:     tree
+                    .into_iter()
+                    .map(|(depth, concept)| {
+                        format!("{}- {}\n", "  ".repeat(depth), name(&concept))
+                    })
+                    .collect(),
+            });
+        }
+
+        let mut issues = Vec::new();
+        for orphan in &description.orphans {
+            issues.push(format!(
+                "Orphan concept (no broader concept and not a top concept): {}",
+                name(orphan)
+            ));
+        }
+        for cycle in &description.cycles {
+            let mut path: Vec<String> = cycle.iter().map(name).collect();
+            path.push(name(&cycle[0]));
+            issues.push(format!(
+                "Cycle in skos:broader: {}",
+                path.join(" -> ")
+            ));
+        }
+        push_list(&mut blocks, "Quality issues", issues);
+
+        push_list(
+            &mut blocks,
+            "Collections",
+            description
+                .collections
+                .iter()
+                .map(|collection| {
+                    format!(
+                        "{}{}: {}",
+                        collection.label.clone().unwrap_or_else(|| {
+                            prefixes.curie_or_iri(&collection.iri)
+                        }),
+                        if collection.ordered { " (ordered)" } else { "" },
+                        collection
+                            .members
+                            .iter()
+                            .map(name)
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    )
+                })
+                .collect(),
+        );
+        blocks
+    }
+
+    fn concept_blocks(
+        concept: &SKOSConcept,
+        name: &dyn Fn(&String) -> String,
+        prefixes: &PrefixRegistry,
+    ) -> Vec<BlockContent> {
+        let mut entries = identity_definitions(&concept.iri, prefixes);
+        for notation in &concept.notations {
+            entries.push(Definition::new("Notation", notation.clone()));
+        }
+        entries.extend(
+            concept
+                .labels
+                .iter()
+                .chain(&concept.definitions)
+                .chain(&concept.scope_notes)
+                .map(|annotation| annotation_definition(annotation, prefixes)),
+        );
+        let mut blocks = vec![BlockContent::DefinitionList(entries)];
+        if concept.definitions.is_empty() {
+            blocks.push(no_description("No definition available."));
+        }
+        push_list(
+            &mut blocks,
+            "Broader",
+            concept.broader.iter().map(name).collect(),
+        );
+        push_list(
+            &mut blocks,
+            "Narrower",
+            concept.narrower.iter().map(name).collect(),
+        );
+        push_list(
+            &mut blocks,
+            "Related",
+            concept.related.iter().map(name).collect(),
+        );
+        push_list(
+            &mut blocks,
+            "Mappings",
+            concept
+                .mappings
+                .iter()
+                .map(|mapping| {
+                    format!(
+                        "skos:{} {}",
+                        mapping.property,
+                        prefixes.curie_or_iri(&mapping.target)
+                    )
+                })
+                .collect(),
+        );
+        push_list(
+            &mut blocks,
+            "Member of",
+            concept
+                .collections
+                .iter()
+                .map(|collection| prefixes.curie_or_iri(collection))
+                .collect(),
+        );
+        blocks
+    }
+}
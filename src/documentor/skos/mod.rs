@@ -0,0 +1,3 @@
+mod concept_scheme_documentor;
+
+pub use concept_scheme_documentor::SKOSConceptSchemeDocumentorImpl;
@@ -0,0 +1,259 @@
+use {
+    crate::{
+        model::Provenance,
+        source::{FileSource, FileSourceImplementor},
+        store::LoaderStore,
+        util::{GitWebRepository, MarkdownCodeBlock, relative_path},
+    },
+    oxrdf::NamedNode,
+    std::{
+        collections::HashMap,
+        path::{Path, PathBuf},
+        sync::{Arc, LazyLock, Mutex},
+    },
+};
+
+/// The source files that were indexed by `SourceLocator`, by their
+/// graph. Graphs are named after the SHA-256 of the content of their
+/// file, so an index never goes stale.
+static SOURCE_FILES: LazyLock<
+    Mutex<HashMap<NamedNode, Option<Arc<SourceFile>>>>,
+> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Finds where the resources that documentors document were read
+/// from: the graph and file of a resource, the line of its first
+/// statement in that file and, if the file is in a git repository, a
+/// link to that line in the web interface of the repository.
+#[derive(Debug, Clone)]
+pub(crate) struct SourceLocator {
+    loader_store: LoaderStore,
+    root_path:    Option<PathBuf>,
+    repository:   Option<GitWebRepository>,
+}
+
+impl SourceLocator {
+    pub fn new(
+        loader_store: &LoaderStore,
+        file_source: Option<&FileSourceImplementor>,
+    ) -> Self {
+        let root_path = file_source
+            .and_then(|file_source| file_source.root_path())
+            .map(Path::to_path_buf);
+        Self {
+            loader_store: loader_store.clone(),
+            repository: root_path
+                .as_deref()
+                .and_then(GitWebRepository::discover),
+            root_path,
+        }
+    }
+
+    /// Returns the provenance of the resource with the given IRI, with
+    /// as much of its location as can be found.
+    pub fn locate(&self, iri: &str) -> anyhow::Result<Provenance> {
+        let mut provenance = Provenance::of_source(iri);
+        let Some(graph) = self.loader_store.graph_of_subject(iri)? else {
+            return Ok(provenance);
+        };
+        if let Some(file) = self.source_file(&graph)? {
+            let line = file.line_of(iri);
+            provenance =
+                Provenance { line, ..self.locate_file(&file.path, line) };
+            provenance.source = Some(iri.to_string());
+        }
+        provenance.graph = Some(graph);
+        Ok(provenance)
+    }
+
+    /// Returns the provenance of an element that documents the given
+    /// file as a whole, like a Markdown page.
+    pub fn locate_file(&self, file: &Path, line: Option<u64>) -> Provenance {
+        let path = match &self.root_path {
+            Some(root_path) => relative_path(file, root_path),
+            None => file.to_path_buf(),
+        };
+        Provenance {
+            file: Some(path.to_string_lossy().to_string()),
+            url: self
+                .repository
+                .as_ref()
+                .and_then(|repository| repository.file_url(file, line)),
+            ..Provenance::default()
+        }
+    }
+
+    fn source_file(
+        &self,
+        graph: &NamedNode,
+    ) -> anyhow::Result<Option<Arc<SourceFile>>> {
+        if let Some(file) = SOURCE_FILES.lock().unwrap().get(graph) {
+            return Ok(file.clone());
+        }
+        let file = match self.loader_store.registered_file(graph)? {
+            Some(registered) => {
+                std::fs::read_to_string(&registered.path)
+                    .ok()
+                    .map(|content| {
+                        Arc::new(SourceFile::index(
+                            registered.path,
+                            &content,
+                            registered.block_line,
+                        ))
+                    })
+            },
+            None => None,
+        };
+        SOURCE_FILES
+            .lock()
+            .unwrap()
+            .insert(graph.clone(), file.clone());
+        Ok(file)
+    }
+}
+
+/// The lines of a source file where the statements about its subjects
+/// start.
+#[derive(Debug)]
+struct SourceFile {
+    path:     PathBuf,
+    /// The (1-based) line of the first statement about each subject,
+    /// by the way the subject is written: `<iri>` or a prefixed name
+    /// like `ex:Car`
+    subjects: HashMap<String, u64>,
+    /// The prefixes that are declared in the file
+    prefixes: Vec<(String, String)>,
+}
+
+impl SourceFile {
+    /// Indexes the given content of a file, or only the code block
+    /// that starts at the given line if the file is a Markdown file.
+    fn index(path: PathBuf, content: &str, block_line: Option<usize>) -> Self {
+        let (code, offset) = match block_line {
+            Some(line) => {
+                let code = MarkdownCodeBlock::extract(content)
+                    .into_iter()
+                    .find(|block| block.line == line)
+                    .map(|block| block.code)
+                    .unwrap_or_default();
+                (code, line)
+            },
+            None => (content.to_string(), 0),
+        };
+        let mut file = Self {
+            path,
+            subjects: HashMap::new(),
+            prefixes: Vec::new(),
+        };
+        for (i, line) in code.lines().enumerate() {
+            let number = (offset + i + 1) as u64;
+            if let Some(prefix) = prefix_declaration(line) {
+                file.prefixes.push(prefix);
+            } else if let Some(subject) = subject(line) {
+                file.subjects.entry(subject).or_insert(number);
+            }
+        }
+        file
+    }
+
+    /// Returns the line of the first statement about the given IRI, if
+    /// any.
+    fn line_of(&self, iri: &str) -> Option<u64> {
+        let names = self.prefixes.iter().filter_map(|(prefix, namespace)| {
+            let local = iri.strip_prefix(namespace.as_str())?;
+            let valid = !local.is_empty() &&
+                local.chars().all(|c| {
+                    c.is_alphanumeric() || matches!(c, '_' | '-' | '.')
+                });
+            valid.then(|| format!("{}:{}", prefix, local))
+        });
+        std::iter::once(format!("<{}>", iri))
+            .chain(names)
+            .filter_map(|name| self.subjects.get(&name).copied())
+            .min()
+    }
+}
+
+/// Returns the prefix and namespace of the given line if it is a
+/// prefix declaration, like `@prefix ex: <http://example.com/> .` or
+/// `PREFIX ex: <http://example.com/>`.
+fn prefix_declaration(line: &str) -> Option<(String, String)> {
+    let line = line.trim_start();
+    let rest = match line.strip_prefix("@prefix") {
+        Some(rest) => rest,
+        None => {
+            line.get(..6)
+                .filter(|keyword| keyword.eq_ignore_ascii_case("prefix"))?;
+            &line[6..]
+        },
+    };
+    let (prefix, rest) = rest.trim_start().split_once(':')?;
+    let (namespace, _) =
+        rest.trim_start().strip_prefix('<')?.split_once('>')?;
+    Some((prefix.to_string(), namespace.to_string()))
+}
+
+/// Returns the subject of the statement that starts at the given line,
+/// if any: the term at the start of a Turtle-like statement, the
+/// `rdf:about` of an RDF/XML element or the `@id` of a JSON-LD node,
+/// the latter two as `<iri>`.
+fn subject(line: &str) -> Option<String> {
+    let quoted = |rest: &str| -> Option<String> {
+        let rest = rest.trim_start().strip_prefix('"')?;
+        Some(format!("<{}>", rest.split_once('"')?.0))
+    };
+    if let Some((_, rest)) = line.split_once("rdf:about=") {
+        return quoted(rest);
+    }
+    if let Some((_, rest)) = line.split_once("\"@id\"") {
+        return quoted(rest.trim_start().strip_prefix(':')?);
+    }
+    // A statement starts at the start of a line, the lines that go on
+    // with the predicates of a statement are indented
+    if line.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let term = line.split_whitespace().next()?;
+    let term = term.trim_end_matches([';', ',', '.']);
+    let is_term = term.starts_with('<') && term.ends_with('>') ||
+        term.contains(':') && !term.starts_with(['@', '#', '"', '[', '(']);
+    is_term.then(|| term.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_of() {
+        let content = r#"@prefix : <http://example.com/onto#> .
+PREFIX owl: <http://www.w3.org/2002/07/owl#>
+
+<http://example.com/onto> a owl:Ontology .
+
+:Vehicle a owl:Class .
+
+:Car a owl:Class ;
+    rdfs:subClassOf :Vehicle .
+
+:Vehicle rdfs:label "Vehicle" .
+"#;
+        let file = SourceFile::index(PathBuf::from("onto.ttl"), content, None);
+        assert_eq!(file.line_of("http://example.com/onto"), Some(4));
+        assert_eq!(
+            file.line_of("http://example.com/onto#Vehicle"),
+            Some(6)
+        );
+        assert_eq!(
+            file.line_of("http://example.com/onto#Car"),
+            Some(8)
+        );
+        assert_eq!(file.line_of("http://example.com/onto#Bike"), None);
+
+        let content =
+            "# Example\n\n```turtle\n@prefix ex: <http://example.com/> \
+             .\n\nex:a ex:b ex:c .\n```\n";
+        let file =
+            SourceFile::index(PathBuf::from("README.md"), content, Some(3));
+        assert_eq!(file.line_of("http://example.com/a"), Some(6));
+    }
+}
@@ -1,9 +1,16 @@
 use {
     super::{
+        DatasetProfileDocumentorImpl,
+        EKGFUseCaseDocumentorImpl,
         JekyllMarkdownDocumentorImpl,
+        LicenseDocumentorImpl,
         MarkdownDocumentorImpl,
         OWLClassDocumentorImpl,
+        OWLIndividualDocumentorImpl,
         OWLOntologyDocumentorImpl,
+        OWLPropertyDocumentorImpl,
+        SKOSConceptSchemeDocumentorImpl,
+        ShaclShapesDocumentorImpl,
     },
     crate::{
         model::Model,
@@ -27,6 +34,10 @@ pub trait Documentor {
     /// cares about.
     fn file_name(&self) -> Option<&Path>;
 
+    /// Returns true if this documentor adds to the books that the
+    /// other documentors created, so it has to run after all of them.
+    fn finishes_books(&self) -> bool { false }
+
     /// Generates the documentatable items into the
     /// `DocumentationModel`. This is the only step where a
     /// Documentor is allowed to mutate the `DocumentationModel`.
@@ -60,8 +71,15 @@ pub trait DocumentorCreator: Sized {
 pub enum DocumentorVariant {
     OWLOntology,
     OWLClass,
+    OWLProperty,
+    OWLIndividual,
+    ShaclShapes,
+    SKOSConceptScheme,
+    EKGFUseCase,
+    License,
     Markdown,
     JekyllMarkdown,
+    DatasetProfile,
 }
 
 /// An enum that holds all the possible documentor implementations.
@@ -72,8 +90,15 @@ pub enum DocumentorVariant {
 pub enum DocumentorImplementor {
     OWLOntologyDocumentor(OWLOntologyDocumentorImpl),
     OWLClassDocumentor(OWLClassDocumentorImpl),
+    OWLPropertyDocumentor(OWLPropertyDocumentorImpl),
+    OWLIndividualDocumentor(OWLIndividualDocumentorImpl),
+    ShaclShapesDocumentor(ShaclShapesDocumentorImpl),
+    SKOSConceptSchemeDocumentor(SKOSConceptSchemeDocumentorImpl),
+    EKGFUseCaseDocumentor(EKGFUseCaseDocumentorImpl),
+    LicenseDocumentor(LicenseDocumentorImpl),
     MarkdownDocumentor(MarkdownDocumentorImpl),
     JekyllMarkdownDocumentor(JekyllMarkdownDocumentorImpl),
+    DatasetProfileDocumentor(DatasetProfileDocumentorImpl),
 }
 
 impl DocumentorImplementor {
@@ -101,6 +126,56 @@ impl DocumentorImplementor {
                     doc_model,
                 ))
             },
+            DocumentorVariant::OWLProperty => {
+                Self::OWLPropertyDocumentor(OWLPropertyDocumentorImpl::new(
+                    file_source,
+                    file_name,
+                    loader_store,
+                    doc_model,
+                ))
+            },
+            DocumentorVariant::OWLIndividual => {
+                Self::OWLIndividualDocumentor(OWLIndividualDocumentorImpl::new(
+                    file_source,
+                    file_name,
+                    loader_store,
+                    doc_model,
+                ))
+            },
+            DocumentorVariant::ShaclShapes => {
+                Self::ShaclShapesDocumentor(ShaclShapesDocumentorImpl::new(
+                    file_source,
+                    file_name,
+                    loader_store,
+                    doc_model,
+                ))
+            },
+            DocumentorVariant::SKOSConceptScheme => {
+                Self::SKOSConceptSchemeDocumentor(
+                    SKOSConceptSchemeDocumentorImpl::new(
+                        file_source,
+                        file_name,
+                        loader_store,
+                        doc_model,
+                    ),
+                )
+            },
+            DocumentorVariant::EKGFUseCase => {
+                Self::EKGFUseCaseDocumentor(EKGFUseCaseDocumentorImpl::new(
+                    file_source,
+                    file_name,
+                    loader_store,
+                    doc_model,
+                ))
+            },
+            DocumentorVariant::License => {
+                Self::LicenseDocumentor(LicenseDocumentorImpl::new(
+                    file_source,
+                    file_name,
+                    loader_store,
+                    doc_model,
+                ))
+            },
             DocumentorVariant::Markdown => {
                 Self::MarkdownDocumentor(MarkdownDocumentorImpl::new(
                     file_source,
@@ -119,6 +194,16 @@ impl DocumentorImplementor {
                     ),
                 )
             },
+            DocumentorVariant::DatasetProfile => {
+                Self::DatasetProfileDocumentor(
+                    DatasetProfileDocumentorImpl::new(
+                        file_source,
+                        file_name,
+                        loader_store,
+                        doc_model,
+                    ),
+                )
+            },
         }
     }
 }
@@ -133,12 +218,33 @@ impl Documentor for DocumentorImplementor {
             DocumentorImplementor::OWLClassDocumentor(documentor) => {
                 documentor.generate().await
             },
+            DocumentorImplementor::OWLPropertyDocumentor(documentor) => {
+                documentor.generate().await
+            },
+            DocumentorImplementor::OWLIndividualDocumentor(documentor) => {
+                documentor.generate().await
+            },
+            DocumentorImplementor::ShaclShapesDocumentor(documentor) => {
+                documentor.generate().await
+            },
+            DocumentorImplementor::SKOSConceptSchemeDocumentor(documentor) => {
+                documentor.generate().await
+            },
+            DocumentorImplementor::EKGFUseCaseDocumentor(documentor) => {
+                documentor.generate().await
+            },
+            DocumentorImplementor::LicenseDocumentor(documentor) => {
+                documentor.generate().await
+            },
             DocumentorImplementor::MarkdownDocumentor(documentor) => {
                 documentor.generate().await
             },
             DocumentorImplementor::JekyllMarkdownDocumentor(documentor) => {
                 documentor.generate().await
             },
+            DocumentorImplementor::DatasetProfileDocumentor(documentor) => {
+                documentor.generate().await
+            },
         }
     }
 
@@ -150,12 +256,71 @@ impl Documentor for DocumentorImplementor {
             DocumentorImplementor::OWLClassDocumentor(documentor) => {
                 documentor.file_name()
             },
+            DocumentorImplementor::OWLPropertyDocumentor(documentor) => {
+                documentor.file_name()
+            },
+            DocumentorImplementor::OWLIndividualDocumentor(documentor) => {
+                documentor.file_name()
+            },
+            DocumentorImplementor::ShaclShapesDocumentor(documentor) => {
+                documentor.file_name()
+            },
+            DocumentorImplementor::SKOSConceptSchemeDocumentor(documentor) => {
+                documentor.file_name()
+            },
+            DocumentorImplementor::EKGFUseCaseDocumentor(documentor) => {
+                documentor.file_name()
+            },
+            DocumentorImplementor::LicenseDocumentor(documentor) => {
+                documentor.file_name()
+            },
             DocumentorImplementor::MarkdownDocumentor(documentor) => {
                 documentor.file_name()
             },
             DocumentorImplementor::JekyllMarkdownDocumentor(documentor) => {
                 documentor.file_name()
             },
+            DocumentorImplementor::DatasetProfileDocumentor(documentor) => {
+                documentor.file_name()
+            },
+        }
+    }
+
+    fn finishes_books(&self) -> bool {
+        match self {
+            DocumentorImplementor::OWLOntologyDocumentor(documentor) => {
+                documentor.finishes_books()
+            },
+            DocumentorImplementor::OWLClassDocumentor(documentor) => {
+                documentor.finishes_books()
+            },
+            DocumentorImplementor::OWLPropertyDocumentor(documentor) => {
+                documentor.finishes_books()
+            },
+            DocumentorImplementor::OWLIndividualDocumentor(documentor) => {
+                documentor.finishes_books()
+            },
+            DocumentorImplementor::ShaclShapesDocumentor(documentor) => {
+                documentor.finishes_books()
+            },
+            DocumentorImplementor::SKOSConceptSchemeDocumentor(documentor) => {
+                documentor.finishes_books()
+            },
+            DocumentorImplementor::EKGFUseCaseDocumentor(documentor) => {
+                documentor.finishes_books()
+            },
+            DocumentorImplementor::LicenseDocumentor(documentor) => {
+                documentor.finishes_books()
+            },
+            DocumentorImplementor::MarkdownDocumentor(documentor) => {
+                documentor.finishes_books()
+            },
+            DocumentorImplementor::JekyllMarkdownDocumentor(documentor) => {
+                documentor.finishes_books()
+            },
+            DocumentorImplementor::DatasetProfileDocumentor(documentor) => {
+                documentor.finishes_books()
+            },
         }
     }
 }
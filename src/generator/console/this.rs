@@ -1,7 +1,18 @@
 use {
     crate::{
         generator::Generator,
-        model::{Book, Chapter, Model, Section},
+        model::{
+            BlockContent,
+            Book,
+            Chapter,
+            CrossReferences,
+            Element,
+            Glossary,
+            Inline,
+            Model,
+            Section,
+            plain_text,
+        },
     },
     console::Style,
     std::{
@@ -13,7 +24,12 @@ use {
 
 /// A generator that outputs documentation to the console using ANSI colors
 pub struct ConsoleGenerator {
-    writer: Box<dyn Write>,
+    writer:     Box<dyn Write>,
+    /// The targets of the cross-references of the model that is being
+    /// generated
+    references: CrossReferences,
+    /// Whether to show where each book, section and chapter comes from
+    sources:    bool,
 }
 
 impl std::fmt::Debug for ConsoleGenerator {
@@ -27,9 +43,49 @@ impl Default for ConsoleGenerator {
 }
 
 impl ConsoleGenerator {
-    pub fn new() -> Self { Self { writer: Box::new(io::stdout()) } }
+    pub fn new() -> Self {
+        Self {
+            writer:     Box::new(io::stdout()),
+            references: CrossReferences::default(),
+            sources:    false,
+        }
+    }
+
+    /// Shows the source file and line (and web URL) of each book,
+    /// section and chapter, see `Provenance`.
+    pub fn with_sources(mut self, sources: bool) -> Self {
+        self.sources = sources;
+        self
+    }
+
+    fn write_source(
+        &mut self,
+        element: &impl Element,
+        depth: usize,
+    ) -> anyhow::Result<()> {
+        if !self.sources {
+            return Ok(());
+        }
+        let Some(provenance) = element.get_provenance()? else {
+            return Ok(());
+        };
+        let Some(location) = provenance.location() else {
+            return Ok(());
+        };
+        let source = match &provenance.url {
+            Some(url) => format!("Source: {} ({})", location, url),
+            None => format!("Source: {}", location),
+        };
+        writeln!(
+            self.writer,
+            "{}{}",
+            "  ".repeat(depth),
+            Style::new().dim().apply_to(source)
+        )?;
+        Ok(())
+    }
 
-    fn write_book(&mut self, book: &Book) -> io::Result<()> {
+    fn write_book(&mut self, book: &Book) -> anyhow::Result<()> {
         let title_style = Style::new().bold().green();
         let subtitle_style = Style::new().italic().blue();
         let author_style = Style::new().yellow();
@@ -46,16 +102,31 @@ impl ConsoleGenerator {
                 subtitle_style.apply_to(subtitle)
             )?;
         }
+        if let Some(version) = &book.version {
+            writeln!(
+                self.writer,
+                "{}",
+                subtitle_style.apply_to(format!("Version {}", version))
+            )?;
+        }
+        if let Some(language) = &book.language {
+            writeln!(
+                self.writer,
+                "{}",
+                subtitle_style.apply_to(format!("Language: {}", language))
+            )?;
+        }
         writeln!(
             self.writer,
             "{}",
             author_style.apply_to(format!("By {}", book.authors.join(", ")))
         )?;
+        self.write_source(book, 0)?;
         writeln!(self.writer)?;
         Ok(())
     }
 
-    fn write_section(&mut self, section: &Section) -> io::Result<()> {
+    fn write_section(&mut self, section: &Section) -> anyhow::Result<()> {
         let title_style = Style::new().bold().cyan();
         let desc_style = Style::new().italic().white();
 
@@ -65,6 +136,7 @@ impl ConsoleGenerator {
             title_style
                 .apply_to(section.title.as_deref().unwrap_or("Untitled"))
         )?;
+        self.write_source(section, 0)?;
         if let Some(desc) = &section.description {
             writeln!(self.writer, "{}", desc_style.apply_to(desc))?;
         }
@@ -72,34 +144,276 @@ impl ConsoleGenerator {
         Ok(())
     }
 
-    fn write_chapter(&mut self, chapter: &Chapter) -> io::Result<()> {
-        let title_style = Style::new().bold().magenta();
-        let content_style = Style::new().white();
+    /// Writes the glossary of the book, with the kind, CURIE and
+    /// definition of each term, and its index by letter.
+    fn write_glossary(&mut self, glossary: &Glossary) -> io::Result<()> {
+        let title_style = Style::new().bold().cyan();
+        let term_style = Style::new().bold();
+        let kind_style = Style::new().dim();
 
         writeln!(
             self.writer,
             "{}",
+            title_style.apply_to("Glossary")
+        )?;
+        for entry in &glossary.entries {
+            let mut kind = entry.term.kind.clone();
+            if let Some(curie) = &entry.term.curie {
+                kind = format!("{}, {}", kind, curie);
+            }
+            writeln!(
+                self.writer,
+                "  {} {}",
+                term_style.apply_to(&entry.label),
+                kind_style.apply_to(format!("({})", kind))
+            )?;
+            if let Some(definition) = &entry.term.definition {
+                writeln!(self.writer, "    {}", definition)?;
+            }
+        }
+        writeln!(self.writer)?;
+
+        writeln!(self.writer, "{}", title_style.apply_to("Index"))?;
+        let mut letter = None;
+        for entry in glossary.index() {
+            if letter != Some(entry.letter()) {
+                letter = Some(entry.letter());
+                writeln!(
+                    self.writer,
+                    "  {}",
+                    term_style.apply_to(entry.letter())
+                )?;
+            }
+            match &entry.see {
+                Some(label) => {
+                    writeln!(self.writer, "    {}, see {}", entry.text, label)?
+                },
+                None => writeln!(self.writer, "    {}", entry.text)?,
+            }
+        }
+        writeln!(self.writer)?;
+        Ok(())
+    }
+
+    fn write_chapter(
+        &mut self,
+        chapter: &Chapter,
+        depth: usize,
+    ) -> anyhow::Result<()> {
+        let title_style = Style::new().bold().magenta();
+
+        writeln!(
+            self.writer,
+            "{}{}",
+            "  ".repeat(depth),
             title_style
                 .apply_to(chapter.title.as_deref().unwrap_or("Untitled"))
         )?;
+        self.write_source(chapter, depth)?;
+        for block in chapter.get_blocks()? {
+            self.write_block(&block.content)?;
+        }
+        writeln!(self.writer)?;
+        Ok(())
+    }
+
+    fn write_block(&mut self, block: &BlockContent) -> io::Result<()> {
+        match block {
+            BlockContent::Paragraph(content) => {
+                writeln!(
+                    self.writer,
+                    "{}",
+                    inlines(content, &self.references)
+                )?
+            },
+            BlockContent::Heading(content) => {
+                writeln!(
+                    self.writer,
+                    "{}",
+                    Style::new().bold().apply_to(plain_text(content))
+                )?
+            },
+            BlockContent::Quote(content) => {
+                writeln!(
+                    self.writer,
+                    "  | {}",
+                    inlines(content, &self.references)
+                )?
+            },
+            BlockContent::List { numbered, items } => {
+                for (i, item) in items.iter().enumerate() {
+                    let marker = match numbered {
+                        true => format!("{}.", i + 1),
+                        false => "-".to_string(),
+                    };
+                    writeln!(
+                        self.writer,
+                        "{} {}",
+                        marker,
+                        inlines(item, &self.references)
+                    )?;
+                }
+            },
+            BlockContent::Table { header, rows } => {
+                self.write_table(header, rows)?
+            },
+            BlockContent::Code { code, .. } => {
+                let code_style = Style::new().dim();
+                for line in code.trim_end_matches('\n').lines() {
+                    writeln!(self.writer, "    {}", code_style.apply_to(line))?;
+                }
+            },
+            BlockContent::Admonition { kind, content } => {
+                writeln!(
+                    self.writer,
+                    "{} {}",
+                    Style::new()
+                        .bold()
+                        .yellow()
+                        .apply_to(format!("{}:", kind.title())),
+                    inlines(content, &self.references)
+                )?
+            },
+            BlockContent::Image { url, alt, caption } => {
+                writeln!(self.writer, "[Image: {}] ({})", alt, url)?;
+                if let Some(caption) = caption {
+                    writeln!(
+                        self.writer,
+                        "{}",
+                        Style::new().italic().apply_to(caption)
+                    )?;
+                }
+            },
+            BlockContent::DefinitionList(definitions) => {
+                for definition in definitions {
+                    writeln!(
+                        self.writer,
+                        "{}: {}",
+                        Style::new().bold().apply_to(&definition.term),
+                        inlines(&definition.description, &self.references)
+                    )?;
+                }
+            },
+        }
+        Ok(())
+    }
+
+    /// Writes a table with its columns aligned.
+    fn write_table(
+        &mut self,
+        header: &[Vec<Inline>],
+        rows: &[Vec<Vec<Inline>>],
+    ) -> io::Result<()> {
+        let cells = |row: &[Vec<Inline>]| -> Vec<String> {
+            row.iter().map(|cell| plain_text(cell)).collect()
+        };
+        let header = cells(header);
+        let rows: Vec<Vec<String>> =
+            rows.iter().map(|row| cells(row)).collect();
+        let mut widths: Vec<usize> =
+            header.iter().map(|cell| cell.chars().count()).collect();
+        for row in &rows {
+            for (i, cell) in row.iter().enumerate() {
+                match widths.get_mut(i) {
+                    Some(width) => *width = (*width).max(cell.chars().count()),
+                    None => widths.push(cell.chars().count()),
+                }
+            }
+        }
+        let line = |row: &[String]| -> String {
+            row.iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect::<Vec<_>>()
+                .join(" | ")
+                .trim_end()
+                .to_string()
+        };
         writeln!(
             self.writer,
             "{}",
-            content_style
-                .apply_to(chapter.content.as_deref().unwrap_or("Untitled"))
+            Style::new().bold().apply_to(line(&header))
         )?;
-        writeln!(self.writer)?;
+        for row in &rows {
+            writeln!(self.writer, "{}", line(row))?;
+        }
+        Ok(())
+    }
+
+    /// Writes the given chapters and, indented, their subchapters.
+    fn write_chapters(
+        &mut self,
+        chapters: &[Chapter],
+        depth: usize,
+    ) -> anyhow::Result<()> {
+        for chapter in chapters {
+            self.write_chapter(chapter, depth)?;
+            self.write_chapters(&chapter.get_chapters()?, depth + 1)?;
+        }
         Ok(())
     }
 }
 
+/// Renders inlines with bold, italic and highlighted code, with the
+/// URL of a link after its text and the title of the target of a
+/// cross-reference after its text.
+fn inlines(inlines: &[Inline], references: &CrossReferences) -> String {
+    inlines
+        .iter()
+        .map(|inline| {
+            match inline {
+                Inline::Text(text) => text.clone(),
+                Inline::Emphasis(content) => {
+                    Style::new()
+                        .italic()
+                        .apply_to(self::inlines(content, references))
+                        .to_string()
+                },
+                Inline::Strong(content) => {
+                    Style::new()
+                        .bold()
+                        .apply_to(self::inlines(content, references))
+                        .to_string()
+                },
+                Inline::Code(code) => {
+                    Style::new().cyan().apply_to(code).to_string()
+                },
+                Inline::Link { url, content } => {
+                    let text = self::inlines(content, references);
+                    if plain_text(content) == *url {
+                        Style::new().underlined().apply_to(text).to_string()
+                    } else {
+                        format!(
+                            "{} ({})",
+                            text,
+                            Style::new().underlined().apply_to(url)
+                        )
+                    }
+                },
+                Inline::Reference { target, content } => {
+                    let text = self::inlines(content, references);
+                    match references.resolve(target) {
+                        Some(anchor) => {
+                            format!("{} (see \"{}\")", text, anchor.title)
+                        },
+                        None => text,
+                    }
+                },
+            }
+        })
+        .collect()
+}
+
 impl Generator for ConsoleGenerator {
     fn generate(&mut self, doc_model: Arc<Model>) -> anyhow::Result<()> {
         info!("Generating console output");
 
+        let references = CrossReferences::of_model(&doc_model)?;
+
         // Get all books using the type-safe API
         let books = Book::get_books(doc_model)?;
         for book in books {
+            self.references = references.in_book(book.get_named_node());
             self.write_book(&book)?;
 
             // Get sections for this book
@@ -108,10 +422,12 @@ impl Generator for ConsoleGenerator {
                 self.write_section(&section)?;
 
                 // Get chapters for this section
-                let chapters = section.get_chapters()?;
-                for chapter in chapters {
-                    self.write_chapter(&chapter)?;
-                }
+                self.write_chapters(&section.get_chapters()?, 0)?;
+            }
+
+            let glossary = book.get_glossary()?;
+            if !glossary.is_empty() {
+                self.write_glossary(&glossary)?;
             }
         }
 
@@ -1,8 +1,9 @@
 use {
     crate::{
+        config::Config,
         documentor::{Documentor, DocumentorImplementor},
         loader::{Loader, LoaderImplementor},
-        model::Model,
+        model::{Book, Model},
         source::{FileSource, FileSourceImplementor},
         store::LoaderStore,
         util::{FileType, relative_path},
@@ -14,6 +15,7 @@ pub struct DocumentationGenerator {
     loaders:      Vec<LoaderImplementor>,
     loader_store: LoaderStore,
     doc_model:    Arc<Model>,
+    config:       Config,
 }
 
 impl DocumentationGenerator {
@@ -22,7 +24,19 @@ impl DocumentationGenerator {
         loader_store: LoaderStore,
         doc_model: Arc<Model>,
     ) -> Self {
-        Self { loaders, loader_store, doc_model }
+        Self {
+            loaders,
+            loader_store,
+            doc_model,
+            config: Config::default(),
+        }
+    }
+
+    /// Sets the configuration, which decides which books the books of
+    /// the documentors go into (see `Book::merge_books`).
+    pub fn with_config(mut self, config: Config) -> Self {
+        self.config = config;
+        self
     }
 
     pub fn file_types(&self) -> Vec<&'static FileType> {
@@ -106,7 +120,38 @@ impl DocumentationGenerator {
         // Finally, we need to generate the documentatable items into
         // the doc_model, using the documentors. This is the
         // only step where a Documentor is allowed
-        // to mutate the doc_model.
+        // to mutate the doc_model. Documentors that add to the books
+        // of the other documentors, such as the license documentor,
+        // run once all books exist and have been merged.
+        let (finishing, documentors): (Vec<_>, Vec<_>) = documentors
+            .iter()
+            .partition(|documentor| documentor.finishes_books());
+        Self::run_documentors(&documentors).await?;
+        Book::merge_books(self.doc_model.clone(), &self.config.books)?;
+        Self::run_documentors(&finishing).await?;
+        self.validate_model()
+    }
+
+    /// Validates the documentation model against the GraphArch
+    /// ontology once all documentors have run, or once the model has
+    /// been read with `--model`, and reports each violation as a
+    /// warning.
+    pub fn validate_model(&self) -> anyhow::Result<()> {
+        let violations = self.doc_model.validate()?;
+        for violation in &violations {
+            tracing::warn!("Invalid documentation model: {}", violation);
+        }
+        if violations.is_empty() {
+            tracing::info!("The documentation model is valid");
+        }
+        Ok(())
+    }
+
+    /// Generates the documentatable items of the given documentors
+    /// concurrently.
+    async fn run_documentors(
+        documentors: &[&DocumentorImplementor],
+    ) -> anyhow::Result<()> {
         let results =
             futures::future::join_all(documentors.iter().map(|documentor| {
                 async move {
@@ -0,0 +1,360 @@
+use crate::model::{
+    BlockContent,
+    CrossReferences,
+    Glossary,
+    Inline,
+    Provenance,
+    anchor_id,
+};
+
+/// Escapes the characters of the given text that have a meaning in
+/// Typst markup, so that the text shows as is.
+pub fn escape(text: &str) -> String { escape_text(text, true) }
+
+/// Escapes the given text, and also the characters that start a
+/// heading or a list item if the text is at the start of a line.
+fn escape_text(text: &str, at_start: bool) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for (i, c) in text.chars().enumerate() {
+        let special = matches!(
+            c,
+            '\\' | '#' |
+                '*' |
+                '_' |
+                '`' |
+                '$' |
+                '<' |
+                '>' |
+                '@' |
+                '[' |
+                ']' |
+                '~' |
+                '/'
+        ) || (at_start && i == 0 && matches!(c, '=' | '-' | '+'));
+        if special {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Returns the given text as a Typst string literal.
+pub fn string(text: &str) -> String {
+    let mut literal = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '\\' => literal.push_str("\\\\"),
+            '"' => literal.push_str("\\\""),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+/// Returns the given title as a file stem that stays in the output
+/// directory: without path separators, characters that are not allowed
+/// in file names and a leading dot.
+pub fn file_stem(title: &str) -> String {
+    let stem: String = title
+        .chars()
+        .map(|c| {
+            match c {
+                '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+                c if c.is_control() => '_',
+                c => c,
+            }
+        })
+        .collect();
+    let stem = stem.trim().trim_start_matches('.').trim_start();
+    if stem.is_empty() {
+        "Untitled".to_string()
+    } else {
+        stem.to_string()
+    }
+}
+
+/// Renders the source of an element in small print, like
+/// `Source: ontology/core.ttl:120`, linked to its web URL if known.
+pub fn source(provenance: &Provenance) -> Option<String> {
+    let location = escape(&provenance.location()?);
+    let location = match &provenance.url {
+        Some(url) => format!("#link({})[{}]", string(url), location),
+        None => location,
+    };
+    Some(format!(
+        "#text(size: 8pt, fill: luma(100))[Source: {}]",
+        location
+    ))
+}
+
+/// Renders the glossary of a book as an unnumbered section with a term
+/// list, each term linked to the heading of its chapter and followed by
+/// its kind, its CURIE and its definition.
+pub fn glossary(glossary: &Glossary) -> String {
+    let mut markup = String::from(
+        "#heading(numbering: none)[Glossary]
+
+",
+    );
+    for entry in &glossary.entries {
+        let mut kind = escape(&entry.term.kind);
+        if let Some(curie) = &entry.term.curie {
+            kind = format!("{}, #raw({})", kind, string(curie));
+        }
+        markup.push_str(&format!(
+            "/ #link(<{}>)[{}]: _{}_",
+            anchor_id(&entry.chapter),
+            escape(&entry.label),
+            kind
+        ));
+        if let Some(definition) = &entry.term.definition {
+            markup.push_str(&format!(" — {}", escape(definition)));
+        }
+        markup.push('\n');
+    }
+    markup
+}
+
+/// Renders the alphabetical index of a book as an unnumbered section
+/// in two columns, with a heading per letter and the page number of
+/// the chapter of each entry, linked to its heading.
+pub fn index(glossary: &Glossary) -> String {
+    let mut markup = String::from(
+        "#heading(numbering: none)[Index]
+
+#columns(2)[
+",
+    );
+    let mut letter = None;
+    for entry in glossary.index() {
+        if letter != Some(entry.letter()) {
+            letter = Some(entry.letter());
+            markup.push_str(&format!(
+                "#heading(level: 2, outlined: false, numbering: none)[{}]
+",
+                escape(&entry.letter().to_string())
+            ));
+        }
+        let anchor = anchor_id(&entry.chapter);
+        let text = match &entry.see {
+            Some(label) => {
+                format!("{}, see _{}_", escape(&entry.text), escape(label))
+            },
+            None => escape(&entry.text),
+        };
+        markup.push_str(&format!(
+            "{} #box(width: 1fr, repeat[.]) #link(<{anchor}>)[#context \
+             counter(page).at(<{anchor}>).first()] \\\n",
+            text
+        ));
+    }
+    markup.push_str("]\n");
+    markup
+}
+
+/// Renders the given blocks of a chapter whose heading is of the
+/// given level, the headings within the chapter go one level deeper
+/// and are not numbered or listed in the outline. A cross-reference
+/// that resolves is followed by a reference to the heading of its
+/// target, like `ex:Car (@urn-uuid-...)`.
+pub fn blocks(
+    blocks: &[BlockContent],
+    level: usize,
+    references: &CrossReferences,
+) -> String {
+    let renderer = Renderer { level, references };
+    blocks
+        .iter()
+        .map(|content| renderer.block(content))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+struct Renderer<'a> {
+    level:      usize,
+    references: &'a CrossReferences,
+}
+
+impl Renderer<'_> {
+    fn block(&self, content: &BlockContent) -> String {
+        let level = self.level;
+        let inlines = |content: &[Inline]| self.inlines(content);
+        match content {
+            BlockContent::Paragraph(content) => inlines(content),
+            BlockContent::Heading(content) => {
+                format!(
+                    "#heading(level: {}, outlined: false, numbering: none)[{}]",
+                    level + 1,
+                    inlines(content)
+                )
+            },
+            BlockContent::Quote(content) => {
+                format!("#quote(block: true)[{}]", inlines(content))
+            },
+            BlockContent::List { numbered, items } => {
+                let marker = if *numbered { "+" } else { "-" };
+                items
+                    .iter()
+                    .map(|item| format!("{} {}", marker, inlines(item)))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            },
+            BlockContent::Table { header, rows } => {
+                let cells = |row: &[Vec<Inline>]| -> String {
+                    row.iter()
+                        .map(|cell| format!("[{}]", inlines(cell)))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                };
+                let mut arguments = vec![
+                    format!("columns: {}", header.len()),
+                    format!("table.header({})", cells(header)),
+                ];
+                arguments.extend(
+                    rows.iter()
+                        .filter(|row| !row.is_empty())
+                        .map(|row| cells(row)),
+                );
+                format!("#table(\n  {},\n)", arguments.join(",\n  "))
+            },
+            BlockContent::Code { language, code } => {
+                let language = language
+                    .as_deref()
+                    .map(|language| format!("lang: {}, ", string(language)))
+                    .unwrap_or_default();
+                format!(
+                    "#raw(block: true, {}{})",
+                    language,
+                    string(code.trim_end_matches('\n'))
+                )
+            },
+            BlockContent::Admonition { kind, content } => {
+                format!(
+                    "#block(fill: luma(235), inset: 8pt, radius: 4pt, width: \
+                     100%)[#strong[{}:] {}]",
+                    kind.title(),
+                    inlines(content)
+                )
+            },
+            BlockContent::Image { url, alt, caption } => {
+                let mut image =
+                    format!("#link({})[Image: {}]", string(url), escape(alt));
+                if let Some(caption) = caption {
+                    image.push_str(&format!(" #emph[{}]", escape(caption)));
+                }
+                image
+            },
+            BlockContent::DefinitionList(definitions) => {
+                definitions
+                    .iter()
+                    .map(|definition| {
+                        format!(
+                            "/ {}: {}",
+                            escape(&definition.term).replace(':', "\\:"),
+                            inlines(&definition.description)
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            },
+        }
+    }
+
+    fn inlines(&self, inlines: &[Inline]) -> String {
+        let mut markup = String::new();
+        // The arguments of a function call or a field access would go
+        // on in text right after the call, unless it is ended with `;`
+        let mut after_call = false;
+        for (i, inline) in inlines.iter().enumerate() {
+            match inline {
+                Inline::Text(text) => {
+                    if after_call && text.starts_with(['(', '.']) {
+                        markup.push(';');
+                    }
+                    markup.push_str(&escape_text(text, i == 0));
+                },
+                Inline::Emphasis(content) => {
+                    markup
+                        .push_str(&format!("#emph[{}]", self.inlines(content)))
+                },
+                Inline::Strong(content) => {
+                    markup.push_str(&format!(
+                        "#strong[{}]",
+                        self.inlines(content)
+                    ))
+                },
+                Inline::Code(code) => {
+                    markup.push_str(&format!("#raw({})", string(code)))
+                },
+                Inline::Link { url, content } => {
+                    markup.push_str(&format!(
+                        "#link({})[{}]",
+                        string(url),
+                        self.inlines(content)
+                    ))
+                },
+                Inline::Reference { target, content } => {
+                    if after_call {
+                        markup.push(';');
+                    }
+                    markup.push_str(&self.inlines(content));
+                    match self.references.resolve(target) {
+                        Some(anchor) => {
+                            markup.push_str(&format!(" (@{})", anchor.id))
+                        },
+                        None => {
+                            after_call = !matches!(
+                                content.last(),
+                                None | Some(Inline::Text(_))
+                            );
+                            continue;
+                        },
+                    }
+                },
+            }
+            after_call =
+                !matches!(inline, Inline::Text(_) | Inline::Reference { .. });
+        }
+        markup.replace('\n', " ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::model::Definition};
+
+    #[test]
+    fn test_blocks() {
+        let content = vec![
+            BlockContent::Paragraph(vec![
+                Inline::text("Use "),
+                Inline::code("a:b"),
+                Inline::text(" for #1 [sic]"),
+            ]),
+            BlockContent::DefinitionList(vec![Definition::new("IRI", "-x")]),
+            BlockContent::table(&["A", "B"], vec![vec![
+                "1".to_string(),
+                "*".to_string(),
+            ]]),
+        ];
+        assert_eq!(
+            blocks(&content, 2, &CrossReferences::default()),
+            "Use #raw(\"a:b\") for \\#1 \\[sic\\]\n\n/ IRI: \\-x\n\n#table(\n  \
+             columns: 2,\n  table.header([A], [B]),\n  [1], [\\*],\n)"
+        );
+    }
+
+    #[test]
+    fn test_file_stem() {
+        assert_eq!(file_stem("Vehicles (en)"), "Vehicles (en)");
+        assert_eq!(file_stem("A/B\\C: D"), "A_B_C_ D");
+        assert_eq!(file_stem("../etc/passwd"), "_etc_passwd");
+        assert_eq!(file_stem(".hidden"), "hidden");
+        assert_eq!(file_stem(" .. "), "Untitled");
+    }
+}
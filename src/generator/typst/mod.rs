@@ -1,4 +1,5 @@
 mod error;
+mod markup;
 mod this;
 
 pub use this::TypstGenerator;
@@ -1,7 +1,16 @@
 use {
+    super::markup::{self, escape, string},
     crate::{
         generator::Generator,
-        model::{Book, Buildable, Element, Model},
+        model::{
+            Book,
+            Buildable,
+            Chapter,
+            CrossReferences,
+            Element,
+            Model,
+            anchor_id,
+        },
     },
     chrono::{DateTime, Datelike, FixedOffset, Local, Utc},
     std::{
@@ -48,6 +57,8 @@ pub struct TypstGenerator {
     /// The current date and time
     #[allow(dead_code)]
     now:        Now,
+    /// Whether to show where each section and chapter comes from
+    sources:    bool,
 }
 
 impl TypstGenerator {
@@ -81,9 +92,31 @@ impl TypstGenerator {
             library: LazyHash::new(Library::default()),
             temp_dir,
             now: Now::System(OnceLock::new()),
+            sources: false,
         })
     }
 
+    /// Shows the source file and line of each section and chapter
+    /// below its heading, linked to the web interface of its git
+    /// repository if known, see `Provenance`.
+    pub fn with_sources(mut self, sources: bool) -> Self {
+        self.sources = sources;
+        self
+    }
+
+    /// Returns the markup of the source of the given element, if it
+    /// is to be shown.
+    fn source(&self, element: &impl Element) -> Option<String> {
+        if !self.sources {
+            return None;
+        }
+        element
+            .get_provenance()
+            .ok()
+            .flatten()
+            .and_then(|provenance| markup::source(&provenance))
+    }
+
     fn compile_to_pdf(&self) -> anyhow::Result<()> {
         use super::error::eco_vec_into_anyhow;
 
@@ -241,6 +274,8 @@ impl TypstGenerator {
         // Create output directory if it doesn't exist
         fs::create_dir_all(&self.output_dir)?;
 
+        let references = CrossReferences::of_model(&doc_model)?;
+
         // Get all books from the documentation model
         let mut books = Book::get_books(doc_model.clone())?;
 
@@ -267,12 +302,17 @@ impl TypstGenerator {
         let template_content = r#"#let project(
           title: "",
           subtitle: "",
+          version: "",
           authors: "",
+          lang: "",
           body,
         ) = {
           set document(title: title, author: authors)
           set page(numbering: "1", number-align: center)
-          set text(font: "New Computer Modern")
+          set text(
+            font: "New Computer Modern",
+            lang: if lang == "" { "en" } else { lang },
+          )
           set heading(numbering: "1.")
 
           align(center)[
@@ -280,6 +320,9 @@ impl TypstGenerator {
             #if subtitle != "" [
               #block(text(style: "italic", size: 14pt)[#subtitle])
             ]
+            #if version != "" [
+              #block(text(size: 12pt)[Version #version])
+            ]
             #block(text(size: 12pt)[#authors])
           ]
 
@@ -290,34 +333,71 @@ impl TypstGenerator {
             template_content,
         )?;
 
-        // Process each book and create book files
+        // Books with the same title get their own file, since the
+        // labels of their chapters must occur only once, and no book
+        // overwrites the template or the main file
+        let mut book_filenames: Vec<String> = Vec::new();
         for book in &books {
+            let mut title =
+                book.title.as_deref().unwrap_or("Untitled").to_string();
+            if let Some(language) = &book.language {
+                title = format!("{} ({})", title, language);
+            }
+            let title = markup::file_stem(&title);
+            let mut book_filename = format!("{}.typ", title);
+            let mut n = 1;
+            while book_filenames.contains(&book_filename) ||
+                ["main.typ", "template.typ"]
+                    .contains(&book_filename.as_str())
+            {
+                n += 1;
+                book_filename = format!("{} ({}).typ", title, n);
+            }
+            book_filenames.push(book_filename);
+        }
+
+        // Process each book and create book files
+        for (book, book_filename) in books.iter().zip(&book_filenames) {
             // Create a file for this book
-            let book_file = self.output_dir.join(format!(
-                "{}.typ",
-                book.title.as_deref().unwrap_or("Untitled")
-            ));
+            let book_file = self.output_dir.join(book_filename);
             let mut book_content = String::new();
             book_content.push_str("#import \"template.typ\": project\n\n");
 
             // Start the project function
             book_content.push_str(&format!(
-                "#project(\n  title: \"{}\",\n",
-                book.title.as_deref().unwrap_or("Untitled")
+                "#project(\n  title: {},\n",
+                string(book.title.as_deref().unwrap_or("Untitled"))
             ));
 
-            if let Some(ref subtitle) = book.subtitle {
-                book_content
-                    .push_str(&format!("  subtitle: \"{}\",\n", subtitle));
-            } else {
-                book_content.push_str("  subtitle: \"\",\n");
-            }
+            book_content.push_str(&format!(
+                "  subtitle: {},\n",
+                string(book.subtitle.as_deref().unwrap_or(""))
+            ));
 
             book_content.push_str(&format!(
-                "  authors: \"{}\",\n)[",
-                book.authors.join(", ")
+                "  version: {},\n",
+                string(book.version.as_deref().unwrap_or(""))
             ));
 
+            book_content.push_str(&format!(
+                "  authors: {},\n",
+                string(&book.authors.join(", "))
+            ));
+
+            // Typst expects the language without the region, like `en`
+            // for `en-GB`
+            book_content.push_str(&format!(
+                "  lang: {},\n)[",
+                string(
+                    book.language
+                        .as_deref()
+                        .and_then(|language| language.split('-').next())
+                        .unwrap_or("")
+                )
+            ));
+
+            let book_references = references.in_book(book.get_named_node());
+
             // Get sections for this book
             let sections = book.get_sections().unwrap_or_default();
 
@@ -334,28 +414,40 @@ impl TypstGenerator {
                 for section in sections {
                     book_content.push_str(&format!(
                         "\n  = {}\n\n",
-                        section.title.as_deref().unwrap_or("Untitled")
+                        escape(section.title.as_deref().unwrap_or("Untitled"))
                     ));
 
-                    if let Some(ref description) = section.description {
-                        book_content
-                            .push_str(&format!("  {}\n\n", description));
+                    if let Some(source) = self.source(&section) {
+                        book_content.push_str(&format!("  {}\n\n", source));
                     }
 
-                    // Get chapters for this section
-                    for chapter in section.get_chapters().unwrap_or_default() {
-                        book_content.push_str(&format!(
-                            "  == {}\n\n",
-                            chapter.title.as_deref().unwrap_or("Untitled")
-                        ));
+                    if let Some(ref description) = section.description {
                         book_content.push_str(&format!(
                             "  {}\n\n",
-                            chapter.content.as_deref().unwrap_or("")
+                            escape(description)
                         ));
                     }
+
+                    // Get chapters for this section
+                    self.push_chapters(
+                        &mut book_content,
+                        &section.get_chapters().unwrap_or_default(),
+                        2,
+                        &book_references,
+                    );
                 }
             }
 
+            // Add the glossary and the index as back matter
+            let glossary = book.get_glossary().unwrap_or_default();
+            if !glossary.is_empty() {
+                book_content.push_str(&format!(
+                    "\n{}\n{}",
+                    markup::glossary(&glossary),
+                    markup::index(&glossary)
+                ));
+            }
+
             // Close the project function
             book_content.push_str("]\n");
 
@@ -367,18 +459,54 @@ impl TypstGenerator {
         let mut main_content = String::new();
 
         // Include each book file
-        for book in &books {
-            let book_filename = format!(
-                "{}.typ",
-                book.title.as_deref().unwrap_or("Untitled")
-            );
-            main_content.push_str(&format!("#include \"{}\"\n", book_filename));
+        for book_filename in &book_filenames {
+            main_content
+                .push_str(&format!("#include {}\n", string(book_filename)));
         }
 
         fs::write(self.output_dir.join("main.typ"), &main_content)?;
 
         Ok(())
     }
+
+    /// Adds the given chapters, with headings of the given level that
+    /// are labelled for cross-references, and their subchapters one
+    /// level deeper.
+    fn push_chapters(
+        &self,
+        content: &mut String,
+        chapters: &[Chapter],
+        level: usize,
+        references: &CrossReferences,
+    ) {
+        for chapter in chapters {
+            content.push_str(&format!(
+                "  {} {} <{}>\n\n",
+                "=".repeat(level),
+                escape(chapter.title.as_deref().unwrap_or("Untitled")),
+                anchor_id(chapter.get_named_node())
+            ));
+            if let Some(source) = self.source(chapter) {
+                content.push_str(&format!("  {}\n\n", source));
+            }
+            let blocks: Vec<_> = chapter
+                .get_blocks()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|block| block.content)
+                .collect();
+            if !blocks.is_empty() {
+                content.push_str(&markup::blocks(&blocks, level, references));
+                content.push_str("\n\n");
+            }
+            self.push_chapters(
+                content,
+                &chapter.get_chapters().unwrap_or_default(),
+                level + 1,
+                references,
+            );
+        }
+    }
 }
 
 impl Generator for TypstGenerator {
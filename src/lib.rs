@@ -1,9 +1,11 @@
 pub mod cli;
+pub mod config;
 pub mod documentor;
 pub mod generator;
 pub mod loader;
 pub mod model;
 pub mod rdf_const;
+pub mod repl;
 pub mod source;
 pub mod store;
 pub mod util;
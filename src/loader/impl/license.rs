@@ -0,0 +1,73 @@
+use {
+    super::super::Loader,
+    crate::{
+        documentor::{DocumentorImplementor, DocumentorVariant},
+        model::Model,
+        source::{FileSource, FileSourceImplementor},
+        store::LoaderStore,
+        util::{FileType, FileTypeSliceStatic, relative_path},
+    },
+    async_trait::async_trait,
+    std::{path::PathBuf, sync::Arc},
+    tracing::{info, warn},
+};
+
+/// This loader registers license files such as `LICENSE`, `LICENSE.md`
+/// or `COPYING` in the loader store, identifying their SPDX license by
+/// their text.
+#[derive(Debug)]
+pub struct LicenseLoader {}
+
+#[async_trait]
+impl Loader for LicenseLoader {
+    fn file_types(&self) -> FileTypeSliceStatic { &[&FileType::License] }
+
+    /// Registers the given license files and returns one
+    /// `DocumentorVariant::License` documentor, also when there are no
+    /// license files, since licenses can be declared in the RDF files
+    /// as well.
+    async fn load_files(
+        &self,
+        file_source: &FileSourceImplementor,
+        file_names: &[&PathBuf],
+        loader_store: LoaderStore,
+        doc_model: Arc<Model>,
+    ) -> anyhow::Result<Vec<DocumentorImplementor>> {
+        for file_name in file_names {
+            let relative_file_name =
+                relative_path(file_name, file_source.root_path().unwrap());
+            match loader_store
+                .register_license_file(file_name.to_path_buf())
+                .await?
+            {
+                Some(license) => {
+                    info!(
+                        "License file {:} has license {:}",
+                        relative_file_name.display(),
+                        license
+                    );
+                },
+                None => {
+                    warn!(
+                        "Could not identify the license in {:}",
+                        relative_file_name.display()
+                    );
+                },
+            }
+        }
+
+        Ok(vec![DocumentorImplementor::new(
+            DocumentorVariant::License,
+            Some(file_source.clone()),
+            file_names.first().map(|file_name| file_name.as_path()),
+            loader_store,
+            doc_model,
+        )])
+    }
+}
+
+impl std::fmt::Display for LicenseLoader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "License-loader")
+    }
+}
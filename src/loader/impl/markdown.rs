@@ -32,8 +32,30 @@ impl Loader for MarkdownLoader {
         loader_store: LoaderStore,
         doc_model: Arc<Model>,
     ) -> anyhow::Result<Vec<DocumentorImplementor>> {
+        // The Markdown files of a Jekyll site are documented by the
+        // documentor of its _config.yml, not one by one
+        let site_roots: Vec<&Path> = file_names
+            .iter()
+            .filter(|file_name| {
+                FileType::from_path(file_name) == Some(&FileType::JekyllConfig)
+            })
+            .filter_map(|file_name| file_name.parent())
+            .collect();
+        // The RDF code blocks of all Markdown files, including those of
+        // Jekyll sites, are loaded into the loader store
+        for file_name in file_names.iter().filter(|file_name| {
+            FileType::from_path(file_name) == Some(&FileType::Markdown)
+        }) {
+            self.load_code_blocks(file_source, file_name, &loader_store)
+                .await?;
+        }
+
+        let file_names = file_names.iter().filter(|file_name| {
+            FileType::from_path(file_name) != Some(&FileType::Markdown) ||
+                !site_roots.iter().any(|root| file_name.starts_with(root))
+        });
         let documentors =
-            futures::future::try_join_all(file_names.iter().map(|file_name| {
+            futures::future::try_join_all(file_names.map(|file_name| {
                 self.load_file(
                     file_source,
                     file_name.as_path(),
@@ -61,6 +83,28 @@ impl MarkdownLoader {
     //     file_names.contains(&
     // PathBuf::from(JEKYLL_CONFIG_FILE_NAME)) }
 
+    /// Loads the Turtle, TriG, JSON-LD (and other RDF) code blocks of
+    /// the given Markdown file into named graphs of the loader store.
+    async fn load_code_blocks(
+        &self,
+        file_source: &FileSourceImplementor,
+        file_name: &Path,
+        loader_store: &LoaderStore,
+    ) -> anyhow::Result<()> {
+        let graphs = loader_store
+            .load_markdown_code_blocks(file_name.to_path_buf())
+            .await?;
+        if !graphs.is_empty() {
+            tracing::info!(
+                "Loaded {} RDF code blocks from {:}",
+                graphs.len(),
+                relative_path(file_name, file_source.root_path().unwrap())
+                    .display()
+            );
+        }
+        Ok(())
+    }
+
     async fn load_file(
         &self,
         file_source: &FileSourceImplementor,
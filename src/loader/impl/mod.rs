@@ -1,4 +1,5 @@
+mod license;
 mod markdown;
 mod rdf;
 
-pub use {markdown::MarkdownLoader, rdf::RDFLoader};
+pub use {license::LicenseLoader, markdown::MarkdownLoader, rdf::RDFLoader};
@@ -4,7 +4,7 @@ use {
         documentor::{DocumentorImplementor, DocumentorVariant},
         model::Model,
         source::{FileSource, FileSourceImplementor},
-        store::LoaderStore,
+        store::{EKGFKind, LoaderStore},
         util::{FileType, FileTypeSliceStatic, relative_path},
     },
     async_trait::async_trait,
@@ -16,7 +16,7 @@ use {
         path::{Path, PathBuf},
         sync::Arc,
     },
-    tracing::{error, info},
+    tracing::{error, info, warn},
 };
 
 /// This loader is used to load RDF files into the loader store.
@@ -39,7 +39,10 @@ impl Loader for RDFLoader {
     }
 
     /// Use the bulk loader of OxiGraph to load all the given RDF
-    /// files into the given loader store.
+    /// files into the given loader store, each file in its own named
+    /// graph. Returns a documentor per file plus one
+    /// `DocumentorVariant::DatasetProfile` documentor that profiles
+    /// all of the loaded graphs.
     async fn load_files(
         &self,
         file_source: &FileSourceImplementor,
@@ -47,7 +50,7 @@ impl Loader for RDFLoader {
         loader_store: LoaderStore,
         doc_model: Arc<Model>,
     ) -> anyhow::Result<Vec<DocumentorImplementor>> {
-        let documentors =
+        let mut documentors: Vec<DocumentorImplementor> =
             futures::future::try_join_all(file_names.iter().map(|file_name| {
                 self.load_file(
                     file_source,
@@ -61,6 +64,16 @@ impl Loader for RDFLoader {
             .flatten()
             .collect();
 
+        if !file_names.is_empty() {
+            documentors.push(DocumentorImplementor::new(
+                DocumentorVariant::DatasetProfile,
+                Some(file_source.clone()),
+                None,
+                loader_store,
+                doc_model,
+            ));
+        }
+
         Ok(documentors)
     }
 }
@@ -85,16 +98,56 @@ impl RDFLoader {
                 .display()
         );
         let file_name_clone = file_name.to_path_buf().clone();
-        let parser = self.get_parser(file_name_clone.as_path())?;
         let file_source_clone = file_source.clone();
 
+        // Register the file first, each file is loaded into its own
+        // named graph, identified by the file content node in the
+        // file registry.
+        let (content, file_content_node) =
+            loader_store.register_file(file_name_clone.clone()).await?;
+        let parser = self.get_parser(
+            file_name_clone.as_path(),
+            file_content_node.as_ref(),
+        )?;
+
+        let graph = file_content_node.clone();
         let documentors_result = tokio::spawn(async move {
             let store = loader_store.store();
-            let bulk_loader = store.bulk_loader();
             let file_name_x = file_name_clone.as_path();
-            let reader = std::fs::File::open(file_name_x)?;
-            if let Err(loader_error) =
-                bulk_loader.load_from_reader(parser, reader)
+
+            // Parse the file ourselves rather than handing it to the
+            // bulk loader directly, so that we get hold of its prefix
+            // declarations for the prefix registry.
+            let (quads, prefixes, parse_error) = {
+                let mut quad_parser = parser.for_reader(content.as_bytes());
+                let mut quads = Vec::new();
+                let mut parse_error = None;
+                for quad in quad_parser.by_ref() {
+                    match quad {
+                        Ok(quad) => quads.push(quad),
+                        Err(error) => {
+                            parse_error = Some(error);
+                            break;
+                        },
+                    }
+                }
+                let prefixes: Vec<(String, String)> = quad_parser
+                    .prefixes()
+                    .map(|(prefix, namespace)| {
+                        (prefix.to_string(), namespace.to_string())
+                    })
+                    .collect();
+                (quads, prefixes, parse_error)
+            };
+
+            if let Some(parse_error) = parse_error {
+                error!(
+                    "Error loading RDF data from {}: {}",
+                    file_name_x.display(),
+                    parse_error
+                );
+            } else if let Err(loader_error) =
+                store.bulk_loader().load_quads(quads)
             {
                 error!(
                     "Error loading RDF data from {}: {}",
@@ -106,34 +159,138 @@ impl RDFLoader {
                     "Successfully loaded RDF data from {}",
                     file_name_x.display()
                 );
+                // A prefix that cannot be registered does not stop the
+                // file from being documented
+                for (prefix, namespace) in prefixes {
+                    if let Err(err) =
+                        loader_store.register_prefix(&prefix, &namespace).await
+                    {
+                        warn!(
+                            "Ignoring prefix {}: <{}> of {}: {}",
+                            prefix,
+                            namespace,
+                            file_name_x.display(),
+                            err
+                        );
+                    }
+                }
             }
 
-            // Check if this is an OWL ontology
-            let query = r#"
+            // Check if this file is an OWL ontology
+            let query = format!(
+                r#"
                 PREFIX owl: <http://www.w3.org/2002/07/owl#>
                 PREFIX rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#>
-                ASK WHERE {
-                    GRAPH ?g {
+                ASK WHERE {{
+                    GRAPH {graph} {{
                         ?s rdf:type owl:Ontology .
-                    }
-                }
-            "#;
-
-            let is_ontology = match store.query(query)? {
+                    }}
+                }}
+            "#
+            );
+            let is_ontology = match store.query(query.as_str())? {
                 oxigraph::sparql::QueryResults::Boolean(b) => b,
                 _ => false,
             };
             info!("Is OWL ontology: {}", is_ontology);
 
-            let mut documentors: Vec<DocumentorImplementor> = vec![];
-            let documentor = DocumentorImplementor::new(
-                DocumentorVariant::OWLOntology,
-                Some(file_source_clone),
-                Some(file_name_clone.as_path()),
-                loader_store,
-                doc_model,
+            // Check if this file is a SHACL shapes graph
+            let query = format!(
+                r#"
+                PREFIX sh: <http://www.w3.org/ns/shacl#>
+                PREFIX rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#>
+                ASK WHERE {{
+                    GRAPH {graph} {{
+                        ?s rdf:type sh:NodeShape .
+                    }}
+                }}
+            "#
             );
-            documentors.push(documentor);
+            let is_shapes_graph = match store.query(query.as_str())? {
+                oxigraph::sparql::QueryResults::Boolean(b) => b,
+                _ => false,
+            };
+            info!("Is SHACL shapes graph: {}", is_shapes_graph);
+
+            // Check if this file is a SKOS vocabulary
+            let query = format!(
+                r#"
+                PREFIX skos: <http://www.w3.org/2004/02/skos/core#>
+                PREFIX rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#>
+                ASK WHERE {{
+                    GRAPH {graph} {{
+                        ?s rdf:type skos:ConceptScheme .
+                    }}
+                }}
+            "#
+            );
+            let is_vocabulary = match store.query(query.as_str())? {
+                oxigraph::sparql::QueryResults::Boolean(b) => b,
+                _ => false,
+            };
+            info!("Is SKOS vocabulary: {}", is_vocabulary);
+
+            // Check if this file contains EKGF use cases, personas,
+            // outcomes, stories or workflow definitions
+            let query = format!(
+                r#"
+                PREFIX rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#>
+                ASK WHERE {{
+                    VALUES ?type {{ {} }}
+                    GRAPH {graph} {{
+                        ?s rdf:type ?type .
+                    }}
+                }}
+            "#,
+                EKGFKind::ALL
+                    .iter()
+                    .map(|kind| format!("<{}>", kind.class_iri()))
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            );
+            let is_use_case = match store.query(query.as_str())? {
+                oxigraph::sparql::QueryResults::Boolean(b) => b,
+                _ => false,
+            };
+            info!("Is EKGF use case: {}", is_use_case);
+
+            let mut documentors: Vec<DocumentorImplementor> = vec![];
+            if is_use_case {
+                documentors.push(DocumentorImplementor::new(
+                    DocumentorVariant::EKGFUseCase,
+                    Some(file_source_clone.clone()),
+                    Some(file_name_clone.as_path()),
+                    loader_store.clone(),
+                    doc_model.clone(),
+                ));
+            }
+            if is_vocabulary {
+                documentors.push(DocumentorImplementor::new(
+                    DocumentorVariant::SKOSConceptScheme,
+                    Some(file_source_clone.clone()),
+                    Some(file_name_clone.as_path()),
+                    loader_store.clone(),
+                    doc_model.clone(),
+                ));
+            }
+            if is_shapes_graph {
+                documentors.push(DocumentorImplementor::new(
+                    DocumentorVariant::ShaclShapes,
+                    Some(file_source_clone.clone()),
+                    Some(file_name_clone.as_path()),
+                    loader_store.clone(),
+                    doc_model.clone(),
+                ));
+            }
+            if is_ontology {
+                documentors.push(DocumentorImplementor::new(
+                    DocumentorVariant::OWLOntology,
+                    Some(file_source_clone),
+                    Some(file_name_clone.as_path()),
+                    loader_store,
+                    doc_model,
+                ));
+            }
 
             Ok::<Vec<DocumentorImplementor>, anyhow::Error>(documentors)
         })
@@ -142,9 +299,11 @@ impl RDFLoader {
         Ok(documentors_result)
     }
 
-    #[allow(unused)]
-    fn get_parser(&self, file_name: &Path) -> anyhow::Result<RdfParser> {
-        let graph_name = NamedNodeRef::new("http://example.com/g2")?;
+    fn get_parser(
+        &self,
+        file_name: &Path,
+        graph_name: NamedNodeRef,
+    ) -> anyhow::Result<RdfParser> {
         let base_iri = "http://example.com";
 
         let extension = file_name.extension().unwrap().to_str().unwrap();
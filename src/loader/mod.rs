@@ -2,6 +2,6 @@ mod r#impl;
 mod this;
 
 pub use {
-    r#impl::{MarkdownLoader, RDFLoader},
+    r#impl::{LicenseLoader, MarkdownLoader, RDFLoader},
     this::{Loader, LoaderImplementor},
 };
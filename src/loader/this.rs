@@ -1,5 +1,5 @@
 use {
-    super::{MarkdownLoader, RDFLoader},
+    super::{LicenseLoader, MarkdownLoader, RDFLoader},
     crate::{
         documentor::DocumentorImplementor,
         model::Model,
@@ -52,6 +52,7 @@ pub trait Loader: std::fmt::Debug + std::fmt::Display {
 
 #[derive(Debug)]
 pub enum LoaderImplementor {
+    LicenseLoader(LicenseLoader),
     MarkdownLoader(MarkdownLoader),
     RDFLoader(RDFLoader),
 }
@@ -60,6 +61,7 @@ pub enum LoaderImplementor {
 impl Loader for LoaderImplementor {
     fn file_types(&self) -> FileTypeSliceStatic {
         match self {
+            LoaderImplementor::LicenseLoader(loader) => loader.file_types(),
             LoaderImplementor::MarkdownLoader(loader) => loader.file_types(),
             LoaderImplementor::RDFLoader(loader) => loader.file_types(),
         }
@@ -79,6 +81,16 @@ impl Loader for LoaderImplementor {
             applicable_file_names.len()
         );
         match self {
+            LoaderImplementor::LicenseLoader(loader) => {
+                loader
+                    .load_files(
+                        file_source,
+                        &applicable_file_names,
+                        loader_store,
+                        doc_model,
+                    )
+                    .await
+            },
             LoaderImplementor::MarkdownLoader(loader) => {
                 loader
                     .load_files(
@@ -106,6 +118,7 @@ impl Loader for LoaderImplementor {
 impl std::fmt::Display for LoaderImplementor {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            LoaderImplementor::LicenseLoader(loader) => loader.fmt(f),
             LoaderImplementor::MarkdownLoader(loader) => loader.fmt(f),
             LoaderImplementor::RDFLoader(loader) => loader.fmt(f),
         }
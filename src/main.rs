@@ -2,20 +2,22 @@ use {
     clap::{CommandFactory, Parser},
     grapharch::{
         cli::{Cli, Commands},
+        config::Config,
         generator::{
             ConsoleGenerator,
             DocumentationGenerator,
             Generator,
             TypstGenerator,
         },
-        loader::{LoaderImplementor, MarkdownLoader, RDFLoader},
-        model::Model,
+        loader::{LicenseLoader, LoaderImplementor, MarkdownLoader, RDFLoader},
+        model::{CrossReferences, Model, ModelDocument},
+        repl::Repl,
         source::{FileSourceImplementor, FileSourceVariant},
         store::LoaderStore,
         util::setup_tracing,
     },
     std::{path::Path, sync::Arc},
-    tracing::{error, info},
+    tracing::{error, info, warn},
 };
 
 async fn run() -> anyhow::Result<()> {
@@ -35,9 +37,7 @@ async fn run() -> anyhow::Result<()> {
         println!("Value for file: {}", file.display());
     }
 
-    if let Some(config_path) = cli.config.as_deref() {
-        println!("Value for config: {}", config_path.display());
-    }
+    let config = Config::load(cli.config.as_deref())?;
 
     // You can see how many times a particular flag or argument occurred
     // Note, only flags can have multiple occurrences
@@ -54,20 +54,30 @@ async fn run() -> anyhow::Result<()> {
         None,
     )?;
 
-    let doc_model = Arc::new(Model::new()?);
+    let doc_model =
+        Arc::new(Model::new()?.with_languages(config.languages(&cli.language)));
     let loader_store = LoaderStore::new_in_memory()?;
 
     let generator = DocumentationGenerator::new(
         vec![
             LoaderImplementor::MarkdownLoader(MarkdownLoader {}),
             LoaderImplementor::RDFLoader(RDFLoader {}),
+            LoaderImplementor::LicenseLoader(LicenseLoader {}),
         ],
         loader_store.clone(),
         doc_model.clone(),
-    );
-
-    // Process the input files
-    generator.generate_from_file_source(&file_source).await?;
+    )
+    .with_config(config);
+
+    // Process the input files, or read the model that was written
+    // with `generate --json`
+    match cli.model.as_deref() {
+        Some(path) => {
+            ModelDocument::from_file(path)?.into_model(&doc_model)?;
+            generator.validate_model()?
+        },
+        None => generator.generate_from_file_source(&file_source).await?,
+    }
 
     // Handle output generation based on command
     match &cli.command {
@@ -78,16 +88,25 @@ async fn run() -> anyhow::Result<()> {
                 println!("Not printing testing lists...");
             }
         },
-        Some(Commands::Generate { console, typst, pdf, markdown, html }) => {
+        Some(Commands::Generate {
+            console,
+            typst,
+            pdf,
+            markdown,
+            html,
+            json,
+            sources,
+        }) => {
             // Check if any output format is explicitly specified
             let any_output_specified = *console ||
                 typst.is_some() ||
                 pdf.is_some() ||
                 markdown.is_some() ||
-                html.is_some();
+                html.is_some() ||
+                json.is_some();
 
             // Check if we have any input to process
-            let has_input = cli.file.is_some();
+            let has_input = cli.file.is_some() || cli.model.is_some();
 
             // If no input and no output format specified, show help and exit
             if !has_input && !any_output_specified {
@@ -101,9 +120,11 @@ async fn run() -> anyhow::Result<()> {
                 (!typst.is_some() &&
                     !pdf.is_some() &&
                     !markdown.is_some() &&
-                    !html.is_some())
+                    !html.is_some() &&
+                    !json.is_some())
             {
-                let mut console_gen = ConsoleGenerator::new();
+                let mut console_gen =
+                    ConsoleGenerator::new().with_sources(*sources);
                 console_gen.generate(doc_model.clone())?;
             }
 
@@ -119,8 +140,23 @@ async fn run() -> anyhow::Result<()> {
                     .and_then(|p| p.as_ref())
                     .or(Some(&default_pdf_path));
 
-                let mut typst_gen = TypstGenerator::new(typst_dir, pdf_path)?;
+                let mut typst_gen = TypstGenerator::new(typst_dir, pdf_path)?
+                    .with_sources(*sources);
                 typst_gen.generate(doc_model.clone())?;
+
+                // Write the VoID/DCAT dataset description next to the
+                // generated Typst sources, or otherwise next to the PDF,
+                // unless the model was read from JSON and nothing was
+                // loaded
+                if cli.model.is_none() {
+                    let description_dir = typst_dir
+                        .map(|dir| dir.as_path())
+                        .or_else(|| pdf_path.and_then(|p| p.parent()))
+                        .filter(|dir| !dir.as_os_str().is_empty())
+                        .unwrap_or(Path::new("."));
+                    loader_store
+                        .write_dataset_description_to_dir(description_dir)?;
+                }
             }
 
             if let Some(output_dir) = markdown {
@@ -137,6 +173,33 @@ async fn run() -> anyhow::Result<()> {
                     output_dir.display()
                 );
             }
+
+            if let Some(path) = json {
+                std::fs::write(
+                    path,
+                    ModelDocument::of_model(&doc_model)?.to_json()?,
+                )?;
+                info!(
+                    "Wrote the documentation model to {}",
+                    path.display()
+                );
+            }
+
+            // Report the cross-references to resources that are not
+            // documented, which were rendered as plain text
+            let references = CrossReferences::of_model(&doc_model)?;
+            for reference in references.unresolved(&doc_model)? {
+                warn!("Unresolved cross-reference to {}", reference);
+            }
+        },
+        Some(Commands::Repl { history }) => {
+            let mut repl = Repl::new(
+                loader_store.clone(),
+                doc_model.clone(),
+                history.clone(),
+            );
+            // The line editor blocks, so keep it off the async workers
+            tokio::task::spawn_blocking(move || repl.run()).await??;
         },
         None => {},
     }
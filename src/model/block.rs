@@ -0,0 +1,898 @@
+use {
+    super::{
+        Buildable,
+        Element,
+        Model,
+        chapter::Chapter,
+        element::ElementRef,
+        provenance::Provenance,
+    },
+    crate::rdf_const::{
+        classes::{
+            OXI_CLASS_GRAPHARCH_ADMONITION,
+            OXI_CLASS_GRAPHARCH_BLOCK,
+            OXI_CLASS_GRAPHARCH_CODE,
+            OXI_CLASS_GRAPHARCH_CODE_LISTING,
+            OXI_CLASS_GRAPHARCH_CROSS_REFERENCE,
+            OXI_CLASS_GRAPHARCH_DEFINITION,
+            OXI_CLASS_GRAPHARCH_DEFINITION_LIST,
+            OXI_CLASS_GRAPHARCH_EMPHASIS,
+            OXI_CLASS_GRAPHARCH_HEADING,
+            OXI_CLASS_GRAPHARCH_IMAGE,
+            OXI_CLASS_GRAPHARCH_INLINE,
+            OXI_CLASS_GRAPHARCH_LINK,
+            OXI_CLASS_GRAPHARCH_LIST,
+            OXI_CLASS_GRAPHARCH_LIST_ITEM,
+            OXI_CLASS_GRAPHARCH_PARAGRAPH,
+            OXI_CLASS_GRAPHARCH_QUOTE,
+            OXI_CLASS_GRAPHARCH_STRONG,
+            OXI_CLASS_GRAPHARCH_TABLE,
+            OXI_CLASS_GRAPHARCH_TABLE_CELL,
+            OXI_CLASS_GRAPHARCH_TABLE_ROW,
+            OXI_CLASS_GRAPHARCH_TEXT,
+        },
+        predicates::{
+            OXI_GRAPHARCH_ADMONITION_KIND,
+            OXI_GRAPHARCH_CAPTION,
+            OXI_GRAPHARCH_HEADER,
+            OXI_GRAPHARCH_LANGUAGE,
+            OXI_GRAPHARCH_NUMBERED,
+            OXI_GRAPHARCH_ORDER,
+            OXI_GRAPHARCH_PARENT,
+            OXI_GRAPHARCH_TARGET,
+            OXI_GRAPHARCH_TARGET_IRI,
+            OXI_GRAPHARCH_TERM,
+            OXI_GRAPHARCH_TEXT,
+            OXI_GRAPHARCH_URL,
+            OXI_RDF_TYPE,
+        },
+    },
+    anyhow::Result,
+    oxrdf::{GraphName, Literal, NamedNode, Quad, Term},
+    serde::{Deserialize, Serialize},
+};
+
+/// An inline element of the content of a block, like a run of text,
+/// emphasized text or a link.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Inline {
+    Text(String),
+    Emphasis(Vec<Inline>),
+    Strong(Vec<Inline>),
+    Code(String),
+    Link {
+        url:     String,
+        content: Vec<Inline>,
+    },
+    /// A cross-reference to another element of the documentation,
+    /// rendered as its content if the target cannot be resolved
+    Reference {
+        target:  ReferenceTarget,
+        content: Vec<Inline>,
+    },
+}
+
+impl Inline {
+    pub fn text(text: impl Into<String>) -> Self { Inline::Text(text.into()) }
+
+    pub fn code(code: impl Into<String>) -> Self { Inline::Code(code.into()) }
+
+    /// Returns a cross-reference to the element that documents the
+    /// given IRI, with the given text as its content.
+    pub fn reference(iri: impl Into<String>, text: impl Into<String>) -> Self {
+        Inline::Reference {
+            target:  ReferenceTarget::Source(iri.into()),
+            content: vec![Inline::text(text)],
+        }
+    }
+}
+
+/// The target of a cross-reference.
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum ReferenceTarget {
+    /// An element of the documentation model, like a chapter
+    Element(#[serde(with = "super::json::iri")] NamedNode),
+    /// The IRI of the source that an element documents, like the IRI
+    /// of an OWL class
+    Source(String),
+}
+
+impl std::fmt::Display for ReferenceTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReferenceTarget::Element(element) => write!(f, "{}", element),
+            ReferenceTarget::Source(iri) => write!(f, "<{}>", iri),
+        }
+    }
+}
+
+/// Returns the text of the given inlines without any markup.
+pub fn plain_text(inlines: &[Inline]) -> String {
+    inlines
+        .iter()
+        .map(|inline| {
+            match inline {
+                Inline::Text(text) | Inline::Code(text) => text.clone(),
+                Inline::Emphasis(content) |
+                Inline::Strong(content) |
+                Inline::Link { content, .. } |
+                Inline::Reference { content, .. } => plain_text(content),
+            }
+        })
+        .collect()
+}
+
+/// The kind of an admonition, a block that stands out from the text
+/// around it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AdmonitionKind {
+    Note,
+    Tip,
+    Important,
+    Warning,
+    Caution,
+}
+
+impl AdmonitionKind {
+    const ALL: [AdmonitionKind; 5] = [
+        AdmonitionKind::Note,
+        AdmonitionKind::Tip,
+        AdmonitionKind::Important,
+        AdmonitionKind::Warning,
+        AdmonitionKind::Caution,
+    ];
+
+    /// The name of the kind in the model, like `warning`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AdmonitionKind::Note => "note",
+            AdmonitionKind::Tip => "tip",
+            AdmonitionKind::Important => "important",
+            AdmonitionKind::Warning => "warning",
+            AdmonitionKind::Caution => "caution",
+        }
+    }
+
+    /// The title of an admonition of this kind, like `Warning`.
+    pub fn title(&self) -> &'static str {
+        match self {
+            AdmonitionKind::Note => "Note",
+            AdmonitionKind::Tip => "Tip",
+            AdmonitionKind::Important => "Important",
+            AdmonitionKind::Warning => "Warning",
+            AdmonitionKind::Caution => "Caution",
+        }
+    }
+
+    /// Returns the kind with the given name (case-insensitive), like
+    /// the `NOTE` of a GitHub alert `> [!NOTE]`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.as_str().eq_ignore_ascii_case(name))
+    }
+}
+
+/// A term and its description in a definition list.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Definition {
+    pub term:        String,
+    pub description: Vec<Inline>,
+}
+
+impl Definition {
+    /// A definition with a plain text description.
+    pub fn new(
+        term: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        Self {
+            term:        term.into(),
+            description: vec![Inline::text(description)],
+        }
+    }
+}
+
+/// The content of a block of a chapter.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BlockContent {
+    Paragraph(Vec<Inline>),
+    /// A heading within the content of a chapter, below the level of
+    /// its subchapters
+    Heading(Vec<Inline>),
+    /// A bullet list, or a numbered list
+    List {
+        numbered: bool,
+        items:    Vec<Vec<Inline>>,
+    },
+    Table {
+        header: Vec<Vec<Inline>>,
+        rows:   Vec<Vec<Vec<Inline>>>,
+    },
+    /// A code listing, like a Turtle example or a SPARQL query
+    Code {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        language: Option<String>,
+        code:     String,
+    },
+    Admonition {
+        kind:    AdmonitionKind,
+        content: Vec<Inline>,
+    },
+    Image {
+        url:     String,
+        alt:     String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        caption: Option<String>,
+    },
+    DefinitionList(Vec<Definition>),
+    Quote(Vec<Inline>),
+}
+
+impl BlockContent {
+    /// A paragraph of plain text.
+    pub fn paragraph(text: impl Into<String>) -> Self {
+        BlockContent::Paragraph(vec![Inline::text(text)])
+    }
+
+    /// A heading of plain text.
+    pub fn heading(text: impl Into<String>) -> Self {
+        BlockContent::Heading(vec![Inline::text(text)])
+    }
+
+    /// A bullet list of plain text items.
+    pub fn bullets(items: impl IntoIterator<Item = String>) -> Self {
+        BlockContent::List {
+            numbered: false,
+            items:    items
+                .into_iter()
+                .map(|item| vec![Inline::Text(item)])
+                .collect(),
+        }
+    }
+
+    /// A table of plain text cells.
+    pub fn table(header: &[&str], rows: Vec<Vec<String>>) -> Self {
+        BlockContent::Table {
+            header: header
+                .iter()
+                .map(|cell| vec![Inline::text(*cell)])
+                .collect(),
+            rows:   rows
+                .into_iter()
+                .map(|row| {
+                    row.into_iter()
+                        .map(|cell| vec![Inline::Text(cell)])
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    /// The class of the block in the model, like `doc:Paragraph`.
+    fn class(&self) -> &'static NamedNode {
+        match self {
+            BlockContent::Paragraph(_) => &OXI_CLASS_GRAPHARCH_PARAGRAPH,
+            BlockContent::Heading(_) => &OXI_CLASS_GRAPHARCH_HEADING,
+            BlockContent::List { .. } => &OXI_CLASS_GRAPHARCH_LIST,
+            BlockContent::Table { .. } => &OXI_CLASS_GRAPHARCH_TABLE,
+            BlockContent::Code { .. } => &OXI_CLASS_GRAPHARCH_CODE_LISTING,
+            BlockContent::Admonition { .. } => &OXI_CLASS_GRAPHARCH_ADMONITION,
+            BlockContent::Image { .. } => &OXI_CLASS_GRAPHARCH_IMAGE,
+            BlockContent::DefinitionList(_) => {
+                &OXI_CLASS_GRAPHARCH_DEFINITION_LIST
+            },
+            BlockContent::Quote(_) => &OXI_CLASS_GRAPHARCH_QUOTE,
+        }
+    }
+}
+
+/// A block of content of a chapter, such as a paragraph, a list or a
+/// table.
+///
+/// A block is stored as a `doc:Block` node of the model whose
+/// parts (list items, table rows and cells, definitions and inlines)
+/// are nodes too, each with its `doc:parent` and `doc:order`.
+#[derive(Debug, Clone)]
+pub struct Block {
+    pub(super) element_ref: ElementRef,
+    pub content:            BlockContent,
+}
+
+impl Element for Block {
+    type Builder = BlockBuilder;
+
+    fn builder(element_ref: ElementRef) -> anyhow::Result<Self::Builder> {
+        Ok(BlockBuilder::new(element_ref))
+    }
+
+    fn get_element_ref(&self) -> &ElementRef { &self.element_ref }
+}
+
+pub struct BlockBuilder {
+    element_ref: ElementRef,
+    content:     Option<BlockContent>,
+    /// The chapter that the block belongs to
+    parent:      Option<NamedNode>,
+    order:       Option<i64>,
+    provenance:  Option<Provenance>,
+}
+
+impl Buildable<Block> for BlockBuilder {
+    fn get_element_ref(&self) -> &ElementRef { &self.element_ref }
+
+    fn get_element_ref_mut(&mut self) -> &mut ElementRef {
+        &mut self.element_ref
+    }
+
+    fn new(element_ref: ElementRef) -> Self {
+        Self {
+            element_ref,
+            content: None,
+            parent: None,
+            order: None,
+            provenance: None,
+        }
+    }
+
+    fn build(&mut self) -> Result<Block> {
+        let content = self
+            .content
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("A block needs content"))?;
+
+        // A block is identified by its position in its chapter
+        let parent = self.parent.clone();
+        let order = match &parent {
+            Some(parent) => Some(self.resolve_order(parent, self.order)?),
+            None => self.order,
+        };
+        let parts = [
+            parent
+                .as_ref()
+                .map(|parent| parent.as_str().to_string())
+                .unwrap_or_default(),
+            order.map(|order| order.to_string()).unwrap_or_default(),
+        ];
+        self.identify(&OXI_CLASS_GRAPHARCH_BLOCK, &parts)?;
+
+        self.insert_type(&OXI_CLASS_GRAPHARCH_BLOCK)?;
+        self.insert_type(content.class())?;
+        if let Some(parent) = &parent {
+            self.insert_parent(parent, order)?;
+        }
+        if let Some(provenance) = &self.provenance {
+            self.insert_provenance(provenance)?;
+        }
+        ContentWriter {
+            model: self.get_model(),
+            graph: self.get_graph_name(),
+        }
+        .block(self.get_named_node(), &content)?;
+
+        Ok(Block { element_ref: self.element_ref.clone(), content })
+    }
+}
+
+impl BlockBuilder {
+    pub fn content(mut self, content: BlockContent) -> Self {
+        self.content = Some(content);
+        self
+    }
+
+    /// Adds the block to the given chapter.
+    pub fn chapter(mut self, chapter: Option<&Chapter>) -> Self {
+        self.parent = chapter.map(|chapter| chapter.get_named_node().clone());
+        self
+    }
+
+    /// Sets the position of the block in its chapter, the block goes
+    /// after the existing blocks (and subchapters) of the chapter if
+    /// not set.
+    pub fn order(mut self, order: Option<i64>) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Records where the block comes from, like the line of a code
+    /// block in a Markdown file, see `Provenance`.
+    pub fn provenance(mut self, provenance: Option<Provenance>) -> Self {
+        self.provenance = provenance;
+        self
+    }
+}
+
+impl Block {
+    /// Returns the blocks of the given chapter, in order.
+    pub(super) fn get_blocks_of(
+        model: &std::sync::Arc<Model>,
+        chapter: &ElementRef,
+    ) -> Result<Vec<Block>> {
+        let reader = ContentReader {
+            model: model.as_ref(),
+            graph: chapter.get_graph_name(),
+        };
+        let mut blocks = Vec::new();
+        for node in reader.children(chapter.get_named_node()) {
+            if !reader.is_a(&node, &OXI_CLASS_GRAPHARCH_BLOCK) {
+                continue;
+            }
+            if let Some(content) = reader.block(&node) {
+                blocks.push(Block {
+                    element_ref: ElementRef {
+                        named_node: node,
+                        graph_name: chapter.get_graph_name().clone(),
+                        model:      model.clone(),
+                    },
+                    content,
+                });
+            }
+        }
+        Ok(blocks)
+    }
+}
+
+/// Writes the parts of a block to the model, as nodes named after
+/// their parent with their position appended, like `<block>/2/0` for
+/// the first inline of the third item of a list.
+struct ContentWriter<'a> {
+    model: &'a Model,
+    graph: &'a GraphName,
+}
+
+impl ContentWriter<'_> {
+    fn insert(
+        &self,
+        subject: &NamedNode,
+        predicate: &NamedNode,
+        object: impl Into<Term>,
+    ) -> Result<()> {
+        self.model.insert(&Quad::new(
+            subject.clone(),
+            predicate.clone(),
+            object,
+            self.graph.clone(),
+        ))
+    }
+
+    /// Adds a part of the given class at the given position of its
+    /// parent.
+    fn part(
+        &self,
+        parent: &NamedNode,
+        order: usize,
+        class: &NamedNode,
+    ) -> Result<NamedNode> {
+        let node = NamedNode::new(format!("{}/{}", parent.as_str(), order))?;
+        self.insert(&node, &OXI_RDF_TYPE, class.clone())?;
+        self.insert(&node, &OXI_GRAPHARCH_PARENT, parent.clone())?;
+        self.insert(
+            &node,
+            &OXI_GRAPHARCH_ORDER,
+            Literal::from(order as i64),
+        )?;
+        Ok(node)
+    }
+
+    fn text(
+        &self,
+        node: &NamedNode,
+        predicate: &NamedNode,
+        text: &str,
+    ) -> Result<()> {
+        self.insert(node, predicate, Literal::new_simple_literal(text))
+    }
+
+    fn block(&self, node: &NamedNode, content: &BlockContent) -> Result<()> {
+        match content {
+            BlockContent::Paragraph(inlines) |
+            BlockContent::Heading(inlines) |
+            BlockContent::Quote(inlines) => self.inlines(node, inlines)?,
+            BlockContent::List { numbered, items } => {
+                self.insert(
+                    node,
+                    &OXI_GRAPHARCH_NUMBERED,
+                    Literal::from(*numbered),
+                )?;
+                for (i, item) in items.iter().enumerate() {
+                    let item_node =
+                        self.part(node, i, &OXI_CLASS_GRAPHARCH_LIST_ITEM)?;
+                    self.inlines(&item_node, item)?;
+                }
+            },
+            BlockContent::Table { header, rows } => {
+                let all_rows = std::iter::once((true, header))
+                    .chain(rows.iter().map(|row| (false, row)));
+                for (i, (is_header, row)) in all_rows.enumerate() {
+                    let row_node =
+                        self.part(node, i, &OXI_CLASS_GRAPHARCH_TABLE_ROW)?;
+                    if is_header {
+                        self.insert(
+                            &row_node,
+                            &OXI_GRAPHARCH_HEADER,
+                            Literal::from(true),
+                        )?;
+                    }
+                    for (j, cell) in row.iter().enumerate() {
+                        let cell_node = self.part(
+                            &row_node,
+                            j,
+                            &OXI_CLASS_GRAPHARCH_TABLE_CELL,
+                        )?;
+                        self.inlines(&cell_node, cell)?;
+                    }
+                }
+            },
+            BlockContent::Code { language, code } => {
+                if let Some(language) = language {
+                    self.text(node, &OXI_GRAPHARCH_LANGUAGE, language)?;
+                }
+                self.text(node, &OXI_GRAPHARCH_TEXT, code)?;
+            },
+            BlockContent::Admonition { kind, content } => {
+                self.text(
+                    node,
+                    &OXI_GRAPHARCH_ADMONITION_KIND,
+                    kind.as_str(),
+                )?;
+                self.inlines(node, content)?;
+            },
+            BlockContent::Image { url, alt, caption } => {
+                self.text(node, &OXI_GRAPHARCH_URL, url)?;
+                self.text(node, &OXI_GRAPHARCH_TEXT, alt)?;
+                if let Some(caption) = caption {
+                    self.text(node, &OXI_GRAPHARCH_CAPTION, caption)?;
+                }
+            },
+            BlockContent::DefinitionList(definitions) => {
+                for (i, definition) in definitions.iter().enumerate() {
+                    let definition_node =
+                        self.part(node, i, &OXI_CLASS_GRAPHARCH_DEFINITION)?;
+                    self.text(
+                        &definition_node,
+                        &OXI_GRAPHARCH_TERM,
+                        &definition.term,
+                    )?;
+                    self.inlines(&definition_node, &definition.description)?;
+                }
+            },
+        }
+        Ok(())
+    }
+
+    fn inlines(&self, parent: &NamedNode, inlines: &[Inline]) -> Result<()> {
+        for (i, inline) in inlines.iter().enumerate() {
+            let class = match inline {
+                Inline::Text(_) => &*OXI_CLASS_GRAPHARCH_TEXT,
+                Inline::Emphasis(_) => &*OXI_CLASS_GRAPHARCH_EMPHASIS,
+                Inline::Strong(_) => &*OXI_CLASS_GRAPHARCH_STRONG,
+                Inline::Code(_) => &*OXI_CLASS_GRAPHARCH_CODE,
+                Inline::Link { .. } => &*OXI_CLASS_GRAPHARCH_LINK,
+                Inline::Reference { .. } => {
+                    &*OXI_CLASS_GRAPHARCH_CROSS_REFERENCE
+                },
+            };
+            let node = self.part(parent, i, class)?;
+            self.insert(
+                &node,
+                &OXI_RDF_TYPE,
+                OXI_CLASS_GRAPHARCH_INLINE.clone(),
+            )?;
+            match inline {
+                Inline::Text(text) | Inline::Code(text) => {
+                    self.text(&node, &OXI_GRAPHARCH_TEXT, text)?
+                },
+                Inline::Emphasis(content) | Inline::Strong(content) => {
+                    self.inlines(&node, content)?
+                },
+                Inline::Link { url, content } => {
+                    self.text(&node, &OXI_GRAPHARCH_URL, url)?;
+                    self.inlines(&node, content)?;
+                },
+                Inline::Reference { target, content } => {
+                    match target {
+                        ReferenceTarget::Element(element) => {
+                            self.insert(
+                                &node,
+                                &OXI_GRAPHARCH_TARGET,
+                                element.clone(),
+                            )?
+                        },
+                        ReferenceTarget::Source(iri) => {
+                            self.insert(
+                                &node,
+                                &OXI_GRAPHARCH_TARGET_IRI,
+                                NamedNode::new(iri)?,
+                            )?
+                        },
+                    }
+                    self.inlines(&node, content)?;
+                },
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Reads the parts of a block back from the model.
+struct ContentReader<'a> {
+    model: &'a Model,
+    graph: &'a GraphName,
+}
+
+impl ContentReader<'_> {
+    fn objects(&self, node: &NamedNode, predicate: &NamedNode) -> Vec<Term> {
+        self.model
+            .get_store()
+            .quads_for_pattern(
+                Some(node.as_ref().into()),
+                Some(predicate.as_ref()),
+                None,
+                Some(self.graph.as_ref()),
+            )
+            .filter_map(Result::ok)
+            .map(|quad| quad.object)
+            .collect()
+    }
+
+    fn string(
+        &self,
+        node: &NamedNode,
+        predicate: &NamedNode,
+    ) -> Option<String> {
+        self.objects(node, predicate).into_iter().find_map(|term| {
+            match term {
+                Term::Literal(literal) => Some(literal.value().to_string()),
+                _ => None,
+            }
+        })
+    }
+
+    fn named_node(
+        &self,
+        node: &NamedNode,
+        predicate: &NamedNode,
+    ) -> Option<NamedNode> {
+        self.objects(node, predicate).into_iter().find_map(|term| {
+            match term {
+                Term::NamedNode(node) => Some(node),
+                _ => None,
+            }
+        })
+    }
+
+    fn boolean(&self, node: &NamedNode, predicate: &NamedNode) -> bool {
+        self.string(node, predicate).as_deref() == Some("true")
+    }
+
+    fn is_a(&self, node: &NamedNode, class: &NamedNode) -> bool {
+        self.objects(node, &OXI_RDF_TYPE)
+            .iter()
+            .any(|term| matches!(term, Term::NamedNode(c) if c == class))
+    }
+
+    /// Returns the children of the given node, ordered by their
+    /// `doc:order`.
+    fn children(&self, parent: &NamedNode) -> Vec<NamedNode> {
+        let mut children: Vec<(i64, NamedNode)> = self
+            .model
+            .get_store()
+            .quads_for_pattern(
+                None,
+                Some(OXI_GRAPHARCH_PARENT.as_ref()),
+                Some(parent.as_ref().into()),
+                Some(self.graph.as_ref()),
+            )
+            .filter_map(Result::ok)
+            .filter_map(|quad| {
+                match quad.subject {
+                    oxrdf::Subject::NamedNode(node) => Some(node),
+                    _ => None,
+                }
+            })
+            .map(|node| {
+                let order = self
+                    .string(&node, &OXI_GRAPHARCH_ORDER)
+                    .and_then(|order| order.parse().ok())
+                    .unwrap_or(i64::MAX);
+                (order, node)
+            })
+            .collect();
+        children.sort_by(|(a, a_node), (b, b_node)| {
+            a.cmp(b).then_with(|| a_node.as_str().cmp(b_node.as_str()))
+        });
+        children.into_iter().map(|(_, node)| node).collect()
+    }
+
+    fn block(&self, node: &NamedNode) -> Option<BlockContent> {
+        let is_a = |class: &NamedNode| self.is_a(node, class);
+        let content = if is_a(&OXI_CLASS_GRAPHARCH_PARAGRAPH) {
+            BlockContent::Paragraph(self.inlines(node))
+        } else if is_a(&OXI_CLASS_GRAPHARCH_HEADING) {
+            BlockContent::Heading(self.inlines(node))
+        } else if is_a(&OXI_CLASS_GRAPHARCH_QUOTE) {
+            BlockContent::Quote(self.inlines(node))
+        } else if is_a(&OXI_CLASS_GRAPHARCH_LIST) {
+            BlockContent::List {
+                numbered: self.boolean(node, &OXI_GRAPHARCH_NUMBERED),
+                items:    self
+                    .children(node)
+                    .iter()
+                    .map(|item| self.inlines(item))
+                    .collect(),
+            }
+        } else if is_a(&OXI_CLASS_GRAPHARCH_TABLE) {
+            let mut header = Vec::new();
+            let mut rows = Vec::new();
+            for row in self.children(node) {
+                let cells: Vec<Vec<Inline>> = self
+                    .children(&row)
+                    .iter()
+                    .map(|cell| self.inlines(cell))
+                    .collect();
+                if self.boolean(&row, &OXI_GRAPHARCH_HEADER) {
+                    header = cells;
+                } else {
+                    rows.push(cells);
+                }
+            }
+            BlockContent::Table { header, rows }
+        } else if is_a(&OXI_CLASS_GRAPHARCH_CODE_LISTING) {
+            BlockContent::Code {
+                language: self.string(node, &OXI_GRAPHARCH_LANGUAGE),
+                code:     self
+                    .string(node, &OXI_GRAPHARCH_TEXT)
+                    .unwrap_or_default(),
+            }
+        } else if is_a(&OXI_CLASS_GRAPHARCH_ADMONITION) {
+            BlockContent::Admonition {
+                kind:    self
+                    .string(node, &OXI_GRAPHARCH_ADMONITION_KIND)
+                    .and_then(|kind| AdmonitionKind::from_name(&kind))
+                    .unwrap_or(AdmonitionKind::Note),
+                content: self.inlines(node),
+            }
+        } else if is_a(&OXI_CLASS_GRAPHARCH_IMAGE) {
+            BlockContent::Image {
+                url:     self.string(node, &OXI_GRAPHARCH_URL)?,
+                alt:     self
+                    .string(node, &OXI_GRAPHARCH_TEXT)
+                    .unwrap_or_default(),
+                caption: self.string(node, &OXI_GRAPHARCH_CAPTION),
+            }
+        } else if is_a(&OXI_CLASS_GRAPHARCH_DEFINITION_LIST) {
+            BlockContent::DefinitionList(
+                self.children(node)
+                    .iter()
+                    .map(|definition| {
+                        Definition {
+                            term:        self
+                                .string(definition, &OXI_GRAPHARCH_TERM)
+                                .unwrap_or_default(),
+                            description: self.inlines(definition),
+                        }
+                    })
+                    .collect(),
+            )
+        } else {
+            return None;
+        };
+        Some(content)
+    }
+
+    /// Returns the inlines that are the children of the given node.
+    fn inlines(&self, parent: &NamedNode) -> Vec<Inline> {
+        self.children(parent)
+            .iter()
+            .filter_map(|node| {
+                let is_a = |class: &NamedNode| self.is_a(node, class);
+                let text = || {
+                    self.string(node, &OXI_GRAPHARCH_TEXT).unwrap_or_default()
+                };
+                if is_a(&OXI_CLASS_GRAPHARCH_TEXT) {
+                    Some(Inline::Text(text()))
+                } else if is_a(&OXI_CLASS_GRAPHARCH_CODE) {
+                    Some(Inline::Code(text()))
+                } else if is_a(&OXI_CLASS_GRAPHARCH_EMPHASIS) {
+                    Some(Inline::Emphasis(self.inlines(node)))
+                } else if is_a(&OXI_CLASS_GRAPHARCH_STRONG) {
+                    Some(Inline::Strong(self.inlines(node)))
+                } else if is_a(&OXI_CLASS_GRAPHARCH_LINK) {
+                    Some(Inline::Link {
+                        url:     self
+                            .string(node, &OXI_GRAPHARCH_URL)
+                            .unwrap_or_default(),
+                        content: self.inlines(node),
+                    })
+                } else if is_a(&OXI_CLASS_GRAPHARCH_CROSS_REFERENCE) {
+                    let target =
+                        match self.named_node(node, &OXI_GRAPHARCH_TARGET) {
+                            Some(element) => ReferenceTarget::Element(element),
+                            None => {
+                                ReferenceTarget::Source(
+                                    self.named_node(
+                                        node,
+                                        &OXI_GRAPHARCH_TARGET_IRI,
+                                    )?
+                                    .into_string(),
+                                )
+                            },
+                        };
+                    Some(Inline::Reference {
+                        target,
+                        content: self.inlines(node),
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::model::Section, std::sync::Arc};
+
+    #[test]
+    fn test_blocks_round_trip() -> anyhow::Result<()> {
+        let model = Arc::new(Model::new()?);
+        let section = Section::builder_in_model::<Section>(&model)?
+            .title(Some("Section".to_string()))
+            .build()?;
+        let blocks = vec![
+            BlockContent::Paragraph(vec![
+                Inline::text("See "),
+                Inline::Link {
+                    url:     "https://ekgf.org".to_string(),
+                    content: vec![Inline::Emphasis(vec![Inline::text("EKGF")])],
+                },
+                Inline::code("ex:a"),
+                Inline::reference("http://example.com/a", "ex:a"),
+            ]),
+            BlockContent::List {
+                numbered: true,
+                items:    vec![vec![Inline::text("one")], vec![
+                    Inline::Strong(vec![Inline::text("two")]),
+                ]],
+            },
+            BlockContent::table(&["A", "B"], vec![vec![
+                "1".to_string(),
+                "2".to_string(),
+            ]]),
+            BlockContent::Code {
+                language: Some("turtle".to_string()),
+                code:     ":a :b :c .".to_string(),
+            },
+            BlockContent::Admonition {
+                kind:    AdmonitionKind::Warning,
+                content: vec![Inline::text("Careful")],
+            },
+            BlockContent::Image {
+                url:     "logo.png".to_string(),
+                alt:     "Logo".to_string(),
+                caption: None,
+            },
+            BlockContent::DefinitionList(vec![Definition::new("IRI", "ex:a")]),
+        ];
+        let chapter = Chapter::builder_in_model::<Chapter>(&model)?
+            .title(Some("Chapter".to_string()))
+            .blocks(blocks.clone())
+            .section(Some(&section))
+            .build()?;
+        let read: Vec<BlockContent> = chapter
+            .get_blocks()?
+            .into_iter()
+            .map(|block| block.content)
+            .collect();
+        assert_eq!(read, blocks);
+        assert!(model.validate()?.is_empty());
+        Ok(())
+    }
+}
@@ -2,10 +2,27 @@ use {
     super::{
         Buildable,
         Model,
-        element::{Element, ElementRef},
+        element::{Element, ElementRef, literal_value, localized_literal},
+        provenance::Provenance,
         section::Section,
     },
-    oxrdf::{Literal, NamedNode},
+    crate::rdf_const::{
+        classes::{OXI_CLASS_GRAPHARCH_BOOK, OXI_CLASS_GRAPHARCH_SECTION},
+        predicates::{
+            OXI_DCTERMS_CREATOR,
+            OXI_DCTERMS_DESCRIPTION,
+            OXI_DCTERMS_LANGUAGE,
+            OXI_DCTERMS_TITLE,
+            OXI_GRAPHARCH_KEY,
+            OXI_GRAPHARCH_ORDER,
+            OXI_GRAPHARCH_PARENT,
+            OXI_GRAPHARCH_REPOSITORY,
+            OXI_GRAPHARCH_SUBTITLE,
+            OXI_GRAPHARCH_URL,
+            OXI_GRAPHARCH_VERSION,
+        },
+    },
+    oxrdf::Literal,
     std::sync::Arc,
     tracing::{info, trace},
 };
@@ -13,11 +30,18 @@ use {
 #[derive(Debug, Clone)]
 pub struct Book {
     element_ref:    ElementRef,
+    /// The stable key of the book, books with the same key are merged
+    /// into one book, see `Book::merge_books`
+    pub key:        Option<String>,
     pub title:      Option<String>,
     pub subtitle:   Option<String>,
+    pub version:    Option<String>,
     pub authors:    Vec<String>,
     pub repository: Option<String>,
     pub url:        Option<String>,
+    /// The language tag of the book, like `en` or `nl`, if it is
+    /// written in one language
+    pub language:   Option<String>,
     pub sections:   Vec<Section>,
 }
 
@@ -34,43 +58,104 @@ impl Element for Book {
 #[derive(Clone)]
 pub struct BookBuilder {
     element_ref: ElementRef,
+    key:         Option<String>,
     title:       Option<String>,
     subtitle:    Option<String>,
+    version:     Option<String>,
     authors:     Vec<String>,
     repository:  Option<String>,
     url:         Option<String>,
+    language:    Option<String>,
+    provenance:  Option<Provenance>,
 }
 
 impl Buildable<Book> for BookBuilder {
     fn new(element_ref: ElementRef) -> Self {
         Self {
             element_ref,
+            key: None,
             title: None,
             subtitle: None,
+            version: None,
             authors: Vec::new(),
             repository: None,
             url: None,
+            language: None,
+            provenance: None,
         }
     }
 
     fn get_element_ref(&self) -> &ElementRef { &self.element_ref }
 
+    fn get_element_ref_mut(&mut self) -> &mut ElementRef {
+        &mut self.element_ref
+    }
+
     fn build(&mut self) -> anyhow::Result<Book> {
+        // Books are identified by their key and language and, for
+        // books with the same key before they are merged, by their file
+        // (or graph) and title
+        let parts = [
+            self.key.clone().unwrap_or_default(),
+            self.language.clone().unwrap_or_default(),
+            self.provenance
+                .as_ref()
+                .and_then(|provenance| {
+                    provenance.file.clone().or_else(|| {
+                        provenance
+                            .graph
+                            .as_ref()
+                            .map(|graph| graph.as_str().to_string())
+                    })
+                })
+                .unwrap_or_default(),
+            self.title.clone().unwrap_or_default(),
+        ];
+        self.identify(&OXI_CLASS_GRAPHARCH_BOOK, &parts)?;
+
         // Add type quad
-        self.insert_type("http://example.org/Book")?;
+        self.insert_type(&OXI_CLASS_GRAPHARCH_BOOK)?;
+
+        if let Some(ref key) = self.key {
+            self.insert_object_literal(
+                OXI_GRAPHARCH_KEY.clone(),
+                Literal::new_simple_literal(key),
+            )?;
+        }
 
         // Add title quad if present
         if let Some(ref title) = self.title {
             self.insert_object_literal(
-                NamedNode::new("http://purl.org/dc/terms/title")?,
-                Literal::new_simple_literal(title),
+                OXI_DCTERMS_TITLE.clone(),
+                localized_literal(title, self.language.as_deref())?,
+            )?;
+        }
+
+        if let Some(ref language) = self.language {
+            self.insert_object_literal(
+                OXI_DCTERMS_LANGUAGE.clone(),
+                Literal::new_simple_literal(language),
+            )?;
+        }
+
+        if let Some(ref subtitle) = self.subtitle {
+            self.insert_object_literal(
+                OXI_GRAPHARCH_SUBTITLE.clone(),
+                Literal::new_simple_literal(subtitle),
+            )?;
+        }
+
+        if let Some(ref version) = self.version {
+            self.insert_object_literal(
+                OXI_GRAPHARCH_VERSION.clone(),
+                Literal::new_simple_literal(version),
             )?;
         }
 
         // Add author quads
         for author in &self.authors {
             self.insert_object_literal(
-                NamedNode::new("http://purl.org/dc/terms/creator")?,
+                OXI_DCTERMS_CREATOR.clone(),
                 Literal::new_simple_literal(author),
             )?;
         }
@@ -78,31 +163,45 @@ impl Buildable<Book> for BookBuilder {
         // Add optional fields
         if let Some(ref repository) = self.repository {
             self.insert_object_literal(
-                NamedNode::new("http://example.com/schema/repository")?,
+                OXI_GRAPHARCH_REPOSITORY.clone(),
                 Literal::new_simple_literal(repository),
             )?;
         }
 
         if let Some(ref url) = self.url {
             self.insert_object_literal(
-                NamedNode::new("http://example.com/schema/url")?,
+                OXI_GRAPHARCH_URL.clone(),
                 Literal::new_simple_literal(url),
             )?;
         }
 
+        if let Some(provenance) = &self.provenance {
+            self.insert_provenance(provenance)?;
+        }
+
         Ok(Book {
             element_ref: self.element_ref.clone(),
+            key:         self.key.clone(),
             title:       self.title.clone(),
             subtitle:    self.subtitle.clone(),
+            version:     self.version.clone(),
             authors:     self.authors.clone(),
             repository:  self.repository.clone(),
             url:         self.url.clone(),
+            language:    self.language.clone(),
             sections:    Vec::new(),
         })
     }
 }
 
 impl BookBuilder {
+    /// Sets the stable key of the book, such as the IRI of the
+    /// ontology that it documents, see `Book::merge_books`.
+    pub fn key(mut self, key: Option<String>) -> Self {
+        self.key = key;
+        self
+    }
+
     /// Sets the title for the book.
     pub fn title(mut self, title: Option<String>) -> Self {
         self.title = title;
@@ -115,6 +214,13 @@ impl BookBuilder {
         self
     }
 
+    /// Sets the version of the book, such as the version of the
+    /// ontology that it documents.
+    pub fn version(mut self, version: Option<String>) -> Self {
+        self.version = version;
+        self
+    }
+
     /// Adds an author to the book.
     pub fn author(mut self, author: Option<String>) -> Self {
         if let Some(author) = author {
@@ -134,27 +240,41 @@ impl BookBuilder {
         self.url = url;
         self
     }
+
+    /// Sets the language tag of the book, like `en`, which is also
+    /// the language tag of its title.
+    pub fn language(mut self, language: Option<String>) -> Self {
+        self.language = language;
+        self
+    }
+
+    /// Records where the book comes from, see `Provenance`.
+    pub fn provenance(mut self, provenance: Option<Provenance>) -> Self {
+        self.provenance = provenance;
+        self
+    }
 }
 
 impl Book {
-    /// Get all sections for a given book
-    /// TODO: Move this to the Book class, it should just be called
-    /// "Book::get_sections" returning a vector of Sections
+    /// Returns the sections of the book, in order.
     pub fn get_sections(&self) -> anyhow::Result<Vec<Section>> {
         let query = format!(
             r#"
-            PREFIX dc: <http://purl.org/dc/terms/>
-            PREFIX rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#>
-            SELECT DISTINCT ?graph ?iri ?title ?description WHERE {{
-                GRAPH ?graph {{
-                    ?iri rdf:type <http://example.org/Section> ;
-                            dc:title ?title ;
-                            <http://example.org/schema/belongsTo> {} .
-                    OPTIONAL {{ ?iri dc:description ?description }}
-                }}
+            SELECT DISTINCT ?iri ?title ?description ?order WHERE {{
+                ?iri a {section} ;
+                     {parent} {book} .
+                OPTIONAL {{ ?iri {title} ?title }}
+                OPTIONAL {{ ?iri {description} ?description }}
+                OPTIONAL {{ ?iri {order} ?order }}
             }}
+            ORDER BY ?order ?title
             "#,
-            self.get_named_node(),
+            section = *OXI_CLASS_GRAPHARCH_SECTION,
+            title = *OXI_DCTERMS_TITLE,
+            parent = *OXI_GRAPHARCH_PARENT,
+            book = self.get_named_node(),
+            description = *OXI_DCTERMS_DESCRIPTION,
+            order = *OXI_GRAPHARCH_ORDER,
         );
 
         let results = self.get_model().get_store().query(&query)?;
@@ -168,10 +288,8 @@ impl Book {
                         self.get_model(),
                         &solution,
                     )?,
-                    title:       solution.get("title").map(|s| s.to_string()),
-                    description: solution
-                        .get("description")
-                        .map(|s| s.to_string()),
+                    title:       literal_value(&solution, "title"),
+                    description: literal_value(&solution, "description"),
                 };
                 sections.push(section);
             }
@@ -193,37 +311,66 @@ impl Book {
     /// Get all books in the given documentation model
     pub fn get_books(model: Arc<Model>) -> anyhow::Result<Vec<Book>> {
         info!("Retrieving all books from documentation model");
-        let query = r#"
-            PREFIX dc: <http://purl.org/dc/terms/>
-            PREFIX rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#>
-            SELECT DISTINCT ?graph ?iri ?title ?subtitle ?author WHERE {
-                GRAPH ?graph {
-                    ?book rdf:type <http://example.org/Book> ;
-                          dc:title ?title ;
-                          dc:creator ?author .
-                    OPTIONAL { ?book dc:subtitle ?subtitle }
-                }
-            }
-        "#;
+        let query = format!(
+            r#"
+            SELECT DISTINCT ?iri ?key ?title ?subtitle ?version ?author
+                ?repository ?url ?language
+            WHERE {{
+                ?iri a {book} ;
+                     {title} ?title ;
+                     {creator} ?author .
+                OPTIONAL {{ ?iri {key} ?key }}
+                OPTIONAL {{ ?iri {subtitle} ?subtitle }}
+                OPTIONAL {{ ?iri {version} ?version }}
+                OPTIONAL {{ ?iri {repository} ?repository }}
+                OPTIONAL {{ ?iri {url} ?url }}
+                OPTIONAL {{ ?iri {language} ?language }}
+            }}
+            ORDER BY ?title ?iri ?author
+            "#,
+            book = *OXI_CLASS_GRAPHARCH_BOOK,
+            title = *OXI_DCTERMS_TITLE,
+            creator = *OXI_DCTERMS_CREATOR,
+            key = *OXI_GRAPHARCH_KEY,
+            subtitle = *OXI_GRAPHARCH_SUBTITLE,
+            version = *OXI_GRAPHARCH_VERSION,
+            repository = *OXI_GRAPHARCH_REPOSITORY,
+            url = *OXI_GRAPHARCH_URL,
+            language = *OXI_DCTERMS_LANGUAGE,
+        );
 
-        let results = model.get_store().query(query)?;
+        let results = model.get_store().query(&query)?;
         let mut books = Vec::new();
 
         if let oxigraph::sparql::QueryResults::Solutions(solutions) = results {
             for solution in solutions {
                 let solution = solution?;
+                let element_ref = ElementRef::from_solution(&model, &solution)?;
+                let author =
+                    literal_value(&solution, "author").unwrap_or_default();
+                // A book with several authors comes back as one row per
+                // author
+                if let Some(book) =
+                    books.last_mut().filter(|book: &&mut Book| {
+                        book.element_ref.named_node == element_ref.named_node
+                    })
+                {
+                    if !book.authors.contains(&author) {
+                        book.authors.push(author);
+                    }
+                    continue;
+                }
                 let book = Book {
-                    element_ref: ElementRef::from_solution(&model, &solution)?,
-                    title:       solution.get("title").map(|s| s.to_string()),
-                    subtitle:    solution
-                        .get("subtitle")
-                        .map(|s| s.to_string()),
-                    authors:     vec![
-                        solution.get("author").unwrap().to_string(),
-                    ],
-                    repository:  None,
-                    url:         None,
-                    sections:    Vec::new(),
+                    element_ref,
+                    key: literal_value(&solution, "key"),
+                    title: literal_value(&solution, "title"),
+                    subtitle: literal_value(&solution, "subtitle"),
+                    version: literal_value(&solution, "version"),
+                    authors: vec![author],
+                    repository: literal_value(&solution, "repository"),
+                    url: literal_value(&solution, "url"),
+                    language: literal_value(&solution, "language"),
+                    sections: Vec::new(),
                 };
                 books.push(book);
             }
@@ -233,3 +380,102 @@ impl Book {
         Ok(books)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::model::{BlockContent, Chapter},
+    };
+
+    #[test]
+    fn test_hierarchy_order() -> anyhow::Result<()> {
+        let model = Arc::new(Model::new()?);
+        let book = Book::builder_in_model::<Book>(&model)?
+            .title(Some("Book".to_string()))
+            .author(Some("Author".to_string()))
+            .build()?;
+        let second = Section::builder_in_model::<Section>(&model)?
+            .title(Some("B".to_string()))
+            .book(Some(&book))
+            .order(Some(2))
+            .build()?;
+        Section::builder_in_model::<Section>(&model)?
+            .title(Some("A".to_string()))
+            .book(Some(&book))
+            .order(Some(1))
+            .build()?;
+        let chapter = Chapter::builder_in_model::<Chapter>(&model)?
+            .title(Some("Z".to_string()))
+            .section(Some(&second))
+            .build()?;
+        Chapter::builder_in_model::<Chapter>(&model)?
+            .title(Some("Y".to_string()))
+            .section(Some(&second))
+            .build()?;
+        Chapter::builder_in_model::<Chapter>(&model)?
+            .title(Some("Sub".to_string()))
+            .chapter(Some(&chapter))
+            .build()?;
+        // Sections and chapters without a title are part of the tree too
+        Section::builder_in_model::<Section>(&model)?
+            .book(Some(&book))
+            .order(Some(3))
+            .build()?;
+        Chapter::builder_in_model::<Chapter>(&model)?
+            .chapter(Some(&chapter))
+            .build()?;
+
+        let sections = Book::get_book(model, "Book")?.get_sections()?;
+        let titles: Vec<_> = sections.iter().map(|s| s.title.clone()).collect();
+        assert_eq!(titles, [
+            Some("A".to_string()),
+            Some("B".to_string()),
+            None
+        ]);
+        let chapters = sections[1].get_chapters()?;
+        let titles: Vec<_> =
+            chapters.iter().filter_map(|c| c.title.clone()).collect();
+        assert_eq!(titles, ["Z", "Y"]);
+        assert_eq!(chapters[0].get_chapters()?.len(), 2);
+        assert!(chapters[1].get_chapters()?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate() -> anyhow::Result<()> {
+        let model = Arc::new(Model::new()?);
+        let book = Book::builder_in_model::<Book>(&model)?
+            .title(Some("Book".to_string()))
+            .subtitle(Some("Subtitle".to_string()))
+            .version(Some("1.0".to_string()))
+            .author(Some("Author".to_string()))
+            .repository(Some(
+                "https://github.com/ekgf/grapharch".to_string(),
+            ))
+            .build()?;
+        let section = Section::builder_in_model::<Section>(&model)?
+            .title(Some("Section".to_string()))
+            .description(Some("Description".to_string()))
+            .book(Some(&book))
+            .build()?;
+        Chapter::builder_in_model::<Chapter>(&model)?
+            .title(Some("Chapter".to_string()))
+            .blocks(vec![BlockContent::paragraph("Content")])
+            .section(Some(&section))
+            .build()?;
+        assert!(model.validate()?.is_empty());
+
+        // A book needs an author
+        Book::builder_in_model::<Book>(&model)?
+            .title(Some("Anonymous".to_string()))
+            .build()?;
+        let violations = model.validate()?;
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            violations[0].component,
+            "MinCountConstraintComponent"
+        );
+        Ok(())
+    }
+}
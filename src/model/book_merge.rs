@@ -0,0 +1,423 @@
+use {
+    super::{
+        Model,
+        book::Book,
+        chapter::Chapter,
+        element::{Element, next_order},
+        section::Section,
+    },
+    crate::{
+        config::BookConfig,
+        rdf_const::predicates::{
+            OXI_DCTERMS_CREATOR,
+            OXI_DCTERMS_TITLE,
+            OXI_GRAPHARCH_KEY,
+            OXI_GRAPHARCH_ORDER,
+            OXI_GRAPHARCH_PARENT,
+            OXI_GRAPHARCH_REPOSITORY,
+            OXI_GRAPHARCH_SUBTITLE,
+            OXI_GRAPHARCH_TARGET,
+            OXI_GRAPHARCH_URL,
+            OXI_GRAPHARCH_VERSION,
+        },
+    },
+    anyhow::Result,
+    oxrdf::{Literal, NamedNode, Quad},
+    std::{collections::BTreeMap, sync::Arc},
+    tracing::info,
+};
+
+impl Book {
+    /// Merges the books that have the same key, like the books of an
+    /// ontology that was loaded from two files, and the books that the
+    /// given configured books include into one book per key.
+    ///
+    /// The first book of each group, in the order of the keys in the
+    /// `include` list of its configured book and then by key and
+    /// title, is kept and gets the sections of the others. Sections
+    /// with the same title are merged into one section, and a chapter
+    /// that documents the same resource as a chapter of that section
+    /// (or has the same title if it documents no resource) is left
+    /// out. Books without a key are left as they are, and books in
+    /// different languages are never merged.
+    pub fn merge_books(model: Arc<Model>, books: &[BookConfig]) -> Result<()> {
+        let mut groups: BTreeMap<(String, Option<String>), Vec<Book>> =
+            BTreeMap::new();
+        for book in Self::get_books(model.clone())? {
+            let Some(key) = book.key.clone() else {
+                continue;
+            };
+            let id = BookConfig::find(books, &key)
+                .map(|config| config.id.clone())
+                .unwrap_or(key);
+            groups
+                .entry((id, book.language.clone()))
+                .or_default()
+                .push(book);
+        }
+
+        for ((id, _), mut group) in groups {
+            let config = books.iter().find(|config| config.id == id);
+            if group.len() == 1 && config.is_none() {
+                continue;
+            }
+            let position = |book: &Book| {
+                let key = book.key.as_deref().unwrap_or_default();
+                config
+                    .and_then(|config| {
+                        config.include.iter().position(|k| k == key)
+                    })
+                    .unwrap_or(usize::MAX)
+            };
+            group.sort_by(|a, b| {
+                (position(a), &a.key, &a.title).cmp(&(
+                    position(b),
+                    &b.key,
+                    &b.title,
+                ))
+            });
+            info!("Merging {} books into book {}", group.len(), id);
+
+            let mut group = group.into_iter();
+            let Some(mut book) = group.next() else {
+                continue;
+            };
+            for other in group {
+                book.merge(&other)?;
+            }
+            book.set_literal(&OXI_GRAPHARCH_KEY, Some(&id))?;
+            if let Some(config) = config {
+                if config.title.is_some() {
+                    book.set_literal(
+                        &OXI_DCTERMS_TITLE,
+                        config.title.as_ref(),
+                    )?;
+                }
+                if config.subtitle.is_some() {
+                    book.set_literal(
+                        &OXI_GRAPHARCH_SUBTITLE,
+                        config.subtitle.as_ref(),
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Moves the sections of the given book into this book and removes
+    /// the given book. Authors are added to the authors of this book,
+    /// the other details only fill in the details that this book
+    /// lacks.
+    fn merge(&mut self, other: &Book) -> Result<()> {
+        for author in &other.authors {
+            if !self.authors.contains(author) {
+                self.get_model().insert(&Quad::new(
+                    self.get_named_node().clone(),
+                    OXI_DCTERMS_CREATOR.clone(),
+                    Literal::new_simple_literal(author),
+                    self.get_graph_name().clone(),
+                ))?;
+                self.authors.push(author.clone());
+            }
+        }
+        let details = [
+            (
+                &*OXI_GRAPHARCH_SUBTITLE,
+                self.subtitle.is_none(),
+                &other.subtitle,
+            ),
+            (
+                &*OXI_GRAPHARCH_VERSION,
+                self.version.is_none(),
+                &other.version,
+            ),
+            (
+                &*OXI_GRAPHARCH_REPOSITORY,
+                self.repository.is_none(),
+                &other.repository,
+            ),
+            (
+                &*OXI_GRAPHARCH_URL,
+                self.url.is_none(),
+                &other.url,
+            ),
+        ];
+        for (predicate, missing, value) in details {
+            if missing && value.is_some() {
+                self.set_literal(predicate, value.as_ref())?;
+            }
+        }
+        self.subtitle = self.subtitle.take().or(other.subtitle.clone());
+        self.version = self.version.take().or(other.version.clone());
+        self.repository = self.repository.take().or(other.repository.clone());
+        self.url = self.url.take().or(other.url.clone());
+
+        let mut sections = self.get_sections()?;
+        for section in other.get_sections()? {
+            match sections.iter().find(|s| s.title == section.title) {
+                Some(target) => {
+                    merge_section(target, &section)?;
+                    remove_tree(self.get_model(), section.get_named_node())?;
+                },
+                None => {
+                    move_to(
+                        self.get_model(),
+                        section.get_named_node(),
+                        self.get_named_node(),
+                    )?;
+                    sections.push(section);
+                },
+            }
+        }
+        remove_tree(self.get_model(), other.get_named_node())
+    }
+
+    /// Replaces the value of the given property of the book.
+    fn set_literal(
+        &self,
+        predicate: &NamedNode,
+        value: Option<&String>,
+    ) -> Result<()> {
+        let book = self.get_named_node();
+        let insert = value
+            .map(|value| {
+                format!(
+                    "{book} {predicate} {} .",
+                    Literal::from(value.as_str())
+                )
+            })
+            .unwrap_or_default();
+        self.get_model().update(&format!(
+            r#"
+            DELETE {{ {book} {predicate} ?value }}
+            WHERE {{ OPTIONAL {{ {book} {predicate} ?value }} }} ;
+            INSERT DATA {{ {insert} }}
+            "#,
+        ))
+    }
+}
+
+/// Moves the chapters of the given section into the target section,
+/// leaving out the chapters that the target section already has.
+fn merge_section(target: &Section, section: &Section) -> Result<()> {
+    let model = target.get_model();
+    let mut chapters = Vec::new();
+    for chapter in target.get_chapters()? {
+        chapters.push((chapter_identity(&chapter)?, chapter));
+    }
+    for chapter in section.get_chapters()? {
+        let identity = chapter_identity(&chapter)?;
+        match chapters.iter().find(|(i, _)| *i == identity) {
+            Some((_, twin)) => {
+                // References to the duplicate go to its twin instead
+                model.update(&format!(
+                    r#"
+                    DELETE {{ ?inline {target} {duplicate} }}
+                    INSERT {{ ?inline {target} {twin} }}
+                    WHERE {{ ?inline {target} {duplicate} }}
+                    "#,
+                    target = *OXI_GRAPHARCH_TARGET,
+                    duplicate = chapter.get_named_node(),
+                    twin = twin.get_named_node(),
+                ))?;
+                remove_tree(model, chapter.get_named_node())?;
+            },
+            None => {
+                move_to(
+                    model,
+                    chapter.get_named_node(),
+                    target.get_named_node(),
+                )?;
+                chapters.push((identity, chapter));
+            },
+        }
+    }
+    Ok(())
+}
+
+/// Returns what makes two chapters the same chapter: the resource
+/// that they document or, if they do not document a resource, their
+/// title.
+fn chapter_identity(
+    chapter: &Chapter,
+) -> Result<(Option<String>, Option<String>)> {
+    Ok(
+        match chapter
+            .get_provenance()?
+            .and_then(|provenance| provenance.source)
+        {
+            Some(source) => (Some(source), None),
+            None => (None, chapter.title.clone()),
+        },
+    )
+}
+
+/// Attaches the given element to the given parent, after the existing
+/// children of the parent.
+fn move_to(
+    model: &Model,
+    element: &NamedNode,
+    parent: &NamedNode,
+) -> Result<()> {
+    let order = next_order(model, parent)?;
+    model.update(&format!(
+        r#"
+        DELETE {{ {element} {parent_predicate} ?parent ; {order_predicate} ?order }}
+        INSERT {{ {element} {parent_predicate} {parent} ; {order_predicate} {order} }}
+        WHERE {{
+            OPTIONAL {{ {element} {parent_predicate} ?parent }}
+            OPTIONAL {{ {element} {order_predicate} ?order }}
+        }}
+        "#,
+        parent_predicate = *OXI_GRAPHARCH_PARENT,
+        order_predicate = *OXI_GRAPHARCH_ORDER,
+    ))
+}
+
+/// Removes the given element and everything that belongs to it, like
+/// the chapters of a section or the blocks of a chapter.
+fn remove_tree(model: &Model, element: &NamedNode) -> Result<()> {
+    model.update(&format!(
+        r#"
+        DELETE {{ ?node ?predicate ?object }}
+        WHERE {{
+            ?node {parent}* {element} .
+            ?node ?predicate ?object .
+        }}
+        "#,
+        parent = *OXI_GRAPHARCH_PARENT,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::model::{Buildable, Provenance},
+    };
+
+    fn book(model: &Arc<Model>, key: &str, title: &str) -> Result<Book> {
+        book_in_file(model, key, title, None)
+    }
+
+    fn book_in_file(
+        model: &Arc<Model>,
+        key: &str,
+        title: &str,
+        file: Option<&str>,
+    ) -> Result<Book> {
+        Book::builder_in_model::<Book>(model)?
+            .key(Some(key.to_string()))
+            .title(Some(title.to_string()))
+            .author(Some(title.to_string()))
+            .provenance(file.map(|file| {
+                Provenance {
+                    file: Some(file.to_string()),
+                    ..Provenance::default()
+                }
+            }))
+            .build()
+    }
+
+    fn section(
+        model: &Arc<Model>,
+        book: &Book,
+        title: &str,
+    ) -> Result<Section> {
+        Section::builder_in_model::<Section>(model)?
+            .title(Some(title.to_string()))
+            .book(Some(book))
+            .build()
+    }
+
+    fn chapter(
+        model: &Arc<Model>,
+        section: &Section,
+        source: &str,
+    ) -> Result<()> {
+        Chapter::builder_in_model::<Chapter>(model)?
+            .title(Some(source.to_string()))
+            .provenance(Some(Provenance::of_source(source)))
+            .section(Some(section))
+            .build()?;
+        Ok(())
+    }
+
+    fn titles(model: &Arc<Model>) -> Result<Vec<(String, Vec<String>)>> {
+        let mut titles = Vec::new();
+        for book in Book::get_books(model.clone())? {
+            for section in book.get_sections()? {
+                let chapters = section
+                    .get_chapters()?
+                    .into_iter()
+                    .filter_map(|chapter| chapter.title)
+                    .collect();
+                titles.push((
+                    format!(
+                        "{}/{}",
+                        book.title.clone().unwrap_or_default(),
+                        section.title.unwrap_or_default()
+                    ),
+                    chapters,
+                ));
+            }
+        }
+        Ok(titles)
+    }
+
+    #[test]
+    fn test_merge_books() -> Result<()> {
+        let model = Arc::new(Model::new()?);
+        for file in ["core.ttl", "core-copy.ttl"] {
+            let core = book_in_file(&model, "urn:core", "Core", Some(file))?;
+            let classes = section(&model, &core, "Classes")?;
+            chapter(&model, &classes, "urn:core#A")?;
+            chapter(&model, &classes, "urn:core#B")?;
+        }
+        let extension = book(&model, "urn:extension", "Extension")?;
+        let classes = section(&model, &extension, "Classes")?;
+        chapter(&model, &classes, "urn:extension#C")?;
+        let properties = section(&model, &extension, "Properties")?;
+        chapter(&model, &properties, "urn:extension#p")?;
+        book(&model, "README.md", "Readme")?;
+
+        // The same ontology twice becomes one book
+        Book::merge_books(model.clone(), &[])?;
+        assert_eq!(Book::get_books(model.clone())?.len(), 3);
+        assert_eq!(
+            titles(&model)?[0],
+            ("Core/Classes".to_string(), vec![
+                "urn:core#A".to_string(),
+                "urn:core#B".to_string()
+            ])
+        );
+
+        // The configured book gets both ontologies, in the configured
+        // order, and leaves the other books alone
+        let config = BookConfig {
+            id:       "all".to_string(),
+            title:    Some("All".to_string()),
+            subtitle: None,
+            include:  vec!["urn:extension".to_string(), "urn:core".to_string()],
+        };
+        Book::merge_books(model.clone(), &[config])?;
+        let books = Book::get_books(model.clone())?;
+        assert_eq!(books.len(), 2);
+        assert_eq!(books[0].key.as_deref(), Some("all"));
+        let mut authors = books[0].authors.clone();
+        authors.sort();
+        assert_eq!(authors, ["Core", "Extension"]);
+        assert_eq!(titles(&model)?, [
+            ("All/Classes".to_string(), vec![
+                "urn:extension#C".to_string(),
+                "urn:core#A".to_string(),
+                "urn:core#B".to_string(),
+            ]),
+            ("All/Properties".to_string(), vec![
+                "urn:extension#p".to_string()
+            ]),
+        ]);
+        assert!(model.validate()?.is_empty());
+        Ok(())
+    }
+}
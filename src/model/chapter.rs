@@ -1,14 +1,37 @@
 use {
-    super::{Buildable, Element, element::ElementRef},
+    super::{
+        Buildable,
+        Element,
+        Model,
+        block::{Block, BlockContent},
+        element::{ElementRef, literal_value, localized_literal},
+        glossary::DocumentedTerm,
+        provenance::Provenance,
+        section::Section,
+    },
+    crate::rdf_const::{
+        classes::OXI_CLASS_GRAPHARCH_CHAPTER,
+        predicates::{
+            OXI_DCTERMS_TITLE,
+            OXI_GRAPHARCH_ALTERNATIVE_LABEL,
+            OXI_GRAPHARCH_CURIE,
+            OXI_GRAPHARCH_DEFINITION,
+            OXI_GRAPHARCH_ORDER,
+            OXI_GRAPHARCH_PARENT,
+            OXI_GRAPHARCH_TERM_KIND,
+        },
+    },
     anyhow::Result,
-    oxrdf::Literal,
+    oxrdf::{Literal, NamedNode},
+    std::sync::Arc,
 };
 
 #[derive(Debug, Clone)]
 pub struct Chapter {
     pub(super) element_ref: ElementRef,
     pub title:              Option<String>,
-    pub content:            Option<String>,
+    /// The language tag of the title, if any
+    pub language:           Option<String>,
 }
 
 impl Element for Chapter {
@@ -24,37 +47,95 @@ impl Element for Chapter {
 pub struct ChapterBuilder {
     pub(super) element_ref: ElementRef,
     pub title:              Option<String>,
-    pub content:            Option<String>,
+    /// The language tag of the title, if any
+    language:               Option<String>,
+    /// The resource that the chapter documents and where it was read
+    /// from
+    provenance:             Option<Provenance>,
+    /// The term that the chapter documents, for the glossary and index
+    term:                   Option<DocumentedTerm>,
+    /// The content of the chapter, added as its first blocks
+    blocks:                 Vec<BlockContent>,
+    /// The section or, for a subchapter, the chapter that the chapter
+    /// belongs to
+    parent:                 Option<NamedNode>,
+    order:                  Option<i64>,
 }
 
 impl Buildable<Chapter> for ChapterBuilder {
     fn get_element_ref(&self) -> &ElementRef { &self.element_ref }
 
+    fn get_element_ref_mut(&mut self) -> &mut ElementRef {
+        &mut self.element_ref
+    }
+
     fn new(element_ref: ElementRef) -> Self {
-        Self { element_ref, title: None, content: None }
+        Self {
+            element_ref,
+            title: None,
+            language: None,
+            provenance: None,
+            term: None,
+            blocks: Vec::new(),
+            parent: None,
+            order: None,
+        }
     }
 
     fn build(&mut self) -> Result<Chapter> {
+        // A chapter is identified by its position in its parent and by
+        // the resource that it documents, or by its title if it does not
+        // document a resource
+        let parent = self.parent.clone();
+        let order = match &parent {
+            Some(parent) => Some(self.resolve_order(parent, self.order)?),
+            None => self.order,
+        };
+        let parts = [
+            parent
+                .as_ref()
+                .map(|parent| parent.as_str().to_string())
+                .unwrap_or_default(),
+            order.map(|order| order.to_string()).unwrap_or_default(),
+            self.provenance
+                .as_ref()
+                .and_then(|provenance| provenance.source.clone())
+                .or_else(|| self.title.clone())
+                .unwrap_or_default(),
+        ];
+        self.identify(&OXI_CLASS_GRAPHARCH_CHAPTER, &parts)?;
         let chapter = Chapter {
             element_ref: self.element_ref.clone(),
             title:       self.title.clone(),
-            content:     self.content.clone(),
+            language:    self.language.clone(),
         };
 
-        self.insert_type("http://example.org/Chapter")?;
+        self.insert_type(&OXI_CLASS_GRAPHARCH_CHAPTER)?;
 
         if let Some(title) = &self.title {
             self.insert_object_literal(
-                oxrdf::NamedNode::new("http://purl.org/dc/terms/title")?,
-                Literal::new_simple_literal(title),
+                OXI_DCTERMS_TITLE.clone(),
+                localized_literal(title, self.language.as_deref())?,
             )?;
         }
 
-        if let Some(content) = &self.content {
-            self.insert_object_literal(
-                oxrdf::NamedNode::new("http://example.org/schema/content")?,
-                Literal::new_simple_literal(content),
-            )?;
+        if let Some(provenance) = &self.provenance {
+            self.insert_provenance(provenance)?;
+        }
+
+        if let Some(term) = &self.term {
+            self.insert_term(term)?;
+        }
+
+        if let Some(parent) = &parent {
+            self.insert_parent(parent, order)?;
+        }
+
+        for content in &self.blocks {
+            Block::builder_in_model::<Block>(self.get_model())?
+                .content(content.clone())
+                .chapter(Some(&chapter))
+                .build()?;
         }
 
         Ok(chapter)
@@ -67,8 +148,139 @@ impl ChapterBuilder {
         self
     }
 
-    pub fn content(mut self, content: Option<&str>) -> Self {
-        self.content = content.map(|s| s.to_string());
+    /// Sets the language tag of the title, like `nl` for the Dutch
+    /// label of an OWL class.
+    pub fn language(mut self, language: Option<String>) -> Self {
+        self.language = language;
+        self
+    }
+
+    /// Records where the chapter comes from, see `Provenance`. The
+    /// cross-references to the source IRI of the chapter, like the IRI
+    /// of an OWL class, resolve to the chapter.
+    pub fn provenance(mut self, provenance: Option<Provenance>) -> Self {
+        self.provenance = provenance;
+        self
+    }
+
+    /// Makes the chapter the entry of the given term in the glossary
+    /// and the index of its book, see `Glossary`.
+    pub fn term(mut self, term: Option<DocumentedTerm>) -> Self {
+        self.term = term;
+        self
+    }
+
+    /// Sets the content of the chapter.
+    pub fn blocks(mut self, blocks: Vec<BlockContent>) -> Self {
+        self.blocks = blocks;
+        self
+    }
+
+    /// Adds the chapter to the given section.
+    pub fn section(mut self, section: Option<&Section>) -> Self {
+        self.parent = section.map(|section| section.get_named_node().clone());
+        self
+    }
+
+    /// Adds the chapter as a subchapter to the given chapter.
+    pub fn chapter(mut self, chapter: Option<&Chapter>) -> Self {
+        self.parent = chapter.map(|chapter| chapter.get_named_node().clone());
+        self
+    }
+
+    /// Sets the position of the chapter in its section (or chapter),
+    /// the chapter goes after the existing chapters of its parent if
+    /// not set.
+    pub fn order(mut self, order: Option<i64>) -> Self {
+        self.order = order;
         self
     }
 }
+
+impl ChapterBuilder {
+    fn insert_term(&self, term: &DocumentedTerm) -> Result<()> {
+        self.insert_object_literal(
+            OXI_GRAPHARCH_TERM_KIND.clone(),
+            Literal::new_simple_literal(&term.kind),
+        )?;
+        if let Some(curie) = &term.curie {
+            self.insert_object_literal(
+                OXI_GRAPHARCH_CURIE.clone(),
+                Literal::new_simple_literal(curie),
+            )?;
+        }
+        for label in &term.alternative_labels {
+            self.insert_object_literal(
+                OXI_GRAPHARCH_ALTERNATIVE_LABEL.clone(),
+                Literal::new_simple_literal(label),
+            )?;
+        }
+        if let Some(definition) = &term.definition {
+            self.insert_object_literal(
+                OXI_GRAPHARCH_DEFINITION.clone(),
+                Literal::new_simple_literal(definition),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Chapter {
+    /// Returns the term that the chapter documents, if it is an entry
+    /// of the glossary of its book.
+    pub fn get_term(&self) -> anyhow::Result<Option<DocumentedTerm>> {
+        DocumentedTerm::of_element(self.get_model(), self.get_named_node())
+    }
+
+    /// Returns the blocks of the chapter, in order.
+    pub fn get_blocks(&self) -> anyhow::Result<Vec<Block>> {
+        Block::get_blocks_of(self.get_model(), self.get_element_ref())
+    }
+
+    /// Returns the subchapters of the chapter, in order.
+    pub fn get_chapters(&self) -> anyhow::Result<Vec<Chapter>> {
+        Self::get_chapters_of(self.get_model(), self.get_named_node())
+    }
+
+    /// Returns the chapters that belong to the given section or
+    /// chapter, in order.
+    pub(super) fn get_chapters_of(
+        model: &Arc<Model>,
+        parent: &NamedNode,
+    ) -> anyhow::Result<Vec<Chapter>> {
+        let query = format!(
+            r#"
+            SELECT DISTINCT ?iri ?title (LANG(?title) AS ?language) ?order
+            WHERE {{
+                ?iri a {chapter} ;
+                     {parent_predicate} {parent} .
+                OPTIONAL {{ ?iri {title} ?title }}
+                OPTIONAL {{ ?iri {order} ?order }}
+            }}
+            ORDER BY ?order ?title
+            "#,
+            chapter = *OXI_CLASS_GRAPHARCH_CHAPTER,
+            title = *OXI_DCTERMS_TITLE,
+            parent_predicate = *OXI_GRAPHARCH_PARENT,
+            order = *OXI_GRAPHARCH_ORDER,
+        );
+
+        let results = model.get_store().query(&query)?;
+        let mut chapters = Vec::new();
+
+        if let oxigraph::sparql::QueryResults::Solutions(solutions) = results {
+            for solution in solutions {
+                let solution = solution?;
+                let chapter = Chapter {
+                    element_ref: ElementRef::from_solution(model, &solution)?,
+                    title:       literal_value(&solution, "title"),
+                    language:    literal_value(&solution, "language")
+                        .filter(|language| !language.is_empty()),
+                };
+                chapters.push(chapter);
+            }
+        }
+
+        Ok(chapters)
+    }
+}
@@ -0,0 +1,322 @@
+use {
+    super::{Model, block::ReferenceTarget, element::literal_value},
+    crate::rdf_const::{
+        classes::{
+            OXI_CLASS_GRAPHARCH_BLOCK,
+            OXI_CLASS_GRAPHARCH_BOOK,
+            OXI_CLASS_GRAPHARCH_CHAPTER,
+            OXI_CLASS_GRAPHARCH_CROSS_REFERENCE,
+        },
+        predicates::{
+            OXI_DCTERMS_TITLE,
+            OXI_GRAPHARCH_PARENT,
+            OXI_GRAPHARCH_SOURCE,
+            OXI_GRAPHARCH_TARGET,
+            OXI_GRAPHARCH_TARGET_IRI,
+        },
+    },
+    anyhow::Result,
+    oxigraph::{
+        model::{NamedNode, Term},
+        sparql::QueryResults,
+    },
+    std::collections::{BTreeSet, HashMap},
+};
+
+/// The place in the generated documentation that a cross-reference
+/// points at.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Anchor {
+    /// The identifier of the element in the output, which is valid as
+    /// a Typst label, an HTML `id` and a Markdown heading anchor
+    pub id:    String,
+    pub title: String,
+}
+
+impl Anchor {
+    fn of(element: &NamedNode, title: String) -> Self {
+        Self { id: anchor_id(element), title }
+    }
+}
+
+/// Returns the identifier of the given element in the output, made of
+/// the lowercase letters and digits of its IRI, with a `-` for every
+/// other character.
+pub fn anchor_id(element: &NamedNode) -> String {
+    element
+        .as_str()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+/// A cross-reference that does not resolve to an element of the model.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct UnresolvedReference {
+    pub target:  ReferenceTarget,
+    /// The title of the chapter that contains the cross-reference
+    pub chapter: Option<String>,
+}
+
+impl std::fmt::Display for UnresolvedReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.chapter {
+            Some(chapter) => write!(f, "{} in \"{}\"", self.target, chapter),
+            None => write!(f, "{}", self.target),
+        }
+    }
+}
+
+/// Resolves the cross-references of a model to the chapters that they
+/// point at, either directly or through the source IRI that a chapter
+/// documents (see `ChapterBuilder::source`).
+///
+/// Generators create it once the model is complete and render a
+/// reference that does not resolve as its content only.
+#[derive(Debug, Clone, Default)]
+pub struct CrossReferences {
+    anchors:      HashMap<NamedNode, Anchor>,
+    /// The chapter that documents each source IRI, the first chapter
+    /// (by IRI) if there are several
+    sources:      HashMap<String, NamedNode>,
+    /// The chapter of each book that documents each source IRI, see
+    /// `CrossReferences::in_book`
+    book_sources: HashMap<(NamedNode, String), NamedNode>,
+}
+
+impl CrossReferences {
+    pub fn of_model(model: &Model) -> Result<Self> {
+        let query = format!(
+            r#"
+            SELECT ?iri ?title ?source ?book WHERE {{
+                ?iri a {chapter} ;
+                     {title} ?title .
+                OPTIONAL {{ ?iri {source} ?source }}
+                OPTIONAL {{
+                    ?iri {parent}+ ?book .
+                    ?book a {book} .
+                }}
+            }}
+            ORDER BY DESC(?iri)
+            "#,
+            chapter = *OXI_CLASS_GRAPHARCH_CHAPTER,
+            title = *OXI_DCTERMS_TITLE,
+            source = *OXI_GRAPHARCH_SOURCE,
+            parent = *OXI_GRAPHARCH_PARENT,
+            book = *OXI_CLASS_GRAPHARCH_BOOK,
+        );
+        let mut references = Self::default();
+        if let QueryResults::Solutions(solutions) =
+            model.get_store().query(&query)?
+        {
+            for solution in solutions {
+                let solution = solution?;
+                let Some(Term::NamedNode(iri)) = solution.get("iri") else {
+                    continue;
+                };
+                if let Some(Term::NamedNode(source)) = solution.get("source") {
+                    references
+                        .sources
+                        .insert(source.as_str().to_string(), iri.clone());
+                    if let Some(Term::NamedNode(book)) = solution.get("book") {
+                        references.book_sources.insert(
+                            (book.clone(), source.as_str().to_string()),
+                            iri.clone(),
+                        );
+                    }
+                }
+                let title =
+                    literal_value(&solution, "title").unwrap_or_default();
+                references
+                    .anchors
+                    .insert(iri.clone(), Anchor::of(iri, title));
+            }
+        }
+        Ok(references)
+    }
+
+    /// Returns the cross-references for the content of the given book:
+    /// a source IRI resolves to the chapter of that book that documents
+    /// it if there is one, like the chapter in the same language if
+    /// there is one book per language.
+    pub fn in_book(&self, book: &NamedNode) -> Self {
+        let mut references = self.clone();
+        for ((chapter_book, source), chapter) in &self.book_sources {
+            if chapter_book == book {
+                references.sources.insert(source.clone(), chapter.clone());
+            }
+        }
+        references
+    }
+
+    /// Returns the anchor of the element that the given target
+    /// resolves to, if any.
+    pub fn resolve(&self, target: &ReferenceTarget) -> Option<&Anchor> {
+        let element = match target {
+            ReferenceTarget::Element(element) => element,
+            ReferenceTarget::Source(iri) => self.sources.get(iri)?,
+        };
+        self.anchors.get(element)
+    }
+
+    /// Returns the cross-references of the given model that do not
+    /// resolve, ordered by their target.
+    pub fn unresolved(
+        &self,
+        model: &Model,
+    ) -> Result<Vec<UnresolvedReference>> {
+        let query = format!(
+            r#"
+            SELECT ?target ?targetIri ?title WHERE {{
+                ?reference a {cross_reference} .
+                OPTIONAL {{ ?reference {target} ?target }}
+                OPTIONAL {{ ?reference {target_iri} ?targetIri }}
+                OPTIONAL {{
+                    ?reference {parent}+ ?block .
+                    ?block a {block} ;
+                           {parent} ?chapter .
+                    ?chapter {title} ?title .
+                }}
+            }}
+            "#,
+            cross_reference = *OXI_CLASS_GRAPHARCH_CROSS_REFERENCE,
+            target = *OXI_GRAPHARCH_TARGET,
+            target_iri = *OXI_GRAPHARCH_TARGET_IRI,
+            parent = *OXI_GRAPHARCH_PARENT,
+            block = *OXI_CLASS_GRAPHARCH_BLOCK,
+            title = *OXI_DCTERMS_TITLE,
+        );
+        let mut unresolved = BTreeSet::new();
+        if let QueryResults::Solutions(solutions) =
+            model.get_store().query(&query)?
+        {
+            for solution in solutions {
+                let solution = solution?;
+                let target =
+                    match (solution.get("target"), solution.get("targetIri")) {
+                        (Some(Term::NamedNode(element)), _) => {
+                            ReferenceTarget::Element(element.clone())
+                        },
+                        (_, Some(Term::NamedNode(iri))) => {
+                            ReferenceTarget::Source(iri.as_str().to_string())
+                        },
+                        _ => continue,
+                    };
+                if self.resolve(&target).is_none() {
+                    unresolved.insert(UnresolvedReference {
+                        target,
+                        chapter: literal_value(&solution, "title"),
+                    });
+                }
+            }
+        }
+        Ok(unresolved.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::model::{
+            BlockContent,
+            Book,
+            Buildable,
+            Chapter,
+            Element,
+            Inline,
+            Provenance,
+            Section,
+        },
+        std::sync::Arc,
+    };
+
+    #[test]
+    fn test_resolve() -> anyhow::Result<()> {
+        let model = Arc::new(Model::new()?);
+        let vehicle = Chapter::builder_in_model::<Chapter>(&model)?
+            .title(Some("Vehicle".to_string()))
+            .provenance(Some(Provenance::of_source(
+                "http://example.com/Vehicle",
+            )))
+            .build()?;
+        Chapter::builder_in_model::<Chapter>(&model)?
+            .title(Some("Car".to_string()))
+            .blocks(vec![BlockContent::Paragraph(vec![
+                Inline::reference("http://example.com/Vehicle", "ex:Vehicle"),
+                Inline::reference("http://example.com/Thing", "ex:Thing"),
+            ])])
+            .build()?;
+
+        let references = CrossReferences::of_model(&model)?;
+        let anchor = references
+            .resolve(&ReferenceTarget::Source(
+                "http://example.com/Vehicle".to_string(),
+            ))
+            .unwrap();
+        assert_eq!(anchor.title, "Vehicle");
+        assert_eq!(anchor.id, anchor_id(vehicle.get_named_node()));
+        assert_eq!(
+            references.resolve(&ReferenceTarget::Element(
+                vehicle.get_named_node().clone()
+            )),
+            Some(anchor)
+        );
+        assert_eq!(references.unresolved(&model)?, vec![
+            UnresolvedReference {
+                target:  ReferenceTarget::Source(
+                    "http://example.com/Thing".to_string(),
+                ),
+                chapter: Some("Car".to_string()),
+            }
+        ]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_in_book() -> anyhow::Result<()> {
+        let model = Arc::new(Model::new()?);
+        let mut chapters = Vec::new();
+        for language in ["en", "nl"] {
+            let book = Book::builder_in_model::<Book>(&model)?
+                .title(Some("Vehicles".to_string()))
+                .author(Some("Author".to_string()))
+                .language(Some(language.to_string()))
+                .build()?;
+            let section = Section::builder_in_model::<Section>(&model)?
+                .title(Some("Classes".to_string()))
+                .book(Some(&book))
+                .build()?;
+            let chapter = Chapter::builder_in_model::<Chapter>(&model)?
+                .title(Some(format!("Car ({})", language)))
+                .language(Some(language.to_string()))
+                .provenance(Some(Provenance::of_source(
+                    "http://example.com/Car",
+                )))
+                .section(Some(&section))
+                .build()?;
+            chapters.push((book, chapter));
+        }
+
+        let references = CrossReferences::of_model(&model)?;
+        let target =
+            ReferenceTarget::Source("http://example.com/Car".to_string());
+        for (book, chapter) in &chapters {
+            assert_eq!(
+                references.in_book(book.get_named_node()).resolve(&target),
+                Some(&Anchor::of(
+                    chapter.get_named_node(),
+                    chapter.title.clone().unwrap_or_default()
+                ))
+            );
+        }
+        assert!(model.validate()?.is_empty());
+        Ok(())
+    }
+}
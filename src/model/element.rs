@@ -1,12 +1,34 @@
 use {
-    crate::model::Model,
+    crate::{
+        model::{Model, Provenance},
+        rdf_const::{
+            data_types::OXI_RDF_XSD_INTEGER,
+            predicates::{
+                OXI_GRAPHARCH_ORDER,
+                OXI_GRAPHARCH_PARENT,
+                OXI_GRAPHARCH_SOURCE,
+                OXI_GRAPHARCH_SOURCE_FILE,
+                OXI_GRAPHARCH_SOURCE_GRAPH,
+                OXI_GRAPHARCH_SOURCE_LINE,
+                OXI_GRAPHARCH_SOURCE_URL,
+                OXI_RDF_TYPE,
+            },
+        },
+    },
     anyhow::Result,
-    oxigraph::{model::NamedNode, sparql::QuerySolution},
+    oxigraph::{
+        model::{NamedNode, Term},
+        sparql::{QueryResults, QuerySolution},
+    },
     oxrdf::{GraphName, Literal, Quad},
+    sha2::{Digest, Sha256},
     std::sync::Arc,
-    uuid::Uuid,
 };
 
+/// The IRI of an element whose builder has not built it yet, the
+/// element gets its IRI when it is built, see `Buildable::identify`.
+const UNNAMED_ELEMENT: &str = "urn:grapharch:unnamed";
+
 /// A reference to an element in the model.
 ///
 /// This is used to reference an element in the model without
@@ -30,26 +52,127 @@ impl ElementRef {
     pub(super) fn get_model(&self) -> &Arc<Model> { &self.model }
 
     /// Create an ElementRef from a QuerySolution assuming that the solution
-    /// contains an "iri" and a "graph" variable.
+    /// contains an "iri" and, unless the element is in the default
+    /// graph, a "graph" variable.
     pub(super) fn from_solution(
         model: &Arc<Model>,
         solution: &QuerySolution,
     ) -> Result<Self> {
-        let iri = solution
-            .get("iri")
-            .ok_or_else(|| anyhow::anyhow!("Missing 'iri' in solution"))?
-            .to_string();
+        let named_node = match solution.get("iri") {
+            Some(Term::NamedNode(iri)) => iri.clone(),
+            _ => return Err(anyhow::anyhow!("Missing 'iri' in solution")),
+        };
+        let graph_name = match solution.get("graph") {
+            Some(Term::NamedNode(graph)) => GraphName::NamedNode(graph.clone()),
+            _ => GraphName::DefaultGraph,
+        };
 
-        let graph = solution
-            .get("graph")
-            .ok_or_else(|| anyhow::anyhow!("Missing 'graph' in solution"))?
-            .to_string();
+        Ok(Self { named_node, graph_name, model: model.clone() })
+    }
+}
 
-        let named_node = NamedNode::new(iri)?;
-        let graph_name = GraphName::NamedNode(NamedNode::new(graph)?);
+/// Returns the value of the given literal variable of the given
+/// solution, without the quotes and datatype that `to_string` would
+/// add.
+pub(super) fn literal_value(
+    solution: &QuerySolution,
+    variable: &str,
+) -> Option<String> {
+    match solution.get(variable) {
+        Some(Term::Literal(literal)) => Some(literal.value().to_string()),
+        Some(term) => Some(term.to_string()),
+        None => None,
+    }
+}
 
-        Ok(Self { named_node, graph_name, model: model.clone() })
+/// Returns a literal with the given language tag, or a plain string
+/// literal if there is no language tag.
+pub(super) fn localized_literal(
+    value: &str,
+    language: Option<&str>,
+) -> Result<Literal> {
+    Ok(match language {
+        Some(language) => {
+            Literal::new_language_tagged_literal(value, language)?
+        },
+        None => Literal::new_simple_literal(value),
+    })
+}
+
+/// Returns the IRI of an element of the given class, derived from the
+/// given parts that identify it, like the IRI of its parent, its
+/// position in its parent and the IRI of the resource that it
+/// documents, so that the same input always gives the same model, in
+/// whatever order the elements are added. Fails if an element with the
+/// same parts is already in the model.
+///
+/// The IRI looks like `urn:grapharch:chapter:<hash>`.
+pub(super) fn derive_named_node(
+    model: &Model,
+    class: &NamedNode,
+    parts: &[String],
+) -> Result<NamedNode> {
+    let role = class
+        .as_str()
+        .rsplit(['#', '/'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+    let mut hasher = Sha256::new();
+    hasher.update(class.as_str());
+    for part in parts {
+        hasher.update([0]);
+        hasher.update(part);
+    }
+    let hash = format!("{:x}", hasher.finalize());
+    let node =
+        NamedNode::new(format!("urn:grapharch:{}:{}", role, &hash[..32]))?;
+    let taken = model
+        .get_store()
+        .quads_for_pattern(Some(node.as_ref().into()), None, None, None)
+        .next()
+        .is_some();
+    if taken {
+        return Err(anyhow::anyhow!(
+            "There is already a {} identified by {:?} in the model: {}",
+            role,
+            parts,
+            node
+        ));
+    }
+    Ok(node)
+}
+
+/// Returns the position for a new child of the given parent element,
+/// after its existing children.
+pub(super) fn next_order(model: &Model, parent: &NamedNode) -> Result<i64> {
+    let query = format!(
+        r#"
+        SELECT (MAX(?order) AS ?max) (COUNT(?child) AS ?count) WHERE {{
+            ?child {parent_predicate} {parent} .
+            OPTIONAL {{ ?child {order_predicate} ?order }}
+        }}
+        "#,
+        parent_predicate = *OXI_GRAPHARCH_PARENT,
+        order_predicate = *OXI_GRAPHARCH_ORDER,
+    );
+    if let QueryResults::Solutions(mut solutions) =
+        model.get_store().query(&query)?
+    {
+        if let Some(solution) = solutions.next() {
+            let solution = solution?;
+            let value = |variable: &str| {
+                literal_value(&solution, variable)
+                    .and_then(|value| value.parse::<i64>().ok())
+            };
+            return Ok(match (value("max"), value("count")) {
+                (Some(max), Some(count)) => (max + 1).max(count),
+                (None, Some(count)) => count,
+                _ => 0,
+            });
+        }
     }
+    Ok(0)
 }
 
 pub trait Buildable<T> {
@@ -58,6 +181,8 @@ pub trait Buildable<T> {
 
     fn get_element_ref(&self) -> &ElementRef;
 
+    fn get_element_ref_mut(&mut self) -> &mut ElementRef;
+
     fn get_model(&self) -> &Arc<Model> { self.get_element_ref().get_model() }
 
     fn get_named_node(&self) -> &NamedNode {
@@ -71,15 +196,41 @@ pub trait Buildable<T> {
     /// Build the element and add it to the model.
     fn build(&mut self) -> Result<T>;
 
+    /// Names the element after what identifies it, see
+    /// `derive_named_node`, unless it was given an IRI with
+    /// `Element::builder_for_node_in_model`. Builders call this before
+    /// they add the element to the model.
+    fn identify(&mut self, class: &NamedNode, parts: &[String]) -> Result<()> {
+        if self.get_named_node().as_str() == UNNAMED_ELEMENT {
+            let node = derive_named_node(self.get_model(), class, parts)?;
+            self.get_element_ref_mut().named_node = node;
+        }
+        Ok(())
+    }
+
+    /// Returns the position of the element in its parent: the given
+    /// order or otherwise the position after the existing children of
+    /// the parent.
+    fn resolve_order(
+        &self,
+        parent: &NamedNode,
+        order: Option<i64>,
+    ) -> Result<i64> {
+        match order {
+            Some(order) => Ok(order),
+            None => next_order(self.get_model(), parent),
+        }
+    }
+
     fn insert(&self, quad: &Quad) -> Result<()> {
         self.get_model().insert(quad)
     }
 
-    fn insert_type(&self, iri: &str) -> Result<()> {
+    fn insert_type(&self, class: &NamedNode) -> Result<()> {
         let quad = Quad::new(
             self.get_named_node().clone(),
-            NamedNode::new("http://www.w3.org/1999/02/22-rdf-syntax-ns#type")?,
-            NamedNode::new(iri)?,
+            OXI_RDF_TYPE.clone(),
+            class.clone(),
             self.get_graph_name().clone(),
         );
         self.insert(&quad)
@@ -98,6 +249,75 @@ pub trait Buildable<T> {
         );
         self.insert(&quad)
     }
+
+    /// Records where the element comes from, see `Provenance`.
+    fn insert_provenance(&self, provenance: &Provenance) -> Result<()> {
+        if let Some(source) = &provenance.source {
+            self.insert_object(
+                OXI_GRAPHARCH_SOURCE.clone(),
+                NamedNode::new(source)?.into(),
+            )?;
+        }
+        if let Some(graph) = &provenance.graph {
+            self.insert_object(
+                OXI_GRAPHARCH_SOURCE_GRAPH.clone(),
+                graph.clone().into(),
+            )?;
+        }
+        if let Some(file) = &provenance.file {
+            self.insert_object_literal(
+                OXI_GRAPHARCH_SOURCE_FILE.clone(),
+                Literal::new_simple_literal(file),
+            )?;
+        }
+        if let Some(line) = provenance.line {
+            self.insert_object_literal(
+                OXI_GRAPHARCH_SOURCE_LINE.clone(),
+                Literal::new_typed_literal(
+                    line.to_string(),
+                    OXI_RDF_XSD_INTEGER.clone(),
+                ),
+            )?;
+        }
+        if let Some(url) = &provenance.url {
+            self.insert_object_literal(
+                OXI_GRAPHARCH_SOURCE_URL.clone(),
+                Literal::new_simple_literal(url),
+            )?;
+        }
+        Ok(())
+    }
+
+    fn insert_object(&self, predicate: NamedNode, object: Term) -> Result<()> {
+        let quad = Quad::new(
+            self.get_named_node().clone(),
+            predicate,
+            object,
+            self.get_graph_name().clone(),
+        );
+        self.insert(&quad)
+    }
+
+    /// Attaches the element to the given parent element at the given
+    /// position, or otherwise after the existing children of the
+    /// parent.
+    fn insert_parent(
+        &self,
+        parent: &NamedNode,
+        order: Option<i64>,
+    ) -> Result<()> {
+        let order = self.resolve_order(parent, order)?;
+        self.insert(&Quad::new(
+            self.get_named_node().clone(),
+            OXI_GRAPHARCH_PARENT.clone(),
+            parent.clone(),
+            self.get_graph_name().clone(),
+        ))?;
+        self.insert_object_literal(
+            OXI_GRAPHARCH_ORDER.clone(),
+            Literal::from(order),
+        )
+    }
 }
 
 pub trait Element: Sized {
@@ -117,6 +337,33 @@ pub trait Element: Sized {
 
     fn builder(element_ref: ElementRef) -> anyhow::Result<Self::Builder>;
 
+    /// Returns where the element comes from, if that was recorded.
+    fn get_provenance(&self) -> Result<Option<Provenance>> {
+        Provenance::of_element(self.get_model(), self.get_named_node())
+    }
+
+    /// Returns the position of the element in its parent, if it has a
+    /// parent.
+    fn get_order(&self) -> Result<Option<i64>> {
+        let order = self
+            .get_model()
+            .get_store()
+            .quads_for_pattern(
+                Some(self.get_named_node().as_ref().into()),
+                Some(OXI_GRAPHARCH_ORDER.as_ref()),
+                None,
+                None,
+            )
+            .next()
+            .transpose()?;
+        Ok(order.and_then(|quad| {
+            match quad.object {
+                Term::Literal(literal) => literal.value().parse().ok(),
+                _ => None,
+            }
+        }))
+    }
+
     fn builder_for_node_in_model(
         named_node: NamedNode,
         model: &Arc<Model>,
@@ -128,20 +375,134 @@ pub trait Element: Sized {
         })
     }
 
+    /// Returns a builder for a new element in the given model, which
+    /// gets its IRI when it is built, see `Buildable::identify`.
     fn builder_in_model<E: Element>(
         model: &Arc<Model>,
     ) -> anyhow::Result<E::Builder> {
-        let named_node = Self::new_named_node()?;
         E::builder(ElementRef {
-            named_node,
+            named_node: NamedNode::new_unchecked(UNNAMED_ELEMENT),
             graph_name: GraphName::DefaultGraph,
-            model: model.clone(),
+            model:      model.clone(),
         })
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            model::{BlockContent, Book, Chapter, Section},
+            rdf_const::classes::OXI_CLASS_GRAPHARCH_CHAPTER,
+        },
+    };
+
+    /// Returns the statements of a small model, sorted.
+    fn statements() -> Result<Vec<String>> {
+        let model = Arc::new(Model::new()?);
+        let book = Book::builder_in_model::<Book>(&model)?
+            .key(Some("http://example.com/onto".to_string()))
+            .title(Some("Book".to_string()))
+            .author(Some("Author".to_string()))
+            .build()?;
+        let section = Section::builder_in_model::<Section>(&model)?
+            .title(Some("Section".to_string()))
+            .book(Some(&book))
+            .build()?;
+        // Two chapters with the same title still get their own IRI
+        for _ in 0..2 {
+            Chapter::builder_in_model::<Chapter>(&model)?
+                .title(Some("Chapter".to_string()))
+                .blocks(vec![BlockContent::paragraph("Content")])
+                .section(Some(&section))
+                .build()?;
+        }
+        let mut statements = model
+            .get_store()
+            .iter()
+            .map(|quad| Ok(quad?.to_string()))
+            .collect::<Result<Vec<_>>>()?;
+        statements.sort();
+        Ok(statements)
+    }
+
+    #[test]
+    fn test_derived_named_nodes() -> Result<()> {
+        let statements = statements()?;
+        assert_eq!(statements, self::statements()?);
+        let chapter = format!("{}", *OXI_CLASS_GRAPHARCH_CHAPTER);
+        let chapters =
+            statements.iter().filter(|s| s.ends_with(&chapter)).count();
+        assert_eq!(chapters, 2);
+        assert!(statements.iter().all(|s| !s.contains("urn:uuid:")));
+        assert!(statements[0].starts_with("<urn:grapharch:"));
+        Ok(())
+    }
+
+    /// Returns the statements of a model with two books that each have
+    /// two chapters with the same title, added book by book or the
+    /// other way around, sorted.
+    fn statements_in_order(reversed: bool) -> Result<Vec<String>> {
+        let model = Arc::new(Model::new()?);
+        let mut keys = vec!["urn:a", "urn:b"];
+        if reversed {
+            keys.reverse();
+        }
+        let mut sections = Vec::new();
+        for key in keys {
+            let book = Book::builder_in_model::<Book>(&model)?
+                .key(Some(key.to_string()))
+                .title(Some("Book".to_string()))
+                .author(Some("Author".to_string()))
+                .build()?;
+            let section = Section::builder_in_model::<Section>(&model)?
+                .title(Some("Section".to_string()))
+                .book(Some(&book))
+                .build()?;
+            sections.push(section);
+        }
+        let mut orders = vec![0, 1];
+        if reversed {
+            sections.reverse();
+            orders.reverse();
+        }
+        for section in &sections {
+            for order in &orders {
+                Chapter::builder_in_model::<Chapter>(&model)?
+                    .title(Some("Chapter".to_string()))
+                    .section(Some(section))
+                    .order(Some(*order))
+                    .build()?;
+            }
+        }
+        let mut statements = model
+            .get_store()
+            .iter()
+            .map(|quad| Ok(quad?.to_string()))
+            .collect::<Result<Vec<_>>>()?;
+        statements.sort();
+        Ok(statements)
+    }
+
+    #[test]
+    fn test_derived_named_nodes_in_any_order() -> Result<()> {
+        assert_eq!(
+            statements_in_order(false)?,
+            statements_in_order(true)?
+        );
 
-    fn new_named_node() -> Result<NamedNode> {
-        let uuid = Uuid::new_v4();
-        let uri = format!("urn:uuid:{}", uuid);
-        Ok(NamedNode::new(uri)?)
+        // An element with the same identity as an element in the model
+        // is an error rather than getting another IRI
+        let model = Arc::new(Model::new()?);
+        for attempt in 0..2 {
+            let book = Book::builder_in_model::<Book>(&model)?
+                .key(Some("urn:a".to_string()))
+                .title(Some("Book".to_string()))
+                .author(Some("Author".to_string()))
+                .build();
+            assert_eq!(book.is_ok(), attempt == 0);
+        }
+        Ok(())
     }
 }
@@ -0,0 +1,326 @@
+use {
+    super::{
+        Model,
+        book::Book,
+        element::{Element, literal_value},
+    },
+    crate::rdf_const::{
+        classes::OXI_CLASS_GRAPHARCH_CHAPTER,
+        predicates::{
+            OXI_DCTERMS_TITLE,
+            OXI_GRAPHARCH_ALTERNATIVE_LABEL,
+            OXI_GRAPHARCH_CURIE,
+            OXI_GRAPHARCH_DEFINITION,
+            OXI_GRAPHARCH_PARENT,
+            OXI_GRAPHARCH_TERM_KIND,
+        },
+    },
+    anyhow::Result,
+    oxigraph::{
+        model::{NamedNode, Term},
+        sparql::QueryResults,
+    },
+    serde::{Deserialize, Serialize},
+};
+
+/// The term that a chapter documents, like an OWL class or a SKOS
+/// concept, which makes the chapter an entry of the glossary and the
+/// index of its book, see `Glossary`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentedTerm {
+    /// The kind of term, like `Class`, `Object Property` or `Concept`
+    pub kind:               String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub curie:              Option<String>,
+    /// The other labels of the term, like its `skos:altLabel` values,
+    /// which the index lists next to the title of the chapter
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub alternative_labels: Vec<String>,
+    /// The definition of the term, as shown in the glossary
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub definition:         Option<String>,
+}
+
+impl DocumentedTerm {
+    pub fn new(kind: impl Into<String>) -> Self {
+        Self { kind: kind.into(), ..Self::default() }
+    }
+
+    /// Returns the term that the given chapter documents, if any.
+    pub(super) fn of_element(
+        model: &Model,
+        element: &NamedNode,
+    ) -> Result<Option<Self>> {
+        let query = format!(
+            r#"
+            SELECT ?kind ?curie ?definition ?alternative WHERE {{
+                {element} {kind} ?kind .
+                OPTIONAL {{ {element} {curie} ?curie }}
+                OPTIONAL {{ {element} {definition} ?definition }}
+                OPTIONAL {{ {element} {alternative} ?alternative }}
+            }}
+            ORDER BY ?alternative
+            "#,
+            kind = *OXI_GRAPHARCH_TERM_KIND,
+            curie = *OXI_GRAPHARCH_CURIE,
+            definition = *OXI_GRAPHARCH_DEFINITION,
+            alternative = *OXI_GRAPHARCH_ALTERNATIVE_LABEL,
+        );
+        let mut term: Option<Self> = None;
+        if let QueryResults::Solutions(solutions) =
+            model.get_store().query(&query)?
+        {
+            for solution in solutions {
+                let solution = solution?;
+                let term = term.get_or_insert_with(|| {
+                    Self {
+                        kind:               literal_value(&solution, "kind")
+                            .unwrap_or_default(),
+                        curie:              literal_value(&solution, "curie"),
+                        alternative_labels: Vec::new(),
+                        definition:         literal_value(
+                            &solution,
+                            "definition",
+                        ),
+                    }
+                });
+                if let Some(label) = literal_value(&solution, "alternative") {
+                    term.alternative_labels.push(label);
+                }
+            }
+        }
+        Ok(term)
+    }
+}
+
+/// An entry of the glossary of a book: a documented term and the title
+/// of the chapter that documents it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlossaryEntry {
+    /// The chapter that documents the term, which links and page
+    /// numbers point to, see `anchor_id`
+    pub chapter: NamedNode,
+    pub label:   String,
+    pub term:    DocumentedTerm,
+}
+
+/// An entry of the alphabetical index of a book.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexEntry {
+    /// The text to look up: the label, an alternative label or the
+    /// CURIE of a term
+    pub text:    String,
+    /// The label of the term if the text is one of its alternative
+    /// labels or its CURIE
+    pub see:     Option<String>,
+    /// The chapter that documents the term
+    pub chapter: NamedNode,
+}
+
+impl IndexEntry {
+    /// Returns the letter that the entry is listed under: the first
+    /// letter of its text in upper case, or `#` if the text does not
+    /// start with a letter.
+    pub fn letter(&self) -> char {
+        sort_key(&self.text)
+            .chars()
+            .next()
+            .filter(|c| c.is_alphabetic())
+            .map(|c| c.to_uppercase().next().unwrap_or(c))
+            .unwrap_or('#')
+    }
+}
+
+/// The back matter of a book: a glossary with the terms that its
+/// chapters document, like the classes, properties and individuals of
+/// an ontology or the concepts of a vocabulary, and an alphabetical
+/// index of their labels, alternative labels and CURIEs.
+#[derive(Debug, Clone, Default)]
+pub struct Glossary {
+    /// The entries in alphabetical order of their labels
+    pub entries: Vec<GlossaryEntry>,
+}
+
+impl Glossary {
+    /// Returns the glossary of the terms that the chapters of the
+    /// given book, including its subchapters, document.
+    pub fn of_book(book: &Book) -> Result<Self> {
+        let query = format!(
+            r#"
+            SELECT ?iri ?title WHERE {{
+                ?iri a {chapter} ;
+                     {title} ?title ;
+                     {kind} ?kind ;
+                     {parent}+ {book} .
+            }}
+            "#,
+            chapter = *OXI_CLASS_GRAPHARCH_CHAPTER,
+            title = *OXI_DCTERMS_TITLE,
+            kind = *OXI_GRAPHARCH_TERM_KIND,
+            parent = *OXI_GRAPHARCH_PARENT,
+            book = book.get_named_node(),
+        );
+        let model = book.get_model();
+        let mut entries = Vec::new();
+        if let QueryResults::Solutions(solutions) =
+            model.get_store().query(&query)?
+        {
+            for solution in solutions {
+                let solution = solution?;
+                let Some(Term::NamedNode(chapter)) = solution.get("iri") else {
+                    continue;
+                };
+                if let Some(term) = DocumentedTerm::of_element(model, chapter)?
+                {
+                    entries.push(GlossaryEntry {
+                        chapter: chapter.clone(),
+                        label: literal_value(&solution, "title")
+                            .unwrap_or_default(),
+                        term,
+                    });
+                }
+            }
+        }
+        entries.sort_by(|a, b| {
+            (sort_key(&a.label), &a.label, a.chapter.as_str()).cmp(&(
+                sort_key(&b.label),
+                &b.label,
+                b.chapter.as_str(),
+            ))
+        });
+        Ok(Self { entries })
+    }
+
+    pub fn is_empty(&self) -> bool { self.entries.is_empty() }
+
+    /// Returns the alphabetical index: an entry for the label of each
+    /// term and one for each of its alternative labels and its CURIE
+    /// that refers to that label.
+    pub fn index(&self) -> Vec<IndexEntry> {
+        let mut index: Vec<IndexEntry> = Vec::new();
+        for entry in &self.entries {
+            let others = entry
+                .term
+                .alternative_labels
+                .iter()
+                .chain(&entry.term.curie)
+                .map(|text| (text, Some(entry.label.clone())));
+            for (text, see) in
+                std::iter::once((&entry.label, None)).chain(others)
+            {
+                let index_entry = IndexEntry {
+                    text:    text.clone(),
+                    see:     see.filter(|label| label != text),
+                    chapter: entry.chapter.clone(),
+                };
+                if !index.iter().any(|e| {
+                    e.text == index_entry.text &&
+                        e.chapter == index_entry.chapter
+                }) {
+                    index.push(index_entry);
+                }
+            }
+        }
+        index.sort_by(|a, b| {
+            (sort_key(&a.text), &a.text, &a.see).cmp(&(
+                sort_key(&b.text),
+                &b.text,
+                &b.see,
+            ))
+        });
+        index
+    }
+}
+
+impl Book {
+    /// Returns the glossary and index of the book, see `Glossary`.
+    pub fn get_glossary(&self) -> Result<Glossary> { Glossary::of_book(self) }
+}
+
+/// Returns the text to sort by: in lower case and without leading
+/// punctuation, like `concept` for `:Concept`.
+fn sort_key(text: &str) -> String {
+    text.trim_start_matches(|c: char| !c.is_alphanumeric())
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::model::{Buildable, Chapter, Section},
+        std::sync::Arc,
+    };
+
+    #[test]
+    fn test_glossary() -> Result<()> {
+        let model = Arc::new(Model::new()?);
+        let book = Book::builder_in_model::<Book>(&model)?
+            .title(Some("Vehicles".to_string()))
+            .author(Some("Author".to_string()))
+            .build()?;
+        let section = Section::builder_in_model::<Section>(&model)?
+            .title(Some("Classes".to_string()))
+            .book(Some(&book))
+            .build()?;
+        let vehicle = Chapter::builder_in_model::<Chapter>(&model)?
+            .title(Some("vehicle".to_string()))
+            .term(Some(DocumentedTerm {
+                curie: Some("ex:Vehicle".to_string()),
+                definition: Some("A means of transport.".to_string()),
+                ..DocumentedTerm::new("Class")
+            }))
+            .section(Some(&section))
+            .build()?;
+        Chapter::builder_in_model::<Chapter>(&model)?
+            .title(Some("Car".to_string()))
+            .term(Some(DocumentedTerm {
+                alternative_labels: vec!["Automobile".to_string()],
+                ..DocumentedTerm::new("Class")
+            }))
+            .chapter(Some(&vehicle))
+            .build()?;
+        Chapter::builder_in_model::<Chapter>(&model)?
+            .title(Some("About this ontology".to_string()))
+            .section(Some(&section))
+            .build()?;
+
+        let glossary = book.get_glossary()?;
+        let labels: Vec<&str> =
+            glossary.entries.iter().map(|e| e.label.as_str()).collect();
+        assert_eq!(labels, ["Car", "vehicle"]);
+        assert_eq!(
+            glossary.entries[1].term.definition.as_deref(),
+            Some("A means of transport.")
+        );
+
+        let index: Vec<(String, Option<String>, char)> = glossary
+            .index()
+            .into_iter()
+            .map(|entry| {
+                (
+                    entry.text.clone(),
+                    entry.see.clone(),
+                    entry.letter(),
+                )
+            })
+            .collect();
+        assert_eq!(index, [
+            (
+                "Automobile".to_string(),
+                Some("Car".to_string()),
+                'A'
+            ),
+            ("Car".to_string(), None, 'C'),
+            (
+                "ex:Vehicle".to_string(),
+                Some("vehicle".to_string()),
+                'E'
+            ),
+            ("vehicle".to_string(), None, 'V'),
+        ]);
+        assert!(model.validate()?.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,510 @@
+use {
+    super::{
+        Block,
+        BlockContent,
+        Book,
+        Buildable,
+        Chapter,
+        DocumentedTerm,
+        Element,
+        Model,
+        Provenance,
+        Section,
+    },
+    anyhow::Result,
+    oxrdf::NamedNode,
+    serde::{Deserialize, Serialize},
+    std::sync::Arc,
+};
+
+/// The JSON Schema of the JSON form of the documentation model, see
+/// `ModelDocument`.
+pub const MODEL_JSON_SCHEMA: &str = include_str!("grapharch-model.schema.json");
+
+/// The `$id` of `MODEL_JSON_SCHEMA`, which changes with its major
+/// version.
+pub const MODEL_JSON_SCHEMA_ID: &str =
+    "https://ekgf.org/ontology/GraphArch/model/1/schema.json";
+
+/// The version of the JSON form of the documentation model. Documents
+/// with the same major version can be read, a new minor version only
+/// adds optional fields, which older readers ignore.
+pub const MODEL_JSON_VERSION: &str = "1.1";
+
+/// The documentation model as JSON, for tools that want the books of
+/// the model without going through SPARQL, see `MODEL_JSON_SCHEMA`.
+///
+/// Every element keeps its IRI, so that the cross-references to
+/// elements (see `ReferenceTarget::Element`) still resolve after
+/// `ModelDocument::into_model`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelDocument {
+    #[serde(
+        rename = "$schema",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub schema:  Option<String>,
+    /// The version of the JSON form, see `MODEL_JSON_VERSION`
+    pub version: String,
+    pub books:   Vec<BookDocument>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BookDocument {
+    pub iri:        String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key:        Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title:      Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtitle:   Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version:    Option<String>,
+    #[serde(default)]
+    pub authors:    Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url:        Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language:   Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<Provenance>,
+    #[serde(default)]
+    pub sections:   Vec<SectionDocument>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SectionDocument {
+    pub iri:         String,
+    /// The position of the section in its book
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order:       Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title:       Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provenance:  Option<Provenance>,
+    #[serde(default)]
+    pub chapters:    Vec<ChapterDocument>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChapterDocument {
+    pub iri:        String,
+    /// The position of the chapter among the chapters and blocks of
+    /// its parent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order:      Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title:      Option<String>,
+    /// The language tag of the title
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language:   Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<Provenance>,
+    /// The term that the chapter documents, since version 1.1
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub term:       Option<DocumentedTerm>,
+    #[serde(default)]
+    pub blocks:     Vec<BlockDocument>,
+    /// The subchapters of the chapter
+    #[serde(default)]
+    pub chapters:   Vec<ChapterDocument>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockDocument {
+    pub iri:        String,
+    /// The position of the block among the chapters and blocks of its
+    /// chapter
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order:      Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<Provenance>,
+    pub content:    BlockContent,
+}
+
+impl ModelDocument {
+    /// Returns the books of the given model, with their sections,
+    /// chapters and blocks.
+    pub fn of_model(model: &Arc<Model>) -> Result<Self> {
+        let mut books = Vec::new();
+        for book in Book::get_books(model.clone())? {
+            let mut sections = Vec::new();
+            for section in book.get_sections()? {
+                sections.push(SectionDocument {
+                    iri:         section.get_named_node().as_str().to_string(),
+                    order:       section.get_order()?,
+                    title:       section.title.clone(),
+                    description: section.description.clone(),
+                    provenance:  section.get_provenance()?,
+                    chapters:    chapter_documents(&section.get_chapters()?)?,
+                });
+            }
+            books.push(BookDocument {
+                iri: book.get_named_node().as_str().to_string(),
+                key: book.key.clone(),
+                title: book.title.clone(),
+                subtitle: book.subtitle.clone(),
+                version: book.version.clone(),
+                authors: book.authors.clone(),
+                repository: book.repository.clone(),
+                url: book.url.clone(),
+                language: book.language.clone(),
+                provenance: book.get_provenance()?,
+                sections,
+            });
+        }
+        Ok(Self {
+            schema: Some(MODEL_JSON_SCHEMA_ID.to_string()),
+            version: MODEL_JSON_VERSION.to_string(),
+            books,
+        })
+    }
+
+    /// Reads a document, which must have the major version of
+    /// `MODEL_JSON_VERSION`, ignoring the fields of newer minor
+    /// versions.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let document: Self = serde_json::from_str(json)?;
+        let major = |version: &str| {
+            version.split('.').next().unwrap_or_default().to_string()
+        };
+        if major(&document.version) != major(MODEL_JSON_VERSION) {
+            return Err(anyhow::anyhow!(
+                "Unsupported version {} of the model JSON, expected version {}",
+                document.version,
+                MODEL_JSON_VERSION
+            ));
+        }
+        Ok(document)
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Reads a document from the given file, see `from_json`.
+    pub fn from_file(path: &std::path::Path) -> Result<Self> {
+        let json = std::fs::read_to_string(path).map_err(|e| {
+            anyhow::anyhow!("Could not read model {}: {}", path.display(), e)
+        })?;
+        Self::from_json(&json).map_err(|e| {
+            anyhow::anyhow!("Invalid model {}: {}", path.display(), e)
+        })
+    }
+
+    /// Adds the books of the document to the given model, with the
+    /// IRIs that they have in the document.
+    pub fn into_model(&self, model: &Arc<Model>) -> Result<()> {
+        for document in &self.books {
+            let mut builder = Book::builder_for_node_in_model(
+                named_node(&document.iri)?,
+                model,
+            )?
+            .key(document.key.clone())
+            .title(document.title.clone())
+            .subtitle(document.subtitle.clone())
+            .version(document.version.clone())
+            .repository(document.repository.clone())
+            .url(document.url.clone())
+            .language(document.language.clone())
+            .provenance(document.provenance.clone());
+            for author in &document.authors {
+                builder = builder.author(Some(author.clone()));
+            }
+            let book = builder.build()?;
+            for document in &document.sections {
+                let section = Section::builder_for_node_in_model(
+                    named_node(&document.iri)?,
+                    model,
+                )?
+                .title(document.title.clone())
+                .description(document.description.clone())
+                .provenance(document.provenance.clone())
+                .order(document.order)
+                .book(Some(&book))
+                .build()?;
+                for document in &document.chapters {
+                    build_chapter(model, document, Some(&section), None)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn chapter_documents(chapters: &[Chapter]) -> Result<Vec<ChapterDocument>> {
+    let mut documents = Vec::new();
+    for chapter in chapters {
+        let mut blocks = Vec::new();
+        for block in chapter.get_blocks()? {
+            blocks.push(BlockDocument {
+                iri:        block.get_named_node().as_str().to_string(),
+                order:      block.get_order()?,
+                provenance: block.get_provenance()?,
+                content:    block.content.clone(),
+            });
+        }
+        documents.push(ChapterDocument {
+            iri: chapter.get_named_node().as_str().to_string(),
+            order: chapter.get_order()?,
+            title: chapter.title.clone(),
+            language: chapter.language.clone(),
+            provenance: chapter.get_provenance()?,
+            term: chapter.get_term()?,
+            blocks,
+            chapters: chapter_documents(&chapter.get_chapters()?)?,
+        });
+    }
+    Ok(documents)
+}
+
+/// Adds the given chapter to the given section, or as a subchapter to
+/// the given chapter, with its blocks and subchapters.
+fn build_chapter(
+    model: &Arc<Model>,
+    document: &ChapterDocument,
+    section: Option<&Section>,
+    parent: Option<&Chapter>,
+) -> Result<()> {
+    let mut builder =
+        Chapter::builder_for_node_in_model(named_node(&document.iri)?, model)?
+            .title(document.title.clone())
+            .language(document.language.clone())
+            .provenance(document.provenance.clone())
+            .term(document.term.clone())
+            .order(document.order);
+    builder = match parent {
+        Some(parent) => builder.chapter(Some(parent)),
+        None => builder.section(section),
+    };
+    let chapter = builder.build()?;
+    for document in &document.blocks {
+        Block::builder_for_node_in_model(named_node(&document.iri)?, model)?
+            .content(document.content.clone())
+            .provenance(document.provenance.clone())
+            .order(document.order)
+            .chapter(Some(&chapter))
+            .build()?;
+    }
+    for document in &document.chapters {
+        build_chapter(model, document, None, Some(&chapter))?;
+    }
+    Ok(())
+}
+
+fn named_node(iri: &str) -> Result<NamedNode> {
+    NamedNode::new(iri)
+        .map_err(|e| anyhow::anyhow!("Invalid IRI {}: {}", iri, e))
+}
+
+/// Serializes a `NamedNode` as its IRI, for `#[serde(with = ...)]`.
+pub(super) mod iri {
+    use {
+        oxrdf::NamedNode,
+        serde::{Deserialize, Deserializer, Serializer, de::Error},
+    };
+
+    pub fn serialize<S: Serializer>(
+        node: &NamedNode,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(node.as_str())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<NamedNode, D::Error> {
+        NamedNode::new(String::deserialize(deserializer)?)
+            .map_err(D::Error::custom)
+    }
+}
+
+/// Serializes an optional `NamedNode` as its IRI, for
+/// `#[serde(with = ...)]`.
+pub(super) mod optional_iri {
+    use {
+        oxrdf::NamedNode,
+        serde::{Deserialize, Deserializer, Serializer, de::Error},
+    };
+
+    pub fn serialize<S: Serializer>(
+        node: &Option<NamedNode>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match node {
+            Some(node) => serializer.serialize_str(node.as_str()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<NamedNode>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|iri| NamedNode::new(iri).map_err(D::Error::custom))
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::model::{AdmonitionKind, Inline, ReferenceTarget},
+    };
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        let model = Arc::new(Model::new()?);
+        let book = Book::builder_in_model::<Book>(&model)?
+            .key(Some("urn:vehicles".to_string()))
+            .title(Some("Vehicles".to_string()))
+            .author(Some("Author".to_string()))
+            .language(Some("nl".to_string()))
+            .build()?;
+        let section = Section::builder_in_model::<Section>(&model)?
+            .title(Some("Classes".to_string()))
+            .book(Some(&book))
+            .build()?;
+        let car = Chapter::builder_in_model::<Chapter>(&model)?
+            .title(Some("Auto".to_string()))
+            .language(Some("nl".to_string()))
+            .term(Some(DocumentedTerm {
+                curie: Some("ex:Car".to_string()),
+                alternative_labels: vec!["Wagen".to_string()],
+                ..DocumentedTerm::new("Class")
+            }))
+            .provenance(Some(Provenance {
+                source: Some("http://example.com/Car".to_string()),
+                file: Some("vehicles.ttl".to_string()),
+                line: Some(12),
+                ..Provenance::default()
+            }))
+            .blocks(vec![
+                BlockContent::Code {
+                    language: Some("turtle".to_string()),
+                    code:     ":Car a owl:Class .".to_string(),
+                },
+                BlockContent::Admonition {
+                    kind:    AdmonitionKind::Note,
+                    content: vec![Inline::Strong(vec![Inline::text("Let op")])],
+                },
+            ])
+            .section(Some(&section))
+            .build()?;
+        Chapter::builder_in_model::<Chapter>(&model)?
+            .title(Some("Fiets".to_string()))
+            .blocks(vec![BlockContent::Paragraph(vec![
+                Inline::Reference {
+                    target:  ReferenceTarget::Element(
+                        car.get_named_node().clone(),
+                    ),
+                    content: vec![Inline::text("Auto")],
+                },
+                Inline::reference("http://example.com/Car", "ex:Car"),
+            ])])
+            .chapter(Some(&car))
+            .build()?;
+
+        let document = ModelDocument::of_model(&model)?;
+        let json = document.to_json()?;
+        assert!(json.contains(r#""element": "urn:grapharch:chapter:"#));
+        assert!(json.contains(r#""source": "http://example.com/Car""#));
+
+        // A model read from the JSON gives the same JSON
+        let imported = Arc::new(Model::new()?);
+        ModelDocument::from_json(&json)?.into_model(&imported)?;
+        assert_eq!(ModelDocument::of_model(&imported)?, document);
+        assert!(imported.validate()?.is_empty());
+
+        let newer = json.replace(r#""version": "1.1""#, r#""version": "2.0""#);
+        assert!(ModelDocument::from_json(&newer).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_newer_minor_version() -> Result<()> {
+        let json = r#"{
+            "version": "1.9",
+            "books": [{
+                "iri": "urn:grapharch:book:1",
+                "title": "Vehicles",
+                "authors": ["GraphArch"],
+                "cover": "cover.png",
+                "provenance": { "file": "vehicles.ttl", "commit": "abc" },
+                "sections": [{
+                    "iri": "urn:grapharch:section:1",
+                    "title": "Classes",
+                    "chapters": [{
+                        "iri": "urn:grapharch:chapter:1",
+                        "title": "Car",
+                        "term": { "kind": "Class", "scopeNote": "Cars" },
+                        "blocks": [{
+                            "iri": "urn:grapharch:block:1",
+                            "content": { "definitionList": [{
+                                "term": "IRI",
+                                "description": [{ "text": "ex:Car" }],
+                                "note": "unknown"
+                            }] },
+                            "hidden": true
+                        }]
+                    }]
+                }]
+            }]
+        }"#;
+        let document = ModelDocument::from_json(json)?;
+        let book = &document.books[0];
+        assert_eq!(book.title.as_deref(), Some("Vehicles"));
+        assert_eq!(
+            book.provenance.as_ref().and_then(|p| p.file.as_deref()),
+            Some("vehicles.ttl")
+        );
+        let chapter = &book.sections[0].chapters[0];
+        assert_eq!(
+            chapter.term.as_ref().map(|t| t.kind.as_str()),
+            Some("Class")
+        );
+
+        let model = Arc::new(Model::new()?);
+        document.into_model(&model)?;
+        assert!(model.validate()?.is_empty());
+
+        // The schema requires what the SHACL shapes of the ontology
+        // require
+        let schema: serde_json::Value =
+            serde_json::from_str(MODEL_JSON_SCHEMA)?;
+        assert_eq!(schema["$id"], MODEL_JSON_SCHEMA_ID);
+        let definitions = &schema["$defs"];
+        assert_eq!(
+            definitions["book"]["required"],
+            serde_json::json!(["iri", "title", "authors"])
+        );
+        assert_eq!(
+            definitions["book"]["properties"]["authors"]["minItems"],
+            1
+        );
+        for element in ["section", "chapter"] {
+            assert_eq!(
+                definitions[element]["required"],
+                serde_json::json!(["iri", "title"])
+            );
+        }
+
+        // A model without them reads, but does not validate
+        let untitled = json.replace(r#""title": "Car","#, "");
+        let model = Arc::new(Model::new()?);
+        ModelDocument::from_json(&untitled)?.into_model(&model)?;
+        assert_eq!(model.validate()?.len(), 1);
+        Ok(())
+    }
+}
@@ -1,13 +1,47 @@
+mod block;
 mod book;
+mod book_merge;
 mod chapter;
+mod cross_reference;
 mod element;
+mod glossary;
+mod json;
+mod provenance;
 mod section;
 mod this;
 
 pub use {
+    block::{
+        AdmonitionKind,
+        Block,
+        BlockBuilder,
+        BlockContent,
+        Definition,
+        Inline,
+        ReferenceTarget,
+        plain_text,
+    },
     book::{Book, BookBuilder},
     chapter::Chapter,
+    cross_reference::{
+        Anchor,
+        CrossReferences,
+        UnresolvedReference,
+        anchor_id,
+    },
     element::{Buildable, Element, ElementRef},
+    glossary::{DocumentedTerm, Glossary, GlossaryEntry, IndexEntry},
+    json::{
+        BlockDocument,
+        BookDocument,
+        ChapterDocument,
+        MODEL_JSON_SCHEMA,
+        MODEL_JSON_SCHEMA_ID,
+        MODEL_JSON_VERSION,
+        ModelDocument,
+        SectionDocument,
+    },
+    provenance::Provenance,
     section::Section,
-    this::Model,
+    this::{GRAPHARCH_ONTOLOGY, Model},
 };
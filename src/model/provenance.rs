@@ -0,0 +1,125 @@
+use {
+    super::{Model, element::literal_value},
+    crate::rdf_const::predicates::{
+        OXI_GRAPHARCH_SOURCE,
+        OXI_GRAPHARCH_SOURCE_FILE,
+        OXI_GRAPHARCH_SOURCE_GRAPH,
+        OXI_GRAPHARCH_SOURCE_LINE,
+        OXI_GRAPHARCH_SOURCE_URL,
+    },
+    anyhow::Result,
+    oxigraph::{
+        model::{NamedNode, Term},
+        sparql::QueryResults,
+    },
+    serde::{Deserialize, Serialize},
+};
+
+/// Where an element of the documentation model comes from: the
+/// resource that it documents and the file and line that resource was
+/// read from, so that a reviewer can go from the generated
+/// documentation back to the source.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Provenance {
+    /// The IRI of the resource that the element documents, like an
+    /// OWL class
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// The named graph that the resource was read from, which is the
+    /// file content node of its file in the file registry
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "super::json::optional_iri"
+    )]
+    pub graph:  Option<NamedNode>,
+    /// The path of the file, relative to the root of its file source
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file:   Option<String>,
+    /// The (1-based) line number of the resource in its file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line:   Option<u64>,
+    /// The web URL of the file (at the line), if the file is in a git
+    /// repository with a known web interface
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url:    Option<String>,
+}
+
+impl Provenance {
+    /// The provenance of an element that documents the given IRI,
+    /// without a known location.
+    pub fn of_source(iri: impl Into<String>) -> Self {
+        Self { source: Some(iri.into()), ..Self::default() }
+    }
+
+    /// Returns the location of the source, like `ontology/core.ttl:120`,
+    /// if the file is known.
+    pub fn location(&self) -> Option<String> {
+        let file = self.file.as_deref()?;
+        Some(match self.line {
+            Some(line) => format!("{}:{}", file, line),
+            None => file.to_string(),
+        })
+    }
+
+    /// Returns the provenance of the given element of the model, if it
+    /// has any.
+    pub(super) fn of_element(
+        model: &Model,
+        element: &NamedNode,
+    ) -> Result<Option<Self>> {
+        let query = format!(
+            r#"
+            SELECT ?source ?graph ?file ?line ?url WHERE {{
+                OPTIONAL {{ {element} {source} ?source }}
+                OPTIONAL {{ {element} {graph} ?graph }}
+                OPTIONAL {{ {element} {file} ?file }}
+                OPTIONAL {{ {element} {line} ?line }}
+                OPTIONAL {{ {element} {url} ?url }}
+            }}
+            LIMIT 1
+            "#,
+            source = *OXI_GRAPHARCH_SOURCE,
+            graph = *OXI_GRAPHARCH_SOURCE_GRAPH,
+            file = *OXI_GRAPHARCH_SOURCE_FILE,
+            line = *OXI_GRAPHARCH_SOURCE_LINE,
+            url = *OXI_GRAPHARCH_SOURCE_URL,
+        );
+        if let QueryResults::Solutions(mut solutions) =
+            model.get_store().query(&query)?
+        {
+            if let Some(solution) = solutions.next() {
+                let solution = solution?;
+                let named_node = |variable: &str| {
+                    match solution.get(variable) {
+                        Some(Term::NamedNode(node)) => Some(node.clone()),
+                        _ => None,
+                    }
+                };
+                let provenance = Self {
+                    source: named_node("source")
+                        .map(|source| source.into_string()),
+                    graph:  named_node("graph"),
+                    file:   literal_value(&solution, "file"),
+                    line:   literal_value(&solution, "line")
+                        .and_then(|line| line.parse().ok()),
+                    url:    literal_value(&solution, "url"),
+                };
+                if provenance != Self::default() {
+                    return Ok(Some(provenance));
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl std::fmt::Display for Provenance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.location(), &self.source) {
+            (Some(location), _) => write!(f, "{}", location),
+            (None, Some(source)) => write!(f, "<{}>", source),
+            (None, None) => write!(f, "unknown source"),
+        }
+    }
+}
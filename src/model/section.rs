@@ -1,7 +1,18 @@
 use {
-    super::{Buildable, Element, chapter::Chapter, element::ElementRef},
+    super::{
+        Buildable,
+        Element,
+        book::Book,
+        chapter::Chapter,
+        element::ElementRef,
+        provenance::Provenance,
+    },
+    crate::rdf_const::{
+        classes::OXI_CLASS_GRAPHARCH_SECTION,
+        predicates::{OXI_DCTERMS_DESCRIPTION, OXI_DCTERMS_TITLE},
+    },
     anyhow::Result,
-    oxrdf::Literal,
+    oxrdf::{Literal, NamedNode},
 };
 
 #[derive(Debug, Clone)]
@@ -25,38 +36,75 @@ pub struct SectionBuilder {
     element_ref: ElementRef,
     title:       Option<String>,
     description: Option<String>,
+    book:        Option<NamedNode>,
+    order:       Option<i64>,
+    provenance:  Option<Provenance>,
 }
 
 impl Buildable<Section> for SectionBuilder {
     fn get_element_ref(&self) -> &ElementRef { &self.element_ref }
 
+    fn get_element_ref_mut(&mut self) -> &mut ElementRef {
+        &mut self.element_ref
+    }
+
     fn new(element_ref: ElementRef) -> Self {
-        Self { element_ref, title: None, description: None }
+        Self {
+            element_ref,
+            title: None,
+            description: None,
+            book: None,
+            order: None,
+            provenance: None,
+        }
     }
 
     fn build(&mut self) -> Result<Section> {
+        // A section is identified by its book, its position in the book
+        // and its title
+        let book = self.book.clone();
+        let order = match &book {
+            Some(book) => Some(self.resolve_order(book, self.order)?),
+            None => self.order,
+        };
+        let parts = [
+            book.as_ref()
+                .map(|book| book.as_str().to_string())
+                .unwrap_or_default(),
+            order.map(|order| order.to_string()).unwrap_or_default(),
+            self.title.clone().unwrap_or_default(),
+        ];
+        self.identify(&OXI_CLASS_GRAPHARCH_SECTION, &parts)?;
         let section = Section {
             element_ref: self.element_ref.clone(),
             title:       self.title.clone(),
             description: self.description.clone(),
         };
 
-        self.insert_type("http://example.org/Section")?;
+        self.insert_type(&OXI_CLASS_GRAPHARCH_SECTION)?;
 
         if let Some(title) = &self.title {
             self.insert_object_literal(
-                oxrdf::NamedNode::new("http://purl.org/dc/terms/title")?,
+                OXI_DCTERMS_TITLE.clone(),
                 Literal::new_simple_literal(title),
             )?;
         }
 
         if let Some(description) = &self.description {
             self.insert_object_literal(
-                oxrdf::NamedNode::new("http://purl.org/dc/terms/description")?,
+                OXI_DCTERMS_DESCRIPTION.clone(),
                 Literal::new_simple_literal(description),
             )?;
         }
 
+        if let Some(provenance) = &self.provenance {
+            self.insert_provenance(provenance)?;
+        }
+
+        if let Some(book) = &book {
+            self.insert_parent(book, order)?;
+        }
+
         Ok(section)
     }
 }
@@ -71,44 +119,30 @@ impl SectionBuilder {
         self.description = description;
         self
     }
+
+    /// Adds the section to the given book.
+    pub fn book(mut self, book: Option<&Book>) -> Self {
+        self.book = book.map(|book| book.get_named_node().clone());
+        self
+    }
+
+    /// Sets the position of the section in its book, the section goes
+    /// after the existing sections of the book if not set.
+    pub fn order(mut self, order: Option<i64>) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Records where the section comes from, see `Provenance`.
+    pub fn provenance(mut self, provenance: Option<Provenance>) -> Self {
+        self.provenance = provenance;
+        self
+    }
 }
 
 impl Section {
+    /// Returns the chapters of the section, in order.
     pub fn get_chapters(&self) -> anyhow::Result<Vec<Chapter>> {
-        let query = format!(
-            r#"
-            PREFIX dc: <http://purl.org/dc/terms/>
-            PREFIX rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#>
-            SELECT DISTINCT ?graph ?iri ?title ?content WHERE {{
-                GRAPH ?graph {{
-                    ?iri rdf:type <http://example.org/Chapter> ;
-                         dc:title ?title ;
-                         <http://example.org/schema/belongsTo> {} .
-                    OPTIONAL {{ ?iri <http://example.org/schema/content> ?content }}
-                }}
-            }}
-            "#,
-            self.get_named_node(),
-        );
-
-        let results = self.get_model().get_store().query(&query)?;
-        let mut chapters = Vec::new();
-
-        if let oxigraph::sparql::QueryResults::Solutions(solutions) = results {
-            for solution in solutions {
-                let solution = solution?;
-                let chapter = Chapter {
-                    element_ref: ElementRef::from_solution(
-                        self.get_model(),
-                        &solution,
-                    )?,
-                    title:       solution.get("title").map(|s| s.to_string()),
-                    content:     solution.get("content").map(|s| s.to_string()),
-                };
-                chapters.push(chapter);
-            }
-        }
-
-        Ok(chapters)
+        Chapter::get_chapters_of(self.get_model(), self.get_named_node())
     }
 }
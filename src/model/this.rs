@@ -1,8 +1,22 @@
 use {
-    oxigraph::{model::Quad, store::Store},
+    crate::{
+        store::{ShaclViolation, read_node_shapes, validate_shapes},
+        util::Languages,
+    },
+    oxigraph::{
+        io::RdfFormat,
+        model::Quad,
+        sparql::QueryResults,
+        store::Store,
+    },
     std::sync::Arc,
 };
 
+/// The GraphArch documentation ontology in Turtle, which defines the
+/// classes and properties of the documentation model. Its classes are
+/// also the SHACL shapes that the model is validated against.
+pub const GRAPHARCH_ONTOLOGY: &str = include_str!("grapharch.ttl");
+
 /// The documentation model holds the data that needs to be
 /// documented. It is a wrapper around an OxiGraph store.
 /// The store does not necessarily hold all data from the source, but
@@ -15,12 +29,49 @@ use {
 /// TODO: Move the field sections to the Book struct
 #[derive(Clone)]
 pub struct Model {
-    store: Arc<Store>,
+    store:     Arc<Store>,
+    /// The languages that the documentors document in
+    languages: Languages,
 }
 
 impl Model {
     pub fn new() -> anyhow::Result<Self> {
-        Ok(Self { store: Arc::new(Store::new()?) })
+        Ok(Self {
+            store:     Arc::new(Store::new()?),
+            languages: Languages::default(),
+        })
+    }
+
+    /// Sets the languages that the documentors pick the labels and
+    /// definitions of the resources in, see `Languages`.
+    pub fn with_languages(mut self, languages: Languages) -> Self {
+        self.languages = languages;
+        self
+    }
+
+    pub fn languages(&self) -> &Languages { &self.languages }
+
+    /// Executes the given SPARQL query against the documentation
+    /// model. This is meant for inspection tools such as the REPL,
+    /// everything else should use the typed element API.
+    pub fn query(&self, sparql: &str) -> anyhow::Result<QueryResults> {
+        Ok(self.store.query(sparql)?)
+    }
+
+    /// Validates the documentation model against the SHACL shapes of
+    /// the GraphArch ontology (see `GRAPHARCH_ONTOLOGY`) and returns
+    /// the violations, which point at a bug in a documentor.
+    pub fn validate(&self) -> anyhow::Result<Vec<ShaclViolation>> {
+        let ontology = Store::new()?;
+        ontology.load_from_reader(
+            RdfFormat::Turtle,
+            GRAPHARCH_ONTOLOGY.as_bytes(),
+        )?;
+        validate_shapes(
+            &read_node_shapes(&ontology, None),
+            &self.store,
+            None,
+        )
     }
 
     pub(super) fn get_store(&self) -> &Arc<Store> { &self.store }
@@ -29,6 +80,14 @@ impl Model {
         self.store.insert(quad)?;
         Ok(())
     }
+
+    /// Executes the given SPARQL update against the documentation
+    /// model, for changes that span several elements such as merging
+    /// books (see `Book::merge_books`).
+    pub(super) fn update(&self, sparql: &str) -> anyhow::Result<()> {
+        self.store.update(sparql)?;
+        Ok(())
+    }
 }
 
 impl std::fmt::Debug for Model {
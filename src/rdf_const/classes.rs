@@ -1,5 +1,19 @@
 use {
-    super::namespaces::NS_FILE_REGISTRY,
+    super::namespaces::{
+        NS_DCAT,
+        NS_DCTERMS,
+        NS_EKGF_OUTCOME,
+        NS_EKGF_PERSONA,
+        NS_EKGF_STORY,
+        NS_EKGF_USE_CASE,
+        NS_EKGF_WORKFLOW,
+        NS_FILE_REGISTRY,
+        NS_GRAPHARCH,
+        NS_OWL,
+        NS_RDFS,
+        NS_SHACL,
+        NS_VOID,
+    },
     const_format::concatcp,
     lazy_static::lazy_static,
     oxrdf::NamedNode,
@@ -9,9 +23,169 @@ pub static CLASS_FILE_REGISTRY_FILE: &str = concatcp!(NS_FILE_REGISTRY, "File");
 pub static CLASS_FILE_REGISTRY_FILE_DISTRIBUTION: &str =
     concatcp!(NS_FILE_REGISTRY, "FileDistribution");
 
+pub static CLASS_VOID_DATASET: &str = concatcp!(NS_VOID, "Dataset");
+
+pub static CLASS_DCAT_DATASET: &str = concatcp!(NS_DCAT, "Dataset");
+
+pub static CLASS_DCAT_DISTRIBUTION: &str = concatcp!(NS_DCAT, "Distribution");
+
+pub static CLASS_RDFS_CLASS: &str = concatcp!(NS_RDFS, "Class");
+
+pub static CLASS_OWL_CLASS: &str = concatcp!(NS_OWL, "Class");
+
+pub static CLASS_SHACL_NODE_SHAPE: &str = concatcp!(NS_SHACL, "NodeShape");
+
+pub static CLASS_SHACL_PROPERTY_SHAPE: &str =
+    concatcp!(NS_SHACL, "PropertyShape");
+
+pub static CLASS_EKGF_USE_CASE: &str = concatcp!(NS_EKGF_USE_CASE, "UseCase");
+
+pub static CLASS_EKGF_PERSONA: &str = concatcp!(NS_EKGF_PERSONA, "Persona");
+
+pub static CLASS_EKGF_OUTCOME: &str = concatcp!(NS_EKGF_OUTCOME, "Outcome");
+
+pub static CLASS_EKGF_OUTCOME_STEREOTYPE: &str =
+    concatcp!(NS_EKGF_OUTCOME, "OutcomeStereotype");
+
+pub static CLASS_EKGF_STORY: &str = concatcp!(NS_EKGF_STORY, "UserStory");
+
+pub static CLASS_EKGF_WORKFLOW_DEFINITION: &str =
+    concatcp!(NS_EKGF_WORKFLOW, "WorkflowDefinition");
+
+pub static CLASS_DCTERMS_LICENSE_DOCUMENT: &str =
+    concatcp!(NS_DCTERMS, "LicenseDocument");
+
+pub static CLASS_GRAPHARCH_ELEMENT: &str = concatcp!(NS_GRAPHARCH, "Element");
+
+pub static CLASS_GRAPHARCH_BOOK: &str = concatcp!(NS_GRAPHARCH, "Book");
+
+pub static CLASS_GRAPHARCH_SECTION: &str = concatcp!(NS_GRAPHARCH, "Section");
+
+pub static CLASS_GRAPHARCH_CHAPTER: &str = concatcp!(NS_GRAPHARCH, "Chapter");
+
+pub static CLASS_GRAPHARCH_BLOCK: &str = concatcp!(NS_GRAPHARCH, "Block");
+
+pub static CLASS_GRAPHARCH_CROSS_REFERENCE: &str =
+    concatcp!(NS_GRAPHARCH, "CrossReference");
+
+pub static CLASS_GRAPHARCH_PARAGRAPH: &str =
+    concatcp!(NS_GRAPHARCH, "Paragraph");
+
+pub static CLASS_GRAPHARCH_HEADING: &str = concatcp!(NS_GRAPHARCH, "Heading");
+
+pub static CLASS_GRAPHARCH_LIST: &str = concatcp!(NS_GRAPHARCH, "List");
+
+pub static CLASS_GRAPHARCH_TABLE: &str = concatcp!(NS_GRAPHARCH, "Table");
+
+pub static CLASS_GRAPHARCH_CODE_LISTING: &str =
+    concatcp!(NS_GRAPHARCH, "CodeListing");
+
+pub static CLASS_GRAPHARCH_ADMONITION: &str =
+    concatcp!(NS_GRAPHARCH, "Admonition");
+
+pub static CLASS_GRAPHARCH_IMAGE: &str = concatcp!(NS_GRAPHARCH, "Image");
+
+pub static CLASS_GRAPHARCH_DEFINITION_LIST: &str =
+    concatcp!(NS_GRAPHARCH, "DefinitionList");
+
+pub static CLASS_GRAPHARCH_QUOTE: &str = concatcp!(NS_GRAPHARCH, "Quote");
+
+pub static CLASS_GRAPHARCH_LIST_ITEM: &str =
+    concatcp!(NS_GRAPHARCH, "ListItem");
+
+pub static CLASS_GRAPHARCH_TABLE_ROW: &str =
+    concatcp!(NS_GRAPHARCH, "TableRow");
+
+pub static CLASS_GRAPHARCH_TABLE_CELL: &str =
+    concatcp!(NS_GRAPHARCH, "TableCell");
+
+pub static CLASS_GRAPHARCH_DEFINITION: &str =
+    concatcp!(NS_GRAPHARCH, "Definition");
+
+pub static CLASS_GRAPHARCH_INLINE: &str = concatcp!(NS_GRAPHARCH, "Inline");
+
+pub static CLASS_GRAPHARCH_TEXT: &str = concatcp!(NS_GRAPHARCH, "Text");
+
+pub static CLASS_GRAPHARCH_EMPHASIS: &str = concatcp!(NS_GRAPHARCH, "Emphasis");
+
+pub static CLASS_GRAPHARCH_STRONG: &str = concatcp!(NS_GRAPHARCH, "Strong");
+
+pub static CLASS_GRAPHARCH_CODE: &str = concatcp!(NS_GRAPHARCH, "Code");
+
+pub static CLASS_GRAPHARCH_LINK: &str = concatcp!(NS_GRAPHARCH, "Link");
+
 lazy_static! {
     pub static ref OXI_CLASS_FILE_REGISTRY_FILE: NamedNode =
         NamedNode::new_unchecked(CLASS_FILE_REGISTRY_FILE);
     pub static ref OXI_CLASS_FILE_REGISTRY_FILE_DISTRIBUTION: NamedNode =
         NamedNode::new_unchecked(CLASS_FILE_REGISTRY_FILE_DISTRIBUTION);
+    pub static ref OXI_CLASS_VOID_DATASET: NamedNode =
+        NamedNode::new_unchecked(CLASS_VOID_DATASET);
+    pub static ref OXI_CLASS_DCAT_DATASET: NamedNode =
+        NamedNode::new_unchecked(CLASS_DCAT_DATASET);
+    pub static ref OXI_CLASS_DCAT_DISTRIBUTION: NamedNode =
+        NamedNode::new_unchecked(CLASS_DCAT_DISTRIBUTION);
+    pub static ref OXI_CLASS_RDFS_CLASS: NamedNode =
+        NamedNode::new_unchecked(CLASS_RDFS_CLASS);
+    pub static ref OXI_CLASS_OWL_CLASS: NamedNode =
+        NamedNode::new_unchecked(CLASS_OWL_CLASS);
+    pub static ref OXI_CLASS_SHACL_NODE_SHAPE: NamedNode =
+        NamedNode::new_unchecked(CLASS_SHACL_NODE_SHAPE);
+    pub static ref OXI_CLASS_SHACL_PROPERTY_SHAPE: NamedNode =
+        NamedNode::new_unchecked(CLASS_SHACL_PROPERTY_SHAPE);
+    pub static ref OXI_CLASS_DCTERMS_LICENSE_DOCUMENT: NamedNode =
+        NamedNode::new_unchecked(CLASS_DCTERMS_LICENSE_DOCUMENT);
+    pub static ref OXI_CLASS_GRAPHARCH_ELEMENT: NamedNode =
+        NamedNode::new_unchecked(CLASS_GRAPHARCH_ELEMENT);
+    pub static ref OXI_CLASS_GRAPHARCH_BOOK: NamedNode =
+        NamedNode::new_unchecked(CLASS_GRAPHARCH_BOOK);
+    pub static ref OXI_CLASS_GRAPHARCH_SECTION: NamedNode =
+        NamedNode::new_unchecked(CLASS_GRAPHARCH_SECTION);
+    pub static ref OXI_CLASS_GRAPHARCH_CHAPTER: NamedNode =
+        NamedNode::new_unchecked(CLASS_GRAPHARCH_CHAPTER);
+    pub static ref OXI_CLASS_GRAPHARCH_BLOCK: NamedNode =
+        NamedNode::new_unchecked(CLASS_GRAPHARCH_BLOCK);
+    pub static ref OXI_CLASS_GRAPHARCH_CROSS_REFERENCE: NamedNode =
+        NamedNode::new_unchecked(CLASS_GRAPHARCH_CROSS_REFERENCE);
+}
+
+lazy_static! {
+    pub static ref OXI_CLASS_GRAPHARCH_PARAGRAPH: NamedNode =
+        NamedNode::new_unchecked(CLASS_GRAPHARCH_PARAGRAPH);
+    pub static ref OXI_CLASS_GRAPHARCH_HEADING: NamedNode =
+        NamedNode::new_unchecked(CLASS_GRAPHARCH_HEADING);
+    pub static ref OXI_CLASS_GRAPHARCH_LIST: NamedNode =
+        NamedNode::new_unchecked(CLASS_GRAPHARCH_LIST);
+    pub static ref OXI_CLASS_GRAPHARCH_TABLE: NamedNode =
+        NamedNode::new_unchecked(CLASS_GRAPHARCH_TABLE);
+    pub static ref OXI_CLASS_GRAPHARCH_CODE_LISTING: NamedNode =
+        NamedNode::new_unchecked(CLASS_GRAPHARCH_CODE_LISTING);
+    pub static ref OXI_CLASS_GRAPHARCH_ADMONITION: NamedNode =
+        NamedNode::new_unchecked(CLASS_GRAPHARCH_ADMONITION);
+    pub static ref OXI_CLASS_GRAPHARCH_IMAGE: NamedNode =
+        NamedNode::new_unchecked(CLASS_GRAPHARCH_IMAGE);
+    pub static ref OXI_CLASS_GRAPHARCH_DEFINITION_LIST: NamedNode =
+        NamedNode::new_unchecked(CLASS_GRAPHARCH_DEFINITION_LIST);
+    pub static ref OXI_CLASS_GRAPHARCH_QUOTE: NamedNode =
+        NamedNode::new_unchecked(CLASS_GRAPHARCH_QUOTE);
+    pub static ref OXI_CLASS_GRAPHARCH_LIST_ITEM: NamedNode =
+        NamedNode::new_unchecked(CLASS_GRAPHARCH_LIST_ITEM);
+    pub static ref OXI_CLASS_GRAPHARCH_TABLE_ROW: NamedNode =
+        NamedNode::new_unchecked(CLASS_GRAPHARCH_TABLE_ROW);
+    pub static ref OXI_CLASS_GRAPHARCH_TABLE_CELL: NamedNode =
+        NamedNode::new_unchecked(CLASS_GRAPHARCH_TABLE_CELL);
+    pub static ref OXI_CLASS_GRAPHARCH_DEFINITION: NamedNode =
+        NamedNode::new_unchecked(CLASS_GRAPHARCH_DEFINITION);
+    pub static ref OXI_CLASS_GRAPHARCH_INLINE: NamedNode =
+        NamedNode::new_unchecked(CLASS_GRAPHARCH_INLINE);
+    pub static ref OXI_CLASS_GRAPHARCH_TEXT: NamedNode =
+        NamedNode::new_unchecked(CLASS_GRAPHARCH_TEXT);
+    pub static ref OXI_CLASS_GRAPHARCH_EMPHASIS: NamedNode =
+        NamedNode::new_unchecked(CLASS_GRAPHARCH_EMPHASIS);
+    pub static ref OXI_CLASS_GRAPHARCH_STRONG: NamedNode =
+        NamedNode::new_unchecked(CLASS_GRAPHARCH_STRONG);
+    pub static ref OXI_CLASS_GRAPHARCH_CODE: NamedNode =
+        NamedNode::new_unchecked(CLASS_GRAPHARCH_CODE);
+    pub static ref OXI_CLASS_GRAPHARCH_LINK: NamedNode =
+        NamedNode::new_unchecked(CLASS_GRAPHARCH_LINK);
 }
@@ -6,7 +6,12 @@ use {
 // Define the named graph URI
 pub const RDF_GRAPH_FILE_REGISTRY: &str = "urn:GraphArch:file-registry";
 
+// The named graph that holds the prefixes harvested from the sources
+pub const RDF_GRAPH_PREFIX_REGISTRY: &str = "urn:GraphArch:prefix-registry";
+
 lazy_static! {
     pub static ref OXI_GRAPH_FILE_REGISTRY: GraphName =
         NamedNode::new_unchecked(RDF_GRAPH_FILE_REGISTRY).into();
+    pub static ref OXI_GRAPH_PREFIX_REGISTRY: GraphName =
+        NamedNode::new_unchecked(RDF_GRAPH_PREFIX_REGISTRY).into();
 }
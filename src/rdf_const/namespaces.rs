@@ -10,4 +10,34 @@ pub static NS_XSD: &str = "http://www.w3.org/2001/XMLSchema#";
 
 pub static NS_SKOS: &str = "http://www.w3.org/2004/02/skos/core#";
 
+pub static NS_SHACL: &str = "http://www.w3.org/ns/shacl#";
+
 pub static NS_FILE_REGISTRY: &str = "https://ekgf.org/ontology/file-registry#";
+
+pub static NS_GRAPHARCH: &str =
+    "https://ekgf.org/ontology/GraphArch/documentation#";
+
+pub static NS_EKGF_USE_CASE: &str = "https://ekgf.org/ontology/use-case/";
+
+pub static NS_EKGF_PERSONA: &str = "https://ekgf.org/ontology/persona/";
+
+pub static NS_EKGF_OUTCOME: &str = "https://ekgf.org/ontology/outcome/";
+
+pub static NS_EKGF_STORY: &str = "https://ekgf.org/ontology/user-story/";
+
+pub static NS_EKGF_WORKFLOW: &str = "https://ekgf.org/ontology/workflow/";
+
+pub static NS_VANN: &str = "http://purl.org/vocab/vann/";
+
+pub static NS_DCTERMS: &str = "http://purl.org/dc/terms/";
+
+pub static NS_CC: &str = "http://creativecommons.org/ns#";
+
+pub static NS_VOID: &str = "http://rdfs.org/ns/void#";
+
+pub static NS_DCAT: &str = "http://www.w3.org/ns/dcat#";
+
+pub static NS_PROV: &str = "http://www.w3.org/ns/prov#";
+
+pub static NS_IANA_MEDIA_TYPES: &str =
+    "https://www.iana.org/assignments/media-types/";
@@ -1,5 +1,17 @@
 use {
-    super::namespaces::{NS_FILE_REGISTRY, NS_RDF, NS_RDFS},
+    super::namespaces::{
+        NS_DCAT,
+        NS_DCTERMS,
+        NS_FILE_REGISTRY,
+        NS_GRAPHARCH,
+        NS_OWL,
+        NS_PROV,
+        NS_RDF,
+        NS_RDFS,
+        NS_SHACL,
+        NS_VANN,
+        NS_VOID,
+    },
     const_format::concatcp,
     lazy_static::lazy_static,
     oxrdf::NamedNode,
@@ -9,6 +21,181 @@ static PREDICATE_RDF_TYPE: &str = concatcp!(NS_RDF, "type");
 
 static PREDICATE_RDFS_LABEL: &str = concatcp!(NS_RDFS, "label");
 
+static PREDICATE_RDF_FIRST: &str = concatcp!(NS_RDF, "first");
+
+static PREDICATE_RDF_REST: &str = concatcp!(NS_RDF, "rest");
+
+static PREDICATE_RDFS_SUB_CLASS_OF: &str = concatcp!(NS_RDFS, "subClassOf");
+
+static PREDICATE_RDFS_DOMAIN: &str = concatcp!(NS_RDFS, "domain");
+
+static PREDICATE_RDFS_RANGE: &str = concatcp!(NS_RDFS, "range");
+
+static PREDICATE_OWL_ON_PROPERTY: &str = concatcp!(NS_OWL, "onProperty");
+
+static PREDICATE_OWL_SOME_VALUES_FROM: &str =
+    concatcp!(NS_OWL, "someValuesFrom");
+
+static PREDICATE_OWL_ALL_VALUES_FROM: &str = concatcp!(NS_OWL, "allValuesFrom");
+
+static PREDICATE_OWL_HAS_VALUE: &str = concatcp!(NS_OWL, "hasValue");
+
+static PREDICATE_OWL_HAS_SELF: &str = concatcp!(NS_OWL, "hasSelf");
+
+static PREDICATE_OWL_MIN_CARDINALITY: &str =
+    concatcp!(NS_OWL, "minCardinality");
+
+static PREDICATE_OWL_MAX_CARDINALITY: &str =
+    concatcp!(NS_OWL, "maxCardinality");
+
+static PREDICATE_OWL_CARDINALITY: &str = concatcp!(NS_OWL, "cardinality");
+
+static PREDICATE_OWL_MIN_QUALIFIED_CARDINALITY: &str =
+    concatcp!(NS_OWL, "minQualifiedCardinality");
+
+static PREDICATE_OWL_MAX_QUALIFIED_CARDINALITY: &str =
+    concatcp!(NS_OWL, "maxQualifiedCardinality");
+
+static PREDICATE_OWL_QUALIFIED_CARDINALITY: &str =
+    concatcp!(NS_OWL, "qualifiedCardinality");
+
+static PREDICATE_OWL_ON_CLASS: &str = concatcp!(NS_OWL, "onClass");
+
+static PREDICATE_OWL_ON_DATA_RANGE: &str = concatcp!(NS_OWL, "onDataRange");
+
+static PREDICATE_OWL_INTERSECTION_OF: &str =
+    concatcp!(NS_OWL, "intersectionOf");
+
+static PREDICATE_OWL_UNION_OF: &str = concatcp!(NS_OWL, "unionOf");
+
+static PREDICATE_OWL_COMPLEMENT_OF: &str = concatcp!(NS_OWL, "complementOf");
+
+static PREDICATE_OWL_ONE_OF: &str = concatcp!(NS_OWL, "oneOf");
+
+static PREDICATE_OWL_INVERSE_OF: &str = concatcp!(NS_OWL, "inverseOf");
+
+static PREDICATE_OWL_EQUIVALENT_CLASS: &str =
+    concatcp!(NS_OWL, "equivalentClass");
+
+static PREDICATE_OWL_DISJOINT_WITH: &str = concatcp!(NS_OWL, "disjointWith");
+
+static PREDICATE_SHACL_TARGET_CLASS: &str = concatcp!(NS_SHACL, "targetClass");
+
+static PREDICATE_SHACL_TARGET_NODE: &str = concatcp!(NS_SHACL, "targetNode");
+
+static PREDICATE_SHACL_TARGET_SUBJECTS_OF: &str =
+    concatcp!(NS_SHACL, "targetSubjectsOf");
+
+static PREDICATE_SHACL_TARGET_OBJECTS_OF: &str =
+    concatcp!(NS_SHACL, "targetObjectsOf");
+
+static PREDICATE_SHACL_PROPERTY: &str = concatcp!(NS_SHACL, "property");
+
+static PREDICATE_SHACL_PATH: &str = concatcp!(NS_SHACL, "path");
+
+static PREDICATE_SHACL_DATATYPE: &str = concatcp!(NS_SHACL, "datatype");
+
+static PREDICATE_SHACL_CLASS: &str = concatcp!(NS_SHACL, "class");
+
+static PREDICATE_SHACL_NODE_KIND: &str = concatcp!(NS_SHACL, "nodeKind");
+
+static PREDICATE_SHACL_NODE: &str = concatcp!(NS_SHACL, "node");
+
+static PREDICATE_SHACL_MIN_COUNT: &str = concatcp!(NS_SHACL, "minCount");
+
+static PREDICATE_SHACL_MAX_COUNT: &str = concatcp!(NS_SHACL, "maxCount");
+
+static PREDICATE_SHACL_MIN_LENGTH: &str = concatcp!(NS_SHACL, "minLength");
+
+static PREDICATE_SHACL_MAX_LENGTH: &str = concatcp!(NS_SHACL, "maxLength");
+
+static PREDICATE_SHACL_MIN_INCLUSIVE: &str =
+    concatcp!(NS_SHACL, "minInclusive");
+
+static PREDICATE_SHACL_MAX_INCLUSIVE: &str =
+    concatcp!(NS_SHACL, "maxInclusive");
+
+static PREDICATE_SHACL_MIN_EXCLUSIVE: &str =
+    concatcp!(NS_SHACL, "minExclusive");
+
+static PREDICATE_SHACL_MAX_EXCLUSIVE: &str =
+    concatcp!(NS_SHACL, "maxExclusive");
+
+static PREDICATE_SHACL_PATTERN: &str = concatcp!(NS_SHACL, "pattern");
+
+static PREDICATE_SHACL_FLAGS: &str = concatcp!(NS_SHACL, "flags");
+
+static PREDICATE_SHACL_IN: &str = concatcp!(NS_SHACL, "in");
+
+static PREDICATE_SHACL_HAS_VALUE: &str = concatcp!(NS_SHACL, "hasValue");
+
+static PREDICATE_SHACL_SEVERITY: &str = concatcp!(NS_SHACL, "severity");
+
+static PREDICATE_SHACL_MESSAGE: &str = concatcp!(NS_SHACL, "message");
+
+static PREDICATE_SHACL_NAME: &str = concatcp!(NS_SHACL, "name");
+
+static PREDICATE_SHACL_DESCRIPTION: &str = concatcp!(NS_SHACL, "description");
+
+static PREDICATE_SHACL_ORDER: &str = concatcp!(NS_SHACL, "order");
+
+static PREDICATE_SHACL_CLOSED: &str = concatcp!(NS_SHACL, "closed");
+
+static PREDICATE_SHACL_IGNORED_PROPERTIES: &str =
+    concatcp!(NS_SHACL, "ignoredProperties");
+
+static PREDICATE_SHACL_DEACTIVATED: &str = concatcp!(NS_SHACL, "deactivated");
+
+static PREDICATE_SHACL_INVERSE_PATH: &str = concatcp!(NS_SHACL, "inversePath");
+
+static PREDICATE_SHACL_ALTERNATIVE_PATH: &str =
+    concatcp!(NS_SHACL, "alternativePath");
+
+static PREDICATE_SHACL_ZERO_OR_MORE_PATH: &str =
+    concatcp!(NS_SHACL, "zeroOrMorePath");
+
+static PREDICATE_SHACL_ONE_OR_MORE_PATH: &str =
+    concatcp!(NS_SHACL, "oneOrMorePath");
+
+static PREDICATE_SHACL_ZERO_OR_ONE_PATH: &str =
+    concatcp!(NS_SHACL, "zeroOrOnePath");
+
+static PREDICATE_VANN_PREFERRED_NAMESPACE_PREFIX: &str =
+    concatcp!(NS_VANN, "preferredNamespacePrefix");
+
+static PREDICATE_VANN_PREFERRED_NAMESPACE_URI: &str =
+    concatcp!(NS_VANN, "preferredNamespaceUri");
+
+static PREDICATE_VOID_TRIPLES: &str = concatcp!(NS_VOID, "triples");
+
+static PREDICATE_VOID_CLASSES: &str = concatcp!(NS_VOID, "classes");
+
+static PREDICATE_VOID_PROPERTIES: &str = concatcp!(NS_VOID, "properties");
+
+static PREDICATE_VOID_ENTITIES: &str = concatcp!(NS_VOID, "entities");
+
+static PREDICATE_VOID_CLASS: &str = concatcp!(NS_VOID, "class");
+
+static PREDICATE_VOID_PROPERTY: &str = concatcp!(NS_VOID, "property");
+
+static PREDICATE_VOID_CLASS_PARTITION: &str =
+    concatcp!(NS_VOID, "classPartition");
+
+static PREDICATE_VOID_PROPERTY_PARTITION: &str =
+    concatcp!(NS_VOID, "propertyPartition");
+
+static PREDICATE_VOID_SUBSET: &str = concatcp!(NS_VOID, "subset");
+
+static PREDICATE_DCAT_DISTRIBUTION: &str = concatcp!(NS_DCAT, "distribution");
+
+static PREDICATE_DCAT_MEDIA_TYPE: &str = concatcp!(NS_DCAT, "mediaType");
+
+static PREDICATE_DCAT_BYTE_SIZE: &str = concatcp!(NS_DCAT, "byteSize");
+
+static PREDICATE_DCTERMS_TITLE: &str = concatcp!(NS_DCTERMS, "title");
+
+static PREDICATE_DCTERMS_LICENSE: &str = concatcp!(NS_DCTERMS, "license");
+
 static PREDICATE_FILE_REGISTRY_IS_CONTENT_FOR_FILE: &str =
     concatcp!(NS_FILE_REGISTRY, "isContentForFile");
 
@@ -21,11 +208,214 @@ static PREDICATE_FILE_REGISTRY_LAST_MODIFIED: &str =
 static PREDICATE_FILE_REGISTRY_CREATED_AT: &str =
     concatcp!(NS_FILE_REGISTRY, "createdAt");
 
+static PREDICATE_FILE_REGISTRY_LINE_NUMBER: &str =
+    concatcp!(NS_FILE_REGISTRY, "lineNumber");
+
+static PREDICATE_PROV_WAS_DERIVED_FROM: &str =
+    concatcp!(NS_PROV, "wasDerivedFrom");
+
+static PREDICATE_DCTERMS_DESCRIPTION: &str =
+    concatcp!(NS_DCTERMS, "description");
+
+static PREDICATE_DCTERMS_CREATOR: &str = concatcp!(NS_DCTERMS, "creator");
+
+static PREDICATE_DCTERMS_LANGUAGE: &str = concatcp!(NS_DCTERMS, "language");
+
+static PREDICATE_GRAPHARCH_SUBTITLE: &str = concatcp!(NS_GRAPHARCH, "subtitle");
+
+static PREDICATE_GRAPHARCH_VERSION: &str = concatcp!(NS_GRAPHARCH, "version");
+
+static PREDICATE_GRAPHARCH_KEY: &str = concatcp!(NS_GRAPHARCH, "key");
+
+static PREDICATE_GRAPHARCH_REPOSITORY: &str =
+    concatcp!(NS_GRAPHARCH, "repository");
+
+static PREDICATE_GRAPHARCH_URL: &str = concatcp!(NS_GRAPHARCH, "url");
+
+static PREDICATE_GRAPHARCH_PARENT: &str = concatcp!(NS_GRAPHARCH, "parent");
+
+static PREDICATE_GRAPHARCH_ORDER: &str = concatcp!(NS_GRAPHARCH, "order");
+
+static PREDICATE_GRAPHARCH_SOURCE: &str = concatcp!(NS_GRAPHARCH, "source");
+
+static PREDICATE_GRAPHARCH_SOURCE_GRAPH: &str =
+    concatcp!(NS_GRAPHARCH, "sourceGraph");
+
+static PREDICATE_GRAPHARCH_SOURCE_LINE: &str =
+    concatcp!(NS_GRAPHARCH, "sourceLine");
+
+static PREDICATE_GRAPHARCH_SOURCE_FILE: &str =
+    concatcp!(NS_GRAPHARCH, "sourceFile");
+
+static PREDICATE_GRAPHARCH_SOURCE_URL: &str =
+    concatcp!(NS_GRAPHARCH, "sourceUrl");
+
+static PREDICATE_GRAPHARCH_TARGET: &str = concatcp!(NS_GRAPHARCH, "target");
+
+static PREDICATE_GRAPHARCH_TARGET_IRI: &str =
+    concatcp!(NS_GRAPHARCH, "targetIri");
+
+static PREDICATE_GRAPHARCH_TEXT: &str = concatcp!(NS_GRAPHARCH, "text");
+
+static PREDICATE_GRAPHARCH_LANGUAGE: &str = concatcp!(NS_GRAPHARCH, "language");
+
+static PREDICATE_GRAPHARCH_NUMBERED: &str = concatcp!(NS_GRAPHARCH, "numbered");
+
+static PREDICATE_GRAPHARCH_HEADER: &str = concatcp!(NS_GRAPHARCH, "header");
+
+static PREDICATE_GRAPHARCH_ADMONITION_KIND: &str =
+    concatcp!(NS_GRAPHARCH, "admonitionKind");
+
+static PREDICATE_GRAPHARCH_CAPTION: &str = concatcp!(NS_GRAPHARCH, "caption");
+
+static PREDICATE_GRAPHARCH_TERM: &str = concatcp!(NS_GRAPHARCH, "term");
+
+static PREDICATE_GRAPHARCH_TERM_KIND: &str =
+    concatcp!(NS_GRAPHARCH, "termKind");
+
+static PREDICATE_GRAPHARCH_CURIE: &str = concatcp!(NS_GRAPHARCH, "curie");
+
+static PREDICATE_GRAPHARCH_ALTERNATIVE_LABEL: &str =
+    concatcp!(NS_GRAPHARCH, "alternativeLabel");
+
+static PREDICATE_GRAPHARCH_DEFINITION: &str =
+    concatcp!(NS_GRAPHARCH, "definition");
+
 lazy_static! {
     pub static ref OXI_RDF_TYPE: NamedNode =
         NamedNode::new_unchecked(PREDICATE_RDF_TYPE);
     pub static ref OXI_RDFS_LABEL: NamedNode =
         NamedNode::new_unchecked(PREDICATE_RDFS_LABEL);
+    pub static ref OXI_RDF_FIRST: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_RDF_FIRST);
+    pub static ref OXI_RDF_REST: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_RDF_REST);
+    pub static ref OXI_RDFS_SUB_CLASS_OF: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_RDFS_SUB_CLASS_OF);
+    pub static ref OXI_RDFS_DOMAIN: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_RDFS_DOMAIN);
+    pub static ref OXI_RDFS_RANGE: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_RDFS_RANGE);
+    pub static ref OXI_OWL_ON_PROPERTY: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_OWL_ON_PROPERTY);
+    pub static ref OXI_OWL_SOME_VALUES_FROM: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_OWL_SOME_VALUES_FROM);
+    pub static ref OXI_OWL_ALL_VALUES_FROM: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_OWL_ALL_VALUES_FROM);
+    pub static ref OXI_OWL_HAS_VALUE: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_OWL_HAS_VALUE);
+    pub static ref OXI_OWL_HAS_SELF: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_OWL_HAS_SELF);
+    pub static ref OXI_OWL_MIN_CARDINALITY: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_OWL_MIN_CARDINALITY);
+    pub static ref OXI_OWL_MAX_CARDINALITY: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_OWL_MAX_CARDINALITY);
+    pub static ref OXI_OWL_CARDINALITY: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_OWL_CARDINALITY);
+    pub static ref OXI_OWL_MIN_QUALIFIED_CARDINALITY: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_OWL_MIN_QUALIFIED_CARDINALITY);
+    pub static ref OXI_OWL_MAX_QUALIFIED_CARDINALITY: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_OWL_MAX_QUALIFIED_CARDINALITY);
+    pub static ref OXI_OWL_QUALIFIED_CARDINALITY: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_OWL_QUALIFIED_CARDINALITY);
+    pub static ref OXI_OWL_ON_CLASS: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_OWL_ON_CLASS);
+    pub static ref OXI_OWL_ON_DATA_RANGE: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_OWL_ON_DATA_RANGE);
+    pub static ref OXI_OWL_INTERSECTION_OF: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_OWL_INTERSECTION_OF);
+    pub static ref OXI_OWL_UNION_OF: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_OWL_UNION_OF);
+    pub static ref OXI_OWL_COMPLEMENT_OF: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_OWL_COMPLEMENT_OF);
+    pub static ref OXI_OWL_ONE_OF: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_OWL_ONE_OF);
+    pub static ref OXI_OWL_INVERSE_OF: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_OWL_INVERSE_OF);
+    pub static ref OXI_OWL_EQUIVALENT_CLASS: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_OWL_EQUIVALENT_CLASS);
+    pub static ref OXI_OWL_DISJOINT_WITH: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_OWL_DISJOINT_WITH);
+}
+
+lazy_static! {
+    pub static ref OXI_SHACL_TARGET_CLASS: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_TARGET_CLASS);
+    pub static ref OXI_SHACL_TARGET_NODE: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_TARGET_NODE);
+    pub static ref OXI_SHACL_TARGET_SUBJECTS_OF: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_TARGET_SUBJECTS_OF);
+    pub static ref OXI_SHACL_TARGET_OBJECTS_OF: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_TARGET_OBJECTS_OF);
+    pub static ref OXI_SHACL_PROPERTY: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_PROPERTY);
+    pub static ref OXI_SHACL_PATH: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_PATH);
+    pub static ref OXI_SHACL_DATATYPE: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_DATATYPE);
+    pub static ref OXI_SHACL_CLASS: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_CLASS);
+    pub static ref OXI_SHACL_NODE_KIND: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_NODE_KIND);
+    pub static ref OXI_SHACL_NODE: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_NODE);
+    pub static ref OXI_SHACL_MIN_COUNT: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_MIN_COUNT);
+    pub static ref OXI_SHACL_MAX_COUNT: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_MAX_COUNT);
+    pub static ref OXI_SHACL_MIN_LENGTH: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_MIN_LENGTH);
+    pub static ref OXI_SHACL_MAX_LENGTH: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_MAX_LENGTH);
+    pub static ref OXI_SHACL_MIN_INCLUSIVE: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_MIN_INCLUSIVE);
+    pub static ref OXI_SHACL_MAX_INCLUSIVE: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_MAX_INCLUSIVE);
+    pub static ref OXI_SHACL_MIN_EXCLUSIVE: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_MIN_EXCLUSIVE);
+    pub static ref OXI_SHACL_MAX_EXCLUSIVE: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_MAX_EXCLUSIVE);
+    pub static ref OXI_SHACL_PATTERN: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_PATTERN);
+    pub static ref OXI_SHACL_FLAGS: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_FLAGS);
+    pub static ref OXI_SHACL_IN: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_IN);
+    pub static ref OXI_SHACL_HAS_VALUE: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_HAS_VALUE);
+    pub static ref OXI_SHACL_SEVERITY: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_SEVERITY);
+    pub static ref OXI_SHACL_MESSAGE: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_MESSAGE);
+    pub static ref OXI_SHACL_NAME: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_NAME);
+    pub static ref OXI_SHACL_DESCRIPTION: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_DESCRIPTION);
+    pub static ref OXI_SHACL_ORDER: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_ORDER);
+    pub static ref OXI_SHACL_CLOSED: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_CLOSED);
+    pub static ref OXI_SHACL_IGNORED_PROPERTIES: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_IGNORED_PROPERTIES);
+    pub static ref OXI_SHACL_DEACTIVATED: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_DEACTIVATED);
+    pub static ref OXI_SHACL_INVERSE_PATH: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_INVERSE_PATH);
+    pub static ref OXI_SHACL_ALTERNATIVE_PATH: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_ALTERNATIVE_PATH);
+    pub static ref OXI_SHACL_ZERO_OR_MORE_PATH: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_ZERO_OR_MORE_PATH);
+    pub static ref OXI_SHACL_ONE_OR_MORE_PATH: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_ONE_OR_MORE_PATH);
+    pub static ref OXI_SHACL_ZERO_OR_ONE_PATH: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_SHACL_ZERO_OR_ONE_PATH);
+}
+
+lazy_static! {
+    pub static ref OXI_VANN_PREFERRED_NAMESPACE_PREFIX: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_VANN_PREFERRED_NAMESPACE_PREFIX);
+    pub static ref OXI_VANN_PREFERRED_NAMESPACE_URI: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_VANN_PREFERRED_NAMESPACE_URI);
     pub static ref OXI_FILE_REGISTRY_IS_CONTENT_FOR_FILE: NamedNode =
         NamedNode::new_unchecked(PREDICATE_FILE_REGISTRY_IS_CONTENT_FOR_FILE);
     pub static ref OXI_FILE_REGISTRY_FILE_SIZE: NamedNode =
@@ -34,4 +424,95 @@ lazy_static! {
         NamedNode::new_unchecked(PREDICATE_FILE_REGISTRY_LAST_MODIFIED);
     pub static ref OXI_FILE_REGISTRY_CREATED_AT: NamedNode =
         NamedNode::new_unchecked(PREDICATE_FILE_REGISTRY_CREATED_AT);
+    pub static ref OXI_FILE_REGISTRY_LINE_NUMBER: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_FILE_REGISTRY_LINE_NUMBER);
+    pub static ref OXI_PROV_WAS_DERIVED_FROM: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_PROV_WAS_DERIVED_FROM);
+    pub static ref OXI_VOID_TRIPLES: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_VOID_TRIPLES);
+    pub static ref OXI_VOID_CLASSES: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_VOID_CLASSES);
+    pub static ref OXI_VOID_PROPERTIES: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_VOID_PROPERTIES);
+    pub static ref OXI_VOID_ENTITIES: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_VOID_ENTITIES);
+    pub static ref OXI_VOID_CLASS: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_VOID_CLASS);
+    pub static ref OXI_VOID_PROPERTY: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_VOID_PROPERTY);
+    pub static ref OXI_VOID_CLASS_PARTITION: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_VOID_CLASS_PARTITION);
+    pub static ref OXI_VOID_PROPERTY_PARTITION: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_VOID_PROPERTY_PARTITION);
+    pub static ref OXI_VOID_SUBSET: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_VOID_SUBSET);
+    pub static ref OXI_DCAT_DISTRIBUTION: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_DCAT_DISTRIBUTION);
+    pub static ref OXI_DCAT_MEDIA_TYPE: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_DCAT_MEDIA_TYPE);
+    pub static ref OXI_DCAT_BYTE_SIZE: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_DCAT_BYTE_SIZE);
+    pub static ref OXI_DCTERMS_TITLE: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_DCTERMS_TITLE);
+    pub static ref OXI_DCTERMS_LICENSE: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_DCTERMS_LICENSE);
+}
+
+lazy_static! {
+    pub static ref OXI_DCTERMS_DESCRIPTION: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_DCTERMS_DESCRIPTION);
+    pub static ref OXI_DCTERMS_CREATOR: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_DCTERMS_CREATOR);
+    pub static ref OXI_DCTERMS_LANGUAGE: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_DCTERMS_LANGUAGE);
+    pub static ref OXI_GRAPHARCH_SUBTITLE: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_GRAPHARCH_SUBTITLE);
+    pub static ref OXI_GRAPHARCH_VERSION: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_GRAPHARCH_VERSION);
+    pub static ref OXI_GRAPHARCH_KEY: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_GRAPHARCH_KEY);
+    pub static ref OXI_GRAPHARCH_REPOSITORY: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_GRAPHARCH_REPOSITORY);
+    pub static ref OXI_GRAPHARCH_URL: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_GRAPHARCH_URL);
+    pub static ref OXI_GRAPHARCH_PARENT: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_GRAPHARCH_PARENT);
+    pub static ref OXI_GRAPHARCH_ORDER: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_GRAPHARCH_ORDER);
+    pub static ref OXI_GRAPHARCH_SOURCE: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_GRAPHARCH_SOURCE);
+    pub static ref OXI_GRAPHARCH_SOURCE_GRAPH: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_GRAPHARCH_SOURCE_GRAPH);
+    pub static ref OXI_GRAPHARCH_SOURCE_LINE: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_GRAPHARCH_SOURCE_LINE);
+    pub static ref OXI_GRAPHARCH_SOURCE_FILE: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_GRAPHARCH_SOURCE_FILE);
+    pub static ref OXI_GRAPHARCH_SOURCE_URL: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_GRAPHARCH_SOURCE_URL);
+    pub static ref OXI_GRAPHARCH_TARGET: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_GRAPHARCH_TARGET);
+    pub static ref OXI_GRAPHARCH_TARGET_IRI: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_GRAPHARCH_TARGET_IRI);
+    pub static ref OXI_GRAPHARCH_TEXT: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_GRAPHARCH_TEXT);
+    pub static ref OXI_GRAPHARCH_LANGUAGE: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_GRAPHARCH_LANGUAGE);
+    pub static ref OXI_GRAPHARCH_NUMBERED: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_GRAPHARCH_NUMBERED);
+    pub static ref OXI_GRAPHARCH_HEADER: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_GRAPHARCH_HEADER);
+    pub static ref OXI_GRAPHARCH_ADMONITION_KIND: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_GRAPHARCH_ADMONITION_KIND);
+    pub static ref OXI_GRAPHARCH_CAPTION: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_GRAPHARCH_CAPTION);
+    pub static ref OXI_GRAPHARCH_TERM: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_GRAPHARCH_TERM);
+    pub static ref OXI_GRAPHARCH_TERM_KIND: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_GRAPHARCH_TERM_KIND);
+    pub static ref OXI_GRAPHARCH_CURIE: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_GRAPHARCH_CURIE);
+    pub static ref OXI_GRAPHARCH_ALTERNATIVE_LABEL: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_GRAPHARCH_ALTERNATIVE_LABEL);
+    pub static ref OXI_GRAPHARCH_DEFINITION: NamedNode =
+        NamedNode::new_unchecked(PREDICATE_GRAPHARCH_DEFINITION);
 }
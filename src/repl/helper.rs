@@ -0,0 +1,196 @@
+use rustyline::{
+    Helper,
+    completion::Completer,
+    highlight::Highlighter,
+    hint::Hinter,
+    validate::{ValidationContext, ValidationResult, Validator},
+};
+
+/// The rustyline helper of the REPL.
+///
+/// Its only real job is to decide when the user is done typing,
+/// so that a SPARQL query can span multiple lines. An input is
+/// considered complete when it is:
+///
+/// - a meta-command (starting with a `.`),
+/// - a prologue of `PREFIX` declarations only, see `split_prologue`,
+/// - a query with at least one `{` and balanced braces, or
+/// - a query with balanced braces ending with a `;` (to force execution of, for
+///   instance, a `DESCRIBE <iri>` without a group graph pattern).
+#[derive(Debug, Default)]
+pub struct SparqlHelper;
+
+impl SparqlHelper {
+    /// Returns true if the given input can be executed as is.
+    pub fn is_complete(input: &str) -> bool {
+        let trimmed = input.trim();
+        if trimmed.is_empty() || trimmed.starts_with('.') {
+            return true;
+        }
+        if Self::split_prologue(trimmed).1.is_empty() {
+            return true;
+        }
+        let (opened, depth) = Self::brace_depth(trimmed);
+        depth <= 0 && (opened || trimmed.ends_with(';'))
+    }
+
+    /// Splits the prologue off the given query: returns its `PREFIX`
+    /// declarations, as prefix and namespace, and the rest of the
+    /// query without leading whitespace, which is empty if the query
+    /// only declares prefixes.
+    pub fn split_prologue(query: &str) -> (Vec<(String, String)>, &str) {
+        let mut prefixes = Vec::new();
+        let mut rest = query.trim_start();
+        while let Some(declaration) = rest
+            .get(..6)
+            .filter(|keyword| keyword.eq_ignore_ascii_case("PREFIX"))
+            .map(|_| &rest[6..])
+            .filter(|declaration| declaration.starts_with(char::is_whitespace))
+        {
+            let Some((prefix, after)) = declaration.split_once(':') else {
+                break;
+            };
+            let prefix = prefix.trim();
+            let Some((namespace, after)) = after
+                .trim_start()
+                .strip_prefix('<')
+                .and_then(|after| after.split_once('>'))
+            else {
+                break;
+            };
+            if prefix.contains(char::is_whitespace) {
+                break;
+            }
+            prefixes.push((prefix.to_string(), namespace.to_string()));
+            rest = after.trim_start();
+        }
+        (prefixes, rest)
+    }
+
+    /// Counts the nesting depth of `{` and `}` outside of IRIs, string
+    /// literals and comments. Returns whether any brace was opened at
+    /// all, and the final depth.
+    fn brace_depth(input: &str) -> (bool, i32) {
+        let chars: Vec<char> = input.chars().collect();
+        let mut opened = false;
+        let mut depth = 0;
+        let mut quote: Option<char> = None;
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            match quote {
+                Some(_) if c == '\\' => i += 1,
+                Some(q) if c == q => quote = None,
+                Some(_) => {},
+                None => {
+                    match c {
+                        '"' | '\'' => quote = Some(c),
+                        '<' => {
+                            // Skip IRIs such as <http://x#>, but not a
+                            // less-than operator in a FILTER
+                            if let Some(len) = chars[i + 1..]
+                                .iter()
+                                .take_while(|c| !c.is_whitespace())
+                                .position(|c| *c == '>')
+                            {
+                                i += len + 1;
+                            }
+                        },
+                        '#' => {
+                            // Skip the rest of the comment line
+                            while i < chars.len() && chars[i] != '\n' {
+                                i += 1;
+                            }
+                        },
+                        '{' => {
+                            opened = true;
+                            depth += 1;
+                        },
+                        '}' => depth -= 1,
+                        _ => {},
+                    }
+                },
+            }
+            i += 1;
+        }
+        (opened, depth)
+    }
+}
+
+impl Validator for SparqlHelper {
+    fn validate(
+        &self,
+        ctx: &mut ValidationContext,
+    ) -> rustyline::Result<ValidationResult> {
+        if Self::is_complete(ctx.input()) {
+            Ok(ValidationResult::Valid(None))
+        } else {
+            Ok(ValidationResult::Incomplete)
+        }
+    }
+}
+
+impl Completer for SparqlHelper {
+    type Candidate = String;
+}
+
+impl Hinter for SparqlHelper {
+    type Hint = String;
+}
+
+impl Highlighter for SparqlHelper {}
+
+impl Helper for SparqlHelper {}
+
+#[cfg(test)]
+mod tests {
+    use super::SparqlHelper;
+
+    #[test]
+    fn test_is_complete() {
+        assert!(SparqlHelper::is_complete(".graphs"));
+        assert!(SparqlHelper::is_complete(
+            "PREFIX owl: <http://www.w3.org/2002/07/owl#>"
+        ));
+        assert!(SparqlHelper::is_complete(
+            "PREFIX ex: <http://ex/> prefix owl:\n  <http://www.w3.org/2002/07/owl#>"
+        ));
+        assert!(!SparqlHelper::is_complete(
+            "PREFIX ex: <http://ex/"
+        ));
+        assert!(!SparqlHelper::is_complete("SELECT ?s"));
+        assert!(!SparqlHelper::is_complete(
+            "SELECT ?s WHERE {\n  ?s a ?o ;"
+        ));
+        assert!(SparqlHelper::is_complete(
+            "SELECT ?s WHERE {\n  ?s a ?o ;\n    ?p \"}\" .\n}"
+        ));
+        assert!(SparqlHelper::is_complete("DESCRIBE <urn:x> ;"));
+        assert!(!SparqlHelper::is_complete(
+            "SELECT ?s WHERE {\n  ?s a <http://www.w3.org/2002/07/owl#Class>"
+        ));
+    }
+
+    #[test]
+    fn test_split_prologue() {
+        // A query on the same line as its prefixes is not prefixes only
+        let query = "PREFIX ex: <http://ex/> PREFIX owl: \
+                     <http://www.w3.org/2002/07/owl#> SELECT * WHERE { ?s ?p ?o }";
+        let (prefixes, rest) = SparqlHelper::split_prologue(query);
+        assert_eq!(prefixes, [
+            ("ex".to_string(), "http://ex/".to_string()),
+            (
+                "owl".to_string(),
+                "http://www.w3.org/2002/07/owl#".to_string()
+            ),
+        ]);
+        assert_eq!(rest, "SELECT * WHERE { ?s ?p ?o }");
+        assert!(SparqlHelper::is_complete(query));
+        assert!(SparqlHelper::is_complete(
+            "PREFIX ex: <http://ex/> ASK {}"
+        ));
+        assert!(!SparqlHelper::is_complete(
+            "PREFIX ex: <http://ex/> SELECT ?s"
+        ));
+    }
+}
@@ -0,0 +1,7 @@
+mod helper;
+mod this;
+
+pub use {
+    helper::SparqlHelper,
+    this::{Repl, ReplTarget},
+};
@@ -0,0 +1,354 @@
+use {
+    super::SparqlHelper,
+    crate::{
+        model::Model,
+        store::{LoaderStore, PrefixRegistry},
+    },
+    console::Style,
+    oxigraph::{model::Term, sparql::QueryResults},
+    rustyline::{Editor, error::ReadlineError, history::DefaultHistory},
+    std::{collections::BTreeMap, path::PathBuf, sync::Arc},
+};
+
+const HISTORY_FILE_NAME: &str = ".grapharch_history";
+
+/// The store that the REPL currently sends its queries to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplTarget {
+    /// The `LoaderStore` with all the raw data that was loaded.
+    Loader,
+    /// The documentation `Model` that the documentors produced.
+    Model,
+}
+
+impl std::fmt::Display for ReplTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReplTarget::Loader => write!(f, "loader"),
+            ReplTarget::Model => write!(f, "model"),
+        }
+    }
+}
+
+/// An interactive SPARQL shell over the `LoaderStore` and the
+/// documentation `Model`.
+///
+/// Queries can span multiple lines (see `SparqlHelper`) and the
+/// input history is persisted between sessions. Every `PREFIX`
+/// declaration that the user types is remembered and prepended to
+/// subsequent queries that don't declare it themselves.
+///
+/// Lines starting with a `.` are meta-commands, type `.help` to
+/// see them.
+pub struct Repl {
+    loader_store: LoaderStore,
+    doc_model:    Arc<Model>,
+    target:       ReplTarget,
+    prefixes:     BTreeMap<String, String>,
+    history:      Option<PathBuf>,
+}
+
+impl Repl {
+    /// Creates a new REPL over the given stores. If no history file
+    /// is given, `~/.grapharch_history` is used.
+    pub fn new(
+        loader_store: LoaderStore,
+        doc_model: Arc<Model>,
+        history: Option<PathBuf>,
+    ) -> Self {
+        let history = history.or_else(|| {
+            std::env::var_os("HOME")
+                .map(|home| PathBuf::from(home).join(HISTORY_FILE_NAME))
+        });
+        // Start with all the prefixes known from the loaded sources
+        let prefixes = loader_store
+            .prefix_registry()
+            .unwrap_or_else(|_| PrefixRegistry::with_well_known_prefixes())
+            .mappings()
+            .into_iter()
+            .map(|m| (m.prefix.clone(), m.namespace.clone()))
+            .collect();
+
+        Self {
+            loader_store,
+            doc_model,
+            target: ReplTarget::Loader,
+            prefixes,
+            history,
+        }
+    }
+
+    /// Runs the read-eval-print loop until the user quits with
+    /// `.quit`, Ctrl-D or Ctrl-C.
+    pub fn run(&mut self) -> anyhow::Result<()> {
+        let mut editor: Editor<SparqlHelper, DefaultHistory> = Editor::new()?;
+        editor.set_helper(Some(SparqlHelper));
+        if let Some(history) = &self.history {
+            // A missing history file is not a problem
+            let _ = editor.load_history(history);
+        }
+
+        println!("GraphArch SPARQL shell, type .help for help");
+        loop {
+            let prompt = format!("{}> ", self.target);
+            match editor.readline(&prompt) {
+                Ok(input) => {
+                    let input = input.trim();
+                    if input.is_empty() {
+                        continue;
+                    }
+                    editor.add_history_entry(input)?;
+                    match self.eval(input) {
+                        Ok(true) => {},
+                        Ok(false) => break,
+                        Err(error) => {
+                            println!("{}", Style::new().red().apply_to(error))
+                        },
+                    }
+                },
+                Err(ReadlineError::Interrupted | ReadlineError::Eof) => break,
+                Err(error) => return Err(error.into()),
+            }
+        }
+
+        if let Some(history) = &self.history {
+            editor.save_history(history)?;
+        }
+        Ok(())
+    }
+
+    /// Evaluates one complete input, returns false if the REPL
+    /// should stop.
+    fn eval(&mut self, input: &str) -> anyhow::Result<bool> {
+        if let Some(command) = input.strip_prefix('.') {
+            return self.eval_meta_command(command);
+        }
+        let query = input.trim_end_matches(';');
+        self.remember_prefixes(query);
+        if Self::is_prefixes_only(query) {
+            return Ok(true);
+        }
+        let results = self.query(&self.with_prefixes(query))?;
+        self.print_results(results)?;
+        Ok(true)
+    }
+
+    fn eval_meta_command(&mut self, command: &str) -> anyhow::Result<bool> {
+        let mut parts = command.split_whitespace();
+        match parts.next().unwrap_or_default() {
+            "quit" | "exit" => return Ok(false),
+            "help" => Self::print_help(),
+            "prefixes" => {
+                for (prefix, namespace) in &self.prefixes {
+                    println!("{}: <{}>", prefix, namespace);
+                }
+            },
+            "graphs" => {
+                let results = self.query(
+                    r#"
+                    SELECT ?graph (COUNT(*) AS ?triples) WHERE {
+                        GRAPH ?graph { ?s ?p ?o }
+                    }
+                    GROUP BY ?graph
+                    ORDER BY ?graph
+                    "#,
+                )?;
+                self.print_results(results)?;
+            },
+            "stats" => {
+                let results = self.query(
+                    r#"
+                    SELECT ?triples ?subjects ?predicates ?classes WHERE {
+                        {
+                            SELECT
+                                (COUNT(*) AS ?triples)
+                                (COUNT(DISTINCT ?s) AS ?subjects)
+                                (COUNT(DISTINCT ?p) AS ?predicates)
+                            WHERE { ?s ?p ?o }
+                        }
+                        {
+                            SELECT (COUNT(DISTINCT ?class) AS ?classes)
+                            WHERE { ?instance a ?class }
+                        }
+                    }
+                    "#,
+                )?;
+                self.print_results(results)?;
+            },
+            "use" => {
+                self.target = match parts.next() {
+                    Some("loader") => ReplTarget::Loader,
+                    Some("model") => ReplTarget::Model,
+                    other => {
+                        return Err(anyhow::anyhow!(
+                            "Unknown store {:?}, use .use loader|model",
+                            other.unwrap_or_default()
+                        ));
+                    },
+                };
+            },
+            other => {
+                return Err(anyhow::anyhow!(
+                    "Unknown command .{}, type .help for help",
+                    other
+                ));
+            },
+        }
+        Ok(true)
+    }
+
+    fn print_help() {
+        println!("Enter a SPARQL query, it is executed as soon as its braces");
+        println!("are balanced (end it with ';' to force execution).");
+        println!("PREFIX declarations are remembered for subsequent queries.");
+        println!();
+        println!(".graphs            List the named graphs and triple counts");
+        println!(".prefixes          List the remembered prefixes");
+        println!(".stats             Show statistics of the current store");
+        println!(".use loader|model  Switch between LoaderStore and Model");
+        println!(".help              Show this help");
+        println!(".quit              Exit the shell");
+    }
+
+    fn query(&self, sparql: &str) -> anyhow::Result<QueryResults> {
+        match self.target {
+            ReplTarget::Loader => self.loader_store.query(sparql),
+            ReplTarget::Model => self.doc_model.query(sparql),
+        }
+    }
+
+    /// Collects the `PREFIX` declarations of the given query.
+    fn remember_prefixes(&mut self, query: &str) {
+        for (prefix, namespace) in Self::parse_prefixes(query) {
+            self.prefixes.insert(prefix, namespace);
+        }
+    }
+
+    fn parse_prefixes(query: &str) -> Vec<(String, String)> {
+        SparqlHelper::split_prologue(query).0
+    }
+
+    fn is_prefixes_only(query: &str) -> bool {
+        SparqlHelper::split_prologue(query).1.is_empty()
+    }
+
+    /// Prepends the remembered prefixes that the query doesn't
+    /// declare itself.
+    fn with_prefixes(&self, query: &str) -> String {
+        let declared: Vec<String> = Self::parse_prefixes(query)
+            .into_iter()
+            .map(|(prefix, _)| prefix)
+            .collect();
+        let mut result = String::new();
+        for (prefix, namespace) in &self.prefixes {
+            if !declared.contains(prefix) {
+                result
+                    .push_str(&format!("PREFIX {}: <{}>\n", prefix, namespace));
+            }
+        }
+        result.push_str(query);
+        result
+    }
+
+    /// Renders the given term, using a CURIE for IRIs in a known
+    /// namespace.
+    fn render_term(&self, term: &Term) -> String {
+        if let Term::NamedNode(node) = term {
+            let longest_match = self
+                .prefixes
+                .iter()
+                .filter(|(_, namespace)| node.as_str().starts_with(*namespace))
+                .max_by_key(|(_, namespace)| namespace.len());
+            if let Some((prefix, namespace)) = longest_match {
+                return format!(
+                    "{}:{}",
+                    prefix,
+                    &node.as_str()[namespace.len()..]
+                );
+            }
+        }
+        term.to_string()
+    }
+
+    fn print_results(&self, results: QueryResults) -> anyhow::Result<()> {
+        let header_style = Style::new().bold().cyan();
+        match results {
+            QueryResults::Boolean(b) => println!("{}", b),
+            QueryResults::Solutions(solutions) => {
+                let variables: Vec<String> = solutions
+                    .variables()
+                    .iter()
+                    .map(|v| v.as_str().to_string())
+                    .collect();
+                let mut rows = Vec::new();
+                for solution in solutions {
+                    let solution = solution?;
+                    rows.push(
+                        variables
+                            .iter()
+                            .map(|v| {
+                                solution
+                                    .get(v.as_str())
+                                    .map(|t| self.render_term(t))
+                                    .unwrap_or_default()
+                            })
+                            .collect::<Vec<String>>(),
+                    );
+                }
+                let widths: Vec<usize> = variables
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| {
+                        rows.iter()
+                            .map(|row| row[i].chars().count())
+                            .max()
+                            .unwrap_or_default()
+                            .max(v.len() + 1)
+                    })
+                    .collect();
+                let header = variables
+                    .iter()
+                    .zip(&widths)
+                    .map(|(v, w)| format!("{:<w$}", format!("?{}", v), w = w))
+                    .collect::<Vec<String>>()
+                    .join(" | ");
+                println!("{}", header_style.apply_to(header));
+                for row in &rows {
+                    println!(
+                        "{}",
+                        row.iter()
+                            .zip(&widths)
+                            .map(|(cell, w)| format!("{:<w$}", cell, w = w))
+                            .collect::<Vec<String>>()
+                            .join(" | ")
+                    );
+                }
+                println!("({} rows)", rows.len());
+            },
+            QueryResults::Graph(triples) => {
+                let mut count = 0;
+                for triple in triples {
+                    let triple = triple?;
+                    println!(
+                        "{} {} {} .",
+                        self.render_term(&triple.subject.into()),
+                        self.render_term(&triple.predicate.into()),
+                        self.render_term(&triple.object)
+                    );
+                    count += 1;
+                }
+                println!("({} triples)", count);
+            },
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for Repl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Repl")
+            .field("target", &self.target)
+            .field("history", &self.history)
+            .finish_non_exhaustive()
+    }
+}
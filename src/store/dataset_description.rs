@@ -0,0 +1,507 @@
+use {
+    super::solution::{count_value, iri_value, literal_value},
+    crate::{
+        rdf_const::{
+            classes::{
+                OXI_CLASS_DCAT_DATASET,
+                OXI_CLASS_DCAT_DISTRIBUTION,
+                OXI_CLASS_VOID_DATASET,
+            },
+            data_types::OXI_RDF_XSD_INTEGER,
+            graphs::RDF_GRAPH_FILE_REGISTRY,
+            namespaces::{
+                NS_DCAT,
+                NS_DCTERMS,
+                NS_IANA_MEDIA_TYPES,
+                NS_RDF,
+                NS_VOID,
+                NS_XSD,
+            },
+            predicates::{
+                OXI_DCAT_BYTE_SIZE,
+                OXI_DCAT_DISTRIBUTION,
+                OXI_DCAT_MEDIA_TYPE,
+                OXI_DCTERMS_TITLE,
+                OXI_RDF_TYPE,
+                OXI_VOID_CLASS,
+                OXI_VOID_CLASS_PARTITION,
+                OXI_VOID_CLASSES,
+                OXI_VOID_ENTITIES,
+                OXI_VOID_PROPERTIES,
+                OXI_VOID_PROPERTY,
+                OXI_VOID_PROPERTY_PARTITION,
+                OXI_VOID_SUBSET,
+                OXI_VOID_TRIPLES,
+            },
+        },
+        store::LoaderStore,
+        util::FileType,
+    },
+    oxrdf::{BlankNode, Literal, NamedNode, Subject, Term, Triple},
+    oxrdfio::{RdfFormat, RdfSerializer},
+    sha2::{Digest, Sha256},
+    std::{
+        collections::BTreeSet,
+        fs::File,
+        io::{BufWriter, Write},
+        path::Path,
+    },
+    tracing::info,
+};
+
+/// The IRI of the dataset that describes everything that was loaded.
+const DATASET_IRI: &str = "urn:GraphArch:dataset";
+
+/// The file name that `write_dataset_description_to_dir` uses.
+pub const DATASET_DESCRIPTION_FILE_NAME: &str = "dataset.ttl";
+
+/// Returns the blank node of the given kind of partition (a class or a
+/// property partition) of the given subset for the given class or
+/// property, named after all three so that the same data always gives
+/// the same description.
+fn partition_node(
+    subset: &NamedNode,
+    partition: &NamedNode,
+    term: &str,
+) -> BlankNode {
+    let mut hasher = Sha256::new();
+    for part in [subset.as_str(), partition.as_str(), term] {
+        hasher.update(part);
+        hasher.update([0]);
+    }
+    let hash = format!("{:x}", hasher.finalize());
+    BlankNode::new_unchecked(format!("p{}", &hash[..32]))
+}
+
+/// A file in the file registry that is described as a distribution,
+/// see `FileRegistryWriter`.
+struct DescribedFile {
+    content_node: NamedNode,
+    file_node:    NamedNode,
+    file_name:    String,
+    file_size:    Option<usize>,
+}
+
+/// Collects the triples of the dataset description.
+#[derive(Default)]
+struct DescriptionBuilder {
+    triples: Vec<Triple>,
+}
+
+impl DescriptionBuilder {
+    fn add(
+        &mut self,
+        subject: impl Into<Subject>,
+        predicate: &NamedNode,
+        object: impl Into<Term>,
+    ) {
+        self.triples
+            .push(Triple::new(subject, predicate.clone(), object));
+    }
+
+    fn add_count(
+        &mut self,
+        subject: impl Into<Subject>,
+        predicate: &NamedNode,
+        count: usize,
+    ) {
+        self.add(
+            subject,
+            predicate,
+            Literal::new_typed_literal(
+                count.to_string(),
+                OXI_RDF_XSD_INTEGER.clone(),
+            ),
+        );
+    }
+}
+
+impl LoaderStore {
+    /// Returns a machine-readable description of all the data in the
+    /// store, as VoID and DCAT triples.
+    ///
+    /// The whole is described as one `void:Dataset` (and
+    /// `dcat:Dataset`) with a `void:subset` per loaded graph. Each
+    /// subset has its triple count and a `void:classPartition` and
+    /// `void:propertyPartition` per class and property. Each file in
+    /// the file registry becomes a `dcat:Distribution` with its media
+    /// type and byte size.
+    pub fn dataset_description(&self) -> anyhow::Result<Vec<Triple>> {
+        let dataset = NamedNode::new(DATASET_IRI)?;
+        let mut builder = DescriptionBuilder::default();
+        builder.add(
+            dataset.clone(),
+            &OXI_RDF_TYPE,
+            OXI_CLASS_VOID_DATASET.clone(),
+        );
+        builder.add(
+            dataset.clone(),
+            &OXI_RDF_TYPE,
+            OXI_CLASS_DCAT_DATASET.clone(),
+        );
+        builder.add(
+            dataset.clone(),
+            &OXI_DCTERMS_TITLE,
+            Literal::new_simple_literal("Data loaded by GraphArch"),
+        );
+
+        let mut total_triples = 0;
+        let mut classes = BTreeSet::new();
+        let mut properties = BTreeSet::new();
+        let mut subsets = BTreeSet::new();
+
+        for graph in self.data_graphs()? {
+            let subset = graph.graph.clone();
+            subsets.insert(subset.clone());
+            builder.add(dataset.clone(), &OXI_VOID_SUBSET, subset.clone());
+            builder.add(
+                subset.clone(),
+                &OXI_RDF_TYPE,
+                OXI_CLASS_VOID_DATASET.clone(),
+            );
+            if let Some(file_name) = &graph.file_name {
+                builder.add(
+                    subset.clone(),
+                    &OXI_DCTERMS_TITLE,
+                    Literal::new_simple_literal(file_name),
+                );
+            }
+
+            let triple_count = self.triple_count(&subset)?;
+            total_triples += triple_count;
+            builder.add_count(subset.clone(), &OXI_VOID_TRIPLES, triple_count);
+
+            let class_counts = self.class_instance_counts(&subset)?;
+            builder.add_count(
+                subset.clone(),
+                &OXI_VOID_CLASSES,
+                class_counts.len(),
+            );
+            for class_count in class_counts {
+                let partition = partition_node(
+                    &subset,
+                    &OXI_VOID_CLASS_PARTITION,
+                    &class_count.term,
+                );
+                builder.add(
+                    subset.clone(),
+                    &OXI_VOID_CLASS_PARTITION,
+                    partition.clone(),
+                );
+                builder.add(
+                    partition.clone(),
+                    &OXI_VOID_CLASS,
+                    NamedNode::new(&class_count.term)?,
+                );
+                builder.add_count(
+                    partition,
+                    &OXI_VOID_ENTITIES,
+                    class_count.count,
+                );
+                classes.insert(class_count.term);
+            }
+
+            let predicate_usage = self.predicate_usage(&subset)?;
+            builder.add_count(
+                subset.clone(),
+                &OXI_VOID_PROPERTIES,
+                predicate_usage.len(),
+            );
+            for predicate_count in predicate_usage {
+                let partition = partition_node(
+                    &subset,
+                    &OXI_VOID_PROPERTY_PARTITION,
+                    &predicate_count.term,
+                );
+                builder.add(
+                    subset.clone(),
+                    &OXI_VOID_PROPERTY_PARTITION,
+                    partition.clone(),
+                );
+                builder.add(
+                    partition.clone(),
+                    &OXI_VOID_PROPERTY,
+                    NamedNode::new(&predicate_count.term)?,
+                );
+                builder.add_count(
+                    partition,
+                    &OXI_VOID_TRIPLES,
+                    predicate_count.count,
+                );
+                properties.insert(predicate_count.term);
+            }
+        }
+
+        builder.add_count(dataset.clone(), &OXI_VOID_TRIPLES, total_triples);
+        builder.add_count(dataset.clone(), &OXI_VOID_CLASSES, classes.len());
+        builder.add_count(
+            dataset.clone(),
+            &OXI_VOID_PROPERTIES,
+            properties.len(),
+        );
+
+        for file in self.described_files()? {
+            builder.add(
+                dataset.clone(),
+                &OXI_DCAT_DISTRIBUTION,
+                file.file_node.clone(),
+            );
+            if subsets.contains(&file.content_node) {
+                builder.add(
+                    file.content_node.clone(),
+                    &OXI_DCAT_DISTRIBUTION,
+                    file.file_node.clone(),
+                );
+            }
+            builder.add(
+                file.file_node.clone(),
+                &OXI_RDF_TYPE,
+                OXI_CLASS_DCAT_DISTRIBUTION.clone(),
+            );
+            builder.add(
+                file.file_node.clone(),
+                &OXI_DCTERMS_TITLE,
+                Literal::new_simple_literal(&file.file_name),
+            );
+            if let Some(media_type) = FileType::from_path(&file.file_name)
+                .and_then(|file_type| file_type.media_type())
+            {
+                builder.add(
+                    file.file_node.clone(),
+                    &OXI_DCAT_MEDIA_TYPE,
+                    NamedNode::new(format!(
+                        "{}{}",
+                        NS_IANA_MEDIA_TYPES, media_type
+                    ))?,
+                );
+            }
+            if let Some(file_size) = file.file_size {
+                builder.add_count(
+                    file.file_node,
+                    &OXI_DCAT_BYTE_SIZE,
+                    file_size,
+                );
+            }
+        }
+
+        Ok(builder.triples)
+    }
+
+    /// Writes the `dataset_description` as Turtle to the given file.
+    pub fn write_dataset_description(&self, path: &Path) -> anyhow::Result<()> {
+        info!(
+            "Writing dataset description to {}",
+            path.display()
+        );
+        let mut serializer = RdfSerializer::from_format(RdfFormat::Turtle)
+            .with_prefix("rdf", NS_RDF)?
+            .with_prefix("xsd", NS_XSD)?
+            .with_prefix("void", NS_VOID)?
+            .with_prefix("dcat", NS_DCAT)?
+            .with_prefix("dcterms", NS_DCTERMS)?
+            .for_writer(BufWriter::new(File::create(path)?));
+        for triple in self.dataset_description()? {
+            serializer.serialize_triple(&triple)?;
+        }
+        serializer.finish()?.flush()?;
+        Ok(())
+    }
+
+    /// Writes the `dataset_description` as Turtle to a file named
+    /// `dataset.ttl` in the given directory, next to the generated
+    /// documentation.
+    pub fn write_dataset_description_to_dir(
+        &self,
+        dir: &Path,
+    ) -> anyhow::Result<()> {
+        std::fs::create_dir_all(dir)?;
+        self.write_dataset_description(&dir.join(DATASET_DESCRIPTION_FILE_NAME))
+    }
+
+    fn described_files(&self) -> anyhow::Result<Vec<DescribedFile>> {
+        let query = format!(
+            r#"
+            PREFIX fr: <https://ekgf.org/ontology/file-registry#>
+            PREFIX rdfs: <http://www.w3.org/2000/01/rdf-schema#>
+            SELECT ?content ?file ?fileName ?fileSize WHERE {{
+                GRAPH <{}> {{
+                    ?content fr:isContentForFile ?file .
+                    ?file rdfs:label ?fileName .
+                    OPTIONAL {{ ?content fr:fileSize ?fileSize }}
+                }}
+            }}
+            ORDER BY ?fileName
+            "#,
+            RDF_GRAPH_FILE_REGISTRY
+        );
+        let mut files = Vec::new();
+        for solution in self.solutions(&query)? {
+            let (Some(content), Some(file), Some(file_name)) = (
+                iri_value(&solution, "content"),
+                iri_value(&solution, "file"),
+                literal_value(&solution, "fileName"),
+            ) else {
+                continue;
+            };
+            files.push(DescribedFile {
+                content_node: NamedNode::new(content)?,
+                file_node: NamedNode::new(file)?,
+                file_name,
+                file_size: solution
+                    .get("fileSize")
+                    .map(|_| count_value(&solution, "fileSize")),
+            });
+        }
+        Ok(files)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the objects of the triples with the given subject and
+    /// predicate.
+    fn objects(
+        triples: &[Triple],
+        subject: impl Into<Subject>,
+        predicate: &NamedNode,
+    ) -> Vec<Term> {
+        let subject = subject.into();
+        triples
+            .iter()
+            .filter(|t| t.subject == subject && &t.predicate == predicate)
+            .map(|t| t.object.clone())
+            .collect()
+    }
+
+    fn count(count: usize) -> Vec<Term> {
+        vec![
+            Literal::new_typed_literal(
+                count.to_string(),
+                OXI_RDF_XSD_INTEGER.clone(),
+            )
+            .into(),
+        ]
+    }
+
+    /// Returns the `void:class` or `void:property` of each partition of
+    /// the given kind of the subset with its count.
+    fn partitions(
+        triples: &[Triple],
+        subset: &NamedNode,
+        partition: &NamedNode,
+        term: &NamedNode,
+        count: &NamedNode,
+    ) -> Vec<(String, Vec<Term>)> {
+        let mut partitions: Vec<(String, Vec<Term>)> =
+            objects(triples, subset.clone(), partition)
+                .into_iter()
+                .filter_map(|node| {
+                    let Term::BlankNode(node) = node else {
+                        return None;
+                    };
+                    let Some(Term::NamedNode(iri)) =
+                        objects(triples, node.clone(), term).pop()
+                    else {
+                        return None;
+                    };
+                    Some((iri.into_string(), objects(triples, node, count)))
+                })
+                .collect();
+        partitions.sort_by(|a, b| a.0.cmp(&b.0));
+        partitions
+    }
+
+    #[test]
+    fn test_dataset_description() -> anyhow::Result<()> {
+        let store = LoaderStore::new_in_memory()?;
+        let people = store.load_turtle(
+            "urn:test:people",
+            r#"
+            @prefix ex: <http://example.com/> .
+            ex:alice a ex:Person ; ex:name "Alice" ; ex:knows ex:bob .
+            ex:bob a ex:Person ; ex:name "Bob" .
+            "#,
+        )?;
+        let companies = store.load_turtle(
+            "urn:test:companies",
+            r#"
+            @prefix ex: <http://example.com/> .
+            ex:acme a ex:Company ; ex:name "ACME" .
+            "#,
+        )?;
+        let triples = store.dataset_description()?;
+        let dataset = NamedNode::new(DATASET_IRI)?;
+
+        let mut subsets = objects(&triples, dataset.clone(), &OXI_VOID_SUBSET);
+        subsets.sort_by_key(|subset| subset.to_string());
+        assert_eq!(subsets, [
+            Term::from(companies.clone()),
+            Term::from(people.clone())
+        ]);
+        assert_eq!(
+            objects(&triples, dataset.clone(), &OXI_VOID_TRIPLES),
+            count(7)
+        );
+        assert_eq!(
+            objects(&triples, dataset.clone(), &OXI_VOID_CLASSES),
+            count(2)
+        );
+        assert_eq!(
+            objects(&triples, dataset, &OXI_VOID_PROPERTIES),
+            count(3)
+        );
+        assert_eq!(
+            objects(&triples, people.clone(), &OXI_VOID_TRIPLES),
+            count(5)
+        );
+        assert_eq!(
+            objects(&triples, companies.clone(), &OXI_VOID_TRIPLES),
+            count(2)
+        );
+
+        assert_eq!(
+            partitions(
+                &triples,
+                &people,
+                &OXI_VOID_CLASS_PARTITION,
+                &OXI_VOID_CLASS,
+                &OXI_VOID_ENTITIES,
+            ),
+            [("http://example.com/Person".to_string(), count(2))]
+        );
+        assert_eq!(
+            partitions(
+                &triples,
+                &people,
+                &OXI_VOID_PROPERTY_PARTITION,
+                &OXI_VOID_PROPERTY,
+                &OXI_VOID_TRIPLES,
+            ),
+            [
+                ("http://example.com/knows".to_string(), count(1)),
+                ("http://example.com/name".to_string(), count(2)),
+                (
+                    "http://www.w3.org/1999/02/22-rdf-syntax-ns#type"
+                        .to_string(),
+                    count(2)
+                ),
+            ]
+        );
+        assert_eq!(
+            partitions(
+                &triples,
+                &companies,
+                &OXI_VOID_CLASS_PARTITION,
+                &OXI_VOID_CLASS,
+                &OXI_VOID_ENTITIES,
+            ),
+            [("http://example.com/Company".to_string(), count(1))]
+        );
+
+        // The same data always gives the same description
+        assert_eq!(store.dataset_description()?, triples);
+        Ok(())
+    }
+}
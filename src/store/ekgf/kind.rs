@@ -0,0 +1,182 @@
+use {
+    crate::rdf_const::{
+        classes::{
+            CLASS_EKGF_OUTCOME,
+            CLASS_EKGF_OUTCOME_STEREOTYPE,
+            CLASS_EKGF_PERSONA,
+            CLASS_EKGF_STORY,
+            CLASS_EKGF_USE_CASE,
+            CLASS_EKGF_WORKFLOW_DEFINITION,
+        },
+        namespaces::{
+            NS_EKGF_OUTCOME,
+            NS_EKGF_PERSONA,
+            NS_EKGF_STORY,
+            NS_EKGF_USE_CASE,
+            NS_EKGF_WORKFLOW,
+        },
+    },
+    const_format::concatcp,
+};
+
+pub(super) const EKGF_PREFIXES: &str = concatcp!(
+    "PREFIX rdfs: <http://www.w3.org/2000/01/rdf-schema#>\n",
+    "PREFIX skos: <http://www.w3.org/2004/02/skos/core#>\n",
+    "PREFIX dcterms: <http://purl.org/dc/terms/>\n",
+    "PREFIX use-case: <",
+    NS_EKGF_USE_CASE,
+    ">\n",
+    "PREFIX persona: <",
+    NS_EKGF_PERSONA,
+    ">\n",
+    "PREFIX outcome: <",
+    NS_EKGF_OUTCOME,
+    ">\n",
+    "PREFIX user-story: <",
+    NS_EKGF_STORY,
+    ">\n",
+    "PREFIX workflow: <",
+    NS_EKGF_WORKFLOW,
+    ">\n",
+);
+
+/// The kinds of things of the EKGF use case ontologies, in the order
+/// in which they appear in the use case book.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum EKGFKind {
+    /// `use-case:UseCase`
+    UseCase,
+    /// `persona:Persona`
+    Persona,
+    /// `outcome:Outcome`
+    Outcome,
+    /// `outcome:OutcomeStereotype`
+    OutcomeStereotype,
+    /// `user-story:UserStory`
+    Story,
+    /// `workflow:WorkflowDefinition`
+    WorkflowDefinition,
+}
+
+impl EKGFKind {
+    pub const ALL: [EKGFKind; 6] = [
+        EKGFKind::UseCase,
+        EKGFKind::Persona,
+        EKGFKind::Outcome,
+        EKGFKind::OutcomeStereotype,
+        EKGFKind::Story,
+        EKGFKind::WorkflowDefinition,
+    ];
+
+    /// Returns the IRI of the class of this kind of thing.
+    pub fn class_iri(&self) -> &'static str {
+        match self {
+            EKGFKind::UseCase => CLASS_EKGF_USE_CASE,
+            EKGFKind::Persona => CLASS_EKGF_PERSONA,
+            EKGFKind::Outcome => CLASS_EKGF_OUTCOME,
+            EKGFKind::OutcomeStereotype => CLASS_EKGF_OUTCOME_STEREOTYPE,
+            EKGFKind::Story => CLASS_EKGF_STORY,
+            EKGFKind::WorkflowDefinition => CLASS_EKGF_WORKFLOW_DEFINITION,
+        }
+    }
+
+    /// Returns the name of one thing of this kind, as used in links.
+    pub fn name(&self) -> &'static str {
+        match self {
+            EKGFKind::UseCase => "Use Case",
+            EKGFKind::Persona => "Persona",
+            EKGFKind::Outcome => "Outcome",
+            EKGFKind::OutcomeStereotype => "Outcome Stereotype",
+            EKGFKind::Story => "Story",
+            EKGFKind::WorkflowDefinition => "Workflow Definition",
+        }
+    }
+
+    /// Returns the title of the section with things of this kind.
+    pub fn section_title(&self) -> &'static str {
+        match self {
+            EKGFKind::UseCase => "Use Cases",
+            EKGFKind::Persona => "Personas",
+            EKGFKind::Outcome => "Outcomes",
+            EKGFKind::OutcomeStereotype => "Outcome Stereotypes",
+            EKGFKind::Story => "Stories",
+            EKGFKind::WorkflowDefinition => "Workflow Definitions",
+        }
+    }
+
+    /// Returns the relations that are documented for things of this
+    /// kind, as a heading and a graph pattern that binds the related
+    /// things to `?term`, given the thing as `?this`.
+    pub(super) fn relations(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            EKGFKind::UseCase => {
+                &[
+                    ("Stories", "?term user-story:isPartOf ?this"),
+                    (
+                        "Personas",
+                        "?story user-story:isPartOf ?this ;
+                                user-story:hasPersona ?term",
+                    ),
+                    (
+                        "Outcomes",
+                        "?story user-story:isPartOf ?this ;
+                                user-story:hasOutcome ?term",
+                    ),
+                    (
+                        "Workflow definitions",
+                        "?story user-story:isPartOf ?this .
+                         ?term workflow:hasStory ?story",
+                    ),
+                ]
+            },
+            EKGFKind::Persona => {
+                &[
+                    ("Stories", "?term user-story:hasPersona ?this"),
+                    (
+                        "Use cases",
+                        "?story user-story:hasPersona ?this ;
+                                user-story:isPartOf ?term",
+                    ),
+                ]
+            },
+            EKGFKind::Outcome => {
+                &[
+                    ("Stereotypes", "?this outcome:hasStereotype ?term"),
+                    ("Stories", "?term user-story:hasOutcome ?this"),
+                ]
+            },
+            EKGFKind::OutcomeStereotype => {
+                &[("Outcomes", "?term outcome:hasStereotype ?this")]
+            },
+            EKGFKind::Story => {
+                &[
+                    ("Use cases", "?this user-story:isPartOf ?term"),
+                    ("Personas", "?this user-story:hasPersona ?term"),
+                    ("Outcomes", "?this user-story:hasOutcome ?term"),
+                    (
+                        "Input concepts",
+                        "?this user-story:hasInput ?term",
+                    ),
+                    (
+                        "Output concepts",
+                        "?this user-story:hasOutput ?term",
+                    ),
+                    (
+                        "Workflow definitions",
+                        "?term workflow:hasStory ?this",
+                    ),
+                ]
+            },
+            EKGFKind::WorkflowDefinition => {
+                &[
+                    ("Stories", "?this workflow:hasStory ?term"),
+                    (
+                        "Use cases",
+                        "?this workflow:hasStory ?story .
+                         ?story user-story:isPartOf ?term",
+                    ),
+                ]
+            },
+        }
+    }
+}
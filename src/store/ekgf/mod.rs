@@ -0,0 +1,7 @@
+mod kind;
+mod thing;
+
+pub use {
+    kind::EKGFKind,
+    thing::{EKGFDescription, EKGFRelation, EKGFThing},
+};
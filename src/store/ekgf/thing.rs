@@ -0,0 +1,224 @@
+use {
+    super::{EKGFKind, kind::EKGF_PREFIXES},
+    crate::store::{
+        LoaderStore,
+        solution::{iri_value, literal_value},
+    },
+    oxrdf::NamedNode,
+};
+
+/// A use case, persona, outcome, story or workflow definition from the
+/// source data.
+#[derive(Debug, Clone)]
+pub struct EKGFThing {
+    /// The IRI of the thing
+    pub iri:         String,
+    pub kind:        EKGFKind,
+    /// `rdfs:label`, `skos:prefLabel` or `dcterms:title`
+    pub label:       Option<String>,
+    /// `dcterms:description`, `skos:definition` or `rdfs:comment`
+    pub description: Option<String>,
+}
+
+/// The things that one thing is related to, such as the personas of
+/// a story.
+#[derive(Debug, Clone)]
+pub struct EKGFRelation {
+    /// The heading of the relation, like "Personas"
+    pub heading: &'static str,
+    /// The IRIs of the related things
+    pub iris:    Vec<String>,
+}
+
+/// A thing with the things that it is related to, see
+/// `LoaderStore::describe_ekgf_thing`.
+#[derive(Debug, Clone)]
+pub struct EKGFDescription {
+    pub thing:     EKGFThing,
+    /// The relations of the thing, only those with related things
+    pub relations: Vec<EKGFRelation>,
+}
+
+impl LoaderStore {
+    /// Returns the things of the given kind in the given graph, or in
+    /// all graphs if no graph is given.
+    pub fn find_ekgf_things(
+        &self,
+        kind: EKGFKind,
+        graph: Option<&NamedNode>,
+    ) -> anyhow::Result<Vec<EKGFThing>> {
+        let class = kind.class_iri();
+        let pattern = match graph {
+            Some(graph) => format!("GRAPH {graph} {{ ?thing a <{class}> }}"),
+            None => format!("?thing a <{class}>"),
+        };
+        let query = format!(
+            r#"{EKGF_PREFIXES}
+            SELECT ?thing (SAMPLE(?l) AS ?label) (SAMPLE(?d) AS ?description)
+            WHERE {{
+                {pattern}
+                FILTER(isIRI(?thing))
+                OPTIONAL {{
+                    ?thing rdfs:label|skos:prefLabel|dcterms:title ?l
+                }}
+                OPTIONAL {{
+                    ?thing dcterms:description|skos:definition|rdfs:comment ?d
+                }}
+            }}
+            GROUP BY ?thing
+            ORDER BY ?thing
+            "#
+        );
+        Ok(self
+            .solutions(&query)?
+            .iter()
+            .filter_map(|solution| {
+                Some(EKGFThing {
+                    iri: iri_value(solution, "thing")?,
+                    kind,
+                    label: literal_value(solution, "label"),
+                    description: literal_value(solution, "description"),
+                })
+            })
+            .collect())
+    }
+
+    /// Returns the given thing with the things that it is related to.
+    pub fn describe_ekgf_thing(
+        &self,
+        thing: &EKGFThing,
+    ) -> anyhow::Result<EKGFDescription> {
+        let mut relations = Vec::new();
+        for &(heading, pattern) in thing.kind.relations() {
+            let query = format!(
+                r#"{EKGF_PREFIXES}
+                SELECT DISTINCT ?term WHERE {{
+                    VALUES ?this {{ <{}> }}
+                    {pattern}
+                    FILTER(isIRI(?term))
+                }}
+                ORDER BY ?term
+                "#,
+                thing.iri
+            );
+            let iris: Vec<String> = self
+                .solutions(&query)?
+                .iter()
+                .filter_map(|solution| iri_value(solution, "term"))
+                .collect();
+            if !iris.is_empty() {
+                relations.push(EKGFRelation { heading, iris });
+            }
+        }
+        Ok(EKGFDescription { thing: thing.clone(), relations })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USE_CASES: &str = r#"
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+        @prefix dcterms: <http://purl.org/dc/terms/> .
+        @prefix use-case: <https://ekgf.org/ontology/use-case/> .
+        @prefix persona: <https://ekgf.org/ontology/persona/> .
+        @prefix outcome: <https://ekgf.org/ontology/outcome/> .
+        @prefix user-story: <https://ekgf.org/ontology/user-story/> .
+        @prefix workflow: <https://ekgf.org/ontology/workflow/> .
+        @prefix ex: <http://example.com/> .
+
+        ex:onboarding a use-case:UseCase ;
+            rdfs:label "Client onboarding" ;
+            dcterms:description "Onboarding of new clients." .
+        ex:analyst a persona:Persona ; rdfs:label "Analyst" .
+        ex:approved a outcome:Outcome ;
+            outcome:hasStereotype ex:decision .
+        ex:decision a outcome:OutcomeStereotype .
+        ex:review a user-story:UserStory ;
+            user-story:isPartOf ex:onboarding ;
+            user-story:hasPersona ex:analyst ;
+            user-story:hasOutcome ex:approved ;
+            user-story:hasInput ex:Client .
+        ex:reviewFlow a workflow:WorkflowDefinition ;
+            workflow:hasStory ex:review .
+    "#;
+
+    fn relations(description: &EKGFDescription) -> Vec<(&str, Vec<&str>)> {
+        description
+            .relations
+            .iter()
+            .map(|relation| {
+                (
+                    relation.heading,
+                    relation.iris.iter().map(String::as_str).collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_use_case_tree() -> anyhow::Result<()> {
+        let store = LoaderStore::new_in_memory()?;
+        let graph = store.load_turtle("urn:test:use-cases", USE_CASES)?;
+        let mut things = Vec::new();
+        for kind in EKGFKind::ALL {
+            things.extend(store.find_ekgf_things(kind, Some(&graph))?);
+        }
+        let iris: Vec<(EKGFKind, &str)> = things
+            .iter()
+            .map(|thing| (thing.kind, thing.iri.as_str()))
+            .collect();
+        assert_eq!(iris, [
+            (EKGFKind::UseCase, "http://example.com/onboarding"),
+            (EKGFKind::Persona, "http://example.com/analyst"),
+            (EKGFKind::Outcome, "http://example.com/approved"),
+            (
+                EKGFKind::OutcomeStereotype,
+                "http://example.com/decision"
+            ),
+            (EKGFKind::Story, "http://example.com/review"),
+            (
+                EKGFKind::WorkflowDefinition,
+                "http://example.com/reviewFlow"
+            ),
+        ]);
+        assert_eq!(
+            things[0].label.as_deref(),
+            Some("Client onboarding")
+        );
+        assert_eq!(
+            things[0].description.as_deref(),
+            Some("Onboarding of new clients.")
+        );
+
+        // A use case is related to personas, outcomes and workflow
+        // definitions through its stories
+        let use_case = store.describe_ekgf_thing(&things[0])?;
+        assert_eq!(relations(&use_case), [
+            ("Stories", vec!["http://example.com/review"]),
+            ("Personas", vec!["http://example.com/analyst"]),
+            ("Outcomes", vec!["http://example.com/approved"]),
+            ("Workflow definitions", vec![
+                "http://example.com/reviewFlow"
+            ]),
+        ]);
+        let story = store.describe_ekgf_thing(&things[4])?;
+        assert_eq!(relations(&story), [
+            ("Use cases", vec!["http://example.com/onboarding"]),
+            ("Personas", vec!["http://example.com/analyst"]),
+            ("Outcomes", vec!["http://example.com/approved"]),
+            ("Input concepts", vec![
+                "http://example.com/Client"
+            ]),
+            ("Workflow definitions", vec![
+                "http://example.com/reviewFlow"
+            ]),
+        ]);
+        let stereotype = store.describe_ekgf_thing(&things[3])?;
+        assert_eq!(relations(&stereotype), [("Outcomes", vec![
+            "http://example.com/approved"
+        ])]);
+        Ok(())
+    }
+}
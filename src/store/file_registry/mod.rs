@@ -1,3 +1,5 @@
+mod reader;
 mod writer;
 
+pub use reader::RegisteredFile;
 pub(crate) use writer::FileRegistryWriter;
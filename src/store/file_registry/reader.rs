@@ -0,0 +1,86 @@
+use {
+    crate::{
+        rdf_const::graphs::RDF_GRAPH_FILE_REGISTRY,
+        store::{
+            LoaderStore,
+            solution::{iri_value, literal_value},
+        },
+    },
+    oxrdf::NamedNode,
+    std::path::PathBuf,
+};
+
+/// A file in the file registry that a named graph was read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegisteredFile {
+    pub path:       PathBuf,
+    /// The (1-based) line number of the code block that the graph was
+    /// read from, if the graph is a code block of a Markdown file
+    pub block_line: Option<usize>,
+}
+
+impl LoaderStore {
+    /// Returns the named graph that the given resource was read from,
+    /// preferring a graph that declares the type of the resource over
+    /// one that only adds statements about it.
+    pub fn graph_of_subject(
+        &self,
+        iri: &str,
+    ) -> anyhow::Result<Option<NamedNode>> {
+        let query = format!(
+            r#"
+            SELECT ?graph ?typed WHERE {{
+                GRAPH ?graph {{
+                    <{iri}> ?predicate ?object .
+                    BIND(EXISTS {{ <{iri}> a ?type }} AS ?typed)
+                }}
+                FILTER(!STRSTARTS(STR(?graph), "urn:GraphArch:"))
+            }}
+            ORDER BY DESC(?typed) ?graph
+            LIMIT 1
+            "#,
+        );
+        Ok(self
+            .solutions(&query)?
+            .first()
+            .and_then(|solution| iri_value(solution, "graph"))
+            .and_then(|graph| NamedNode::new(graph).ok()))
+    }
+
+    /// Returns the file that the given named graph was read from, see
+    /// `register_file` and `load_markdown_code_blocks`.
+    pub fn registered_file(
+        &self,
+        graph: &NamedNode,
+    ) -> anyhow::Result<Option<RegisteredFile>> {
+        let query = format!(
+            r#"
+            PREFIX rdfs: <http://www.w3.org/2000/01/rdf-schema#>
+            PREFIX prov: <http://www.w3.org/ns/prov#>
+            PREFIX fr: <https://ekgf.org/ontology/file-registry#>
+            SELECT ?path ?line WHERE {{
+                GRAPH <{RDF_GRAPH_FILE_REGISTRY}> {{
+                    {{
+                        {graph} fr:isContentForFile ?file .
+                    }} UNION {{
+                        {graph} prov:wasDerivedFrom ?content ;
+                            fr:lineNumber ?line .
+                        ?content fr:isContentForFile ?file .
+                    }}
+                    ?file rdfs:label ?path .
+                }}
+            }}
+            LIMIT 1
+            "#,
+        );
+        Ok(
+            self.solutions(&query)?.first().and_then(|solution| {
+                Some(RegisteredFile {
+                    path:       PathBuf::from(literal_value(solution, "path")?),
+                    block_line: literal_value(solution, "line")
+                        .and_then(|line| line.parse().ok()),
+                })
+            }),
+        )
+    }
+}
@@ -0,0 +1,418 @@
+use {
+    super::solution::{count_value, iri_value, literal_value},
+    crate::{rdf_const::graphs::RDF_GRAPH_FILE_REGISTRY, store::LoaderStore},
+    oxigraph::model::{NamedNode, Term},
+    oxrdf::TermRef,
+    std::{collections::BTreeMap, path::Path},
+};
+
+/// A named graph in the `LoaderStore` that holds data loaded from
+/// one of the sources, as opposed to the graphs that GraphArch uses
+/// for its own bookkeeping (such as the file registry).
+#[derive(Debug, Clone)]
+pub struct DataGraph {
+    /// The name of the graph, which is the file content node of the
+    /// file registry for graphs that were loaded from a file, or
+    /// derived from it for the code blocks of a Markdown file
+    pub graph:     NamedNode,
+    /// The name of the file that the graph was loaded from, if any
+    pub file_name: Option<String>,
+}
+
+/// The number of times a term (a class, a property, a datatype or a
+/// language tag) occurs in a graph.
+#[derive(Debug, Clone)]
+pub struct TermCount {
+    pub term:  String,
+    pub count: usize,
+}
+
+/// How often instances of a class have a given property, for each
+/// property that at least one instance of the class has.
+#[derive(Debug, Clone)]
+pub struct PropertyCardinality {
+    /// The IRI of the class
+    pub class:     String,
+    /// The IRI of the property
+    pub property:  String,
+    /// The number of instances of the class
+    pub instances: usize,
+    /// The minimum number of values per instance, 0 if some instances
+    /// do not have the property
+    pub min:       usize,
+    /// The maximum number of values per instance
+    pub max:       usize,
+}
+
+/// The most frequently used values of a property.
+#[derive(Debug, Clone)]
+pub struct ExampleValues {
+    /// The IRI of the property
+    pub property: String,
+    /// The most used values with their number of uses
+    pub values:   Vec<(Term, usize)>,
+}
+
+/// The profile of the instance data in one named graph of the
+/// `LoaderStore`, see `LoaderStore::profile_graph`.
+#[derive(Debug, Clone)]
+pub struct GraphProfile {
+    pub graph:                  DataGraph,
+    /// The total number of triples in the graph
+    pub triple_count:           usize,
+    /// The number of distinct instances per class, most used first
+    pub class_instance_counts:  Vec<TermCount>,
+    /// The number of triples per predicate, most used first
+    pub predicate_usage:        Vec<TermCount>,
+    /// The number of literals per datatype, most used first
+    pub datatype_distribution:  Vec<TermCount>,
+    /// The number of language-tagged literals per language tag
+    pub language_distribution:  Vec<TermCount>,
+    /// The value cardinalities per class/property pair
+    pub property_cardinalities: Vec<PropertyCardinality>,
+    /// The top-N values per property
+    pub example_values:         Vec<ExampleValues>,
+}
+
+impl LoaderStore {
+    /// Returns the named graphs that hold data loaded from the
+    /// sources, ordered by file name.
+    pub fn data_graphs(&self) -> anyhow::Result<Vec<DataGraph>> {
+        let query = format!(
+            r#"
+            PREFIX fr: <https://ekgf.org/ontology/file-registry#>
+            PREFIX rdfs: <http://www.w3.org/2000/01/rdf-schema#>
+            PREFIX prov: <http://www.w3.org/ns/prov#>
+            SELECT DISTINCT ?graph ?fileName WHERE {{
+                GRAPH ?graph {{ ?s ?p ?o }}
+                FILTER(!STRSTARTS(STR(?graph), "urn:GraphArch:"))
+                OPTIONAL {{
+                    GRAPH <{}> {{
+                        ?graph prov:wasDerivedFrom?/fr:isContentForFile ?file .
+                        ?file rdfs:label ?fileName .
+                    }}
+                }}
+            }}
+            ORDER BY ?fileName ?graph
+            "#,
+            RDF_GRAPH_FILE_REGISTRY
+        );
+        let mut graphs = Vec::new();
+        for solution in self.solutions(&query)? {
+            if let Some(Term::NamedNode(graph)) = solution.get("graph") {
+                graphs.push(DataGraph {
+                    graph:     graph.clone(),
+                    file_name: literal_value(&solution, "fileName"),
+                });
+            }
+        }
+        Ok(graphs)
+    }
+
+    /// Returns the named graph that holds the data loaded from the
+    /// given file, if any.
+    pub fn graph_for_file(
+        &self,
+        file_name: &Path,
+    ) -> anyhow::Result<Option<NamedNode>> {
+        let file_name = file_name.to_string_lossy();
+        Ok(self
+            .data_graphs()?
+            .into_iter()
+            .find(|g| g.file_name.as_deref() == Some(file_name.as_ref()))
+            .map(|g| g.graph))
+    }
+
+    /// Profiles the instance data in the given graph, showing at
+    /// most `top_n` example values per property.
+    pub fn profile_graph(
+        &self,
+        graph: &DataGraph,
+        top_n: usize,
+    ) -> anyhow::Result<GraphProfile> {
+        let g = &graph.graph;
+        let triple_count = self.triple_count(g)?;
+        let class_instance_counts = self.class_instance_counts(g)?;
+        let predicate_usage = self.predicate_usage(g)?;
+
+        let datatype_distribution = self.term_counts(&format!(
+            r#"
+            SELECT (?datatype AS ?term) (COUNT(*) AS ?count) WHERE {{
+                GRAPH {g} {{ ?s ?p ?o }}
+                FILTER(isLiteral(?o))
+                BIND(DATATYPE(?o) AS ?datatype)
+            }}
+            GROUP BY ?datatype
+            ORDER BY DESC(?count) ?datatype
+            "#
+        ))?;
+
+        let language_distribution = self.term_counts(&format!(
+            r#"
+            SELECT (?language AS ?term) (COUNT(*) AS ?count) WHERE {{
+                GRAPH {g} {{ ?s ?p ?o }}
+                FILTER(isLiteral(?o) && LANG(?o) != "")
+                BIND(LANG(?o) AS ?language)
+            }}
+            GROUP BY ?language
+            ORDER BY DESC(?count) ?language
+            "#
+        ))?;
+
+        let property_cardinalities = self.property_cardinalities(g)?;
+        let example_values = self.example_values(g, top_n)?;
+
+        Ok(GraphProfile {
+            graph: graph.clone(),
+            triple_count,
+            class_instance_counts,
+            predicate_usage,
+            datatype_distribution,
+            language_distribution,
+            property_cardinalities,
+            example_values,
+        })
+    }
+
+    /// Returns the number of triples in the given graph.
+    pub(crate) fn triple_count(&self, g: &NamedNode) -> anyhow::Result<usize> {
+        Ok(self
+            .term_counts(&format!(
+                r#"
+                SELECT ("triples" AS ?term) (COUNT(*) AS ?count) WHERE {{
+                    GRAPH {g} {{ ?s ?p ?o }}
+                }}
+                "#
+            ))?
+            .first()
+            .map(|c| c.count)
+            .unwrap_or_default())
+    }
+
+    /// Returns the number of distinct instances per class in the
+    /// given graph, most used first.
+    pub(crate) fn class_instance_counts(
+        &self,
+        g: &NamedNode,
+    ) -> anyhow::Result<Vec<TermCount>> {
+        self.term_counts(&format!(
+            r#"
+            SELECT (?class AS ?term) (COUNT(DISTINCT ?s) AS ?count) WHERE {{
+                GRAPH {g} {{ ?s a ?class }}
+            }}
+            GROUP BY ?class
+            ORDER BY DESC(?count) ?class
+            "#
+        ))
+    }
+
+    /// Returns the number of triples per predicate in the given
+    /// graph, most used first.
+    pub(crate) fn predicate_usage(
+        &self,
+        g: &NamedNode,
+    ) -> anyhow::Result<Vec<TermCount>> {
+        self.term_counts(&format!(
+            r#"
+            SELECT (?p AS ?term) (COUNT(*) AS ?count) WHERE {{
+                GRAPH {g} {{ ?s ?p ?o }}
+            }}
+            GROUP BY ?p
+            ORDER BY DESC(?count) ?p
+            "#
+        ))
+    }
+
+    fn property_cardinalities(
+        &self,
+        graph: &NamedNode,
+    ) -> anyhow::Result<Vec<PropertyCardinality>> {
+        let query = format!(
+            r#"{RDF_PREFIX}
+            SELECT ?class ?property
+                (COUNT(?s) AS ?instances)
+                (MIN(?n) AS ?min)
+                (MAX(?n) AS ?max)
+            WHERE {{
+                {{
+                    SELECT ?class ?s ?property (COUNT(?o) AS ?n) WHERE {{
+                        {{
+                            SELECT DISTINCT ?class ?property WHERE {{
+                                GRAPH {graph} {{ ?x a ?class ; ?property ?y }}
+                                FILTER(?property != rdf:type)
+                            }}
+                        }}
+                        GRAPH {graph} {{ ?s a ?class }}
+                        OPTIONAL {{ GRAPH {graph} {{ ?s ?property ?o }} }}
+                    }}
+                    GROUP BY ?class ?s ?property
+                }}
+            }}
+            GROUP BY ?class ?property
+            ORDER BY ?class ?property
+            "#
+        );
+        let mut cardinalities = Vec::new();
+        for solution in self.solutions(&query)? {
+            let (Some(class), Some(property)) = (
+                iri_value(&solution, "class"),
+                iri_value(&solution, "property"),
+            ) else {
+                continue;
+            };
+            cardinalities.push(PropertyCardinality {
+                class,
+                property,
+                instances: count_value(&solution, "instances"),
+                min: count_value(&solution, "min"),
+                max: count_value(&solution, "max"),
+            });
+        }
+        Ok(cardinalities)
+    }
+
+    fn example_values(
+        &self,
+        graph: &NamedNode,
+        top_n: usize,
+    ) -> anyhow::Result<Vec<ExampleValues>> {
+        let query = format!(
+            r#"{RDF_PREFIX}
+            SELECT ?property ?value (COUNT(*) AS ?count) WHERE {{
+                GRAPH {graph} {{ ?s ?property ?value }}
+                FILTER(?property != rdf:type && !isBlank(?value))
+            }}
+            GROUP BY ?property ?value
+            ORDER BY ?property DESC(?count) ?value
+            "#
+        );
+        let mut by_property: BTreeMap<String, Vec<(Term, usize)>> =
+            BTreeMap::new();
+        for solution in self.solutions(&query)? {
+            let (Some(property), Some(value)) = (
+                iri_value(&solution, "property"),
+                solution.get("value"),
+            ) else {
+                continue;
+            };
+            let values = by_property.entry(property).or_default();
+            if values.len() < top_n {
+                values.push((value.clone(), count_value(&solution, "count")));
+            }
+        }
+        Ok(by_property
+            .into_iter()
+            .map(|(property, values)| ExampleValues { property, values })
+            .collect())
+    }
+
+    /// Runs a query that returns a `?term` and a `?count` column.
+    fn term_counts(&self, query: &str) -> anyhow::Result<Vec<TermCount>> {
+        Ok(self
+            .solutions(query)?
+            .iter()
+            .filter_map(|solution| {
+                let term = match solution.get("term")?.as_ref() {
+                    TermRef::NamedNode(n) => n.as_str().to_string(),
+                    TermRef::Literal(l) => l.value().to_string(),
+                    _ => return None,
+                };
+                Some(TermCount { term, count: count_value(solution, "count") })
+            })
+            .collect())
+    }
+}
+
+const RDF_PREFIX: &str =
+    "PREFIX rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#>\n";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_graph() -> anyhow::Result<()> {
+        let store = LoaderStore::new_in_memory()?;
+        let graph = store.load_turtle(
+            "urn:test:people",
+            r#"
+            @prefix ex: <http://example.com/> .
+            @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+            ex:alice a ex:Person ; ex:name "Alice"@en, "Alicia"@es ;
+                ex:age 42 .
+            ex:bob a ex:Person ; ex:name "Bob"@en .
+            ex:acme a ex:Company ; ex:name "ACME" .
+            "#,
+        )?;
+        let profile =
+            store.profile_graph(&DataGraph { graph, file_name: None }, 2)?;
+        let counts = |counts: &[TermCount]| -> Vec<(String, usize)> {
+            counts.iter().map(|c| (c.term.clone(), c.count)).collect()
+        };
+
+        assert_eq!(profile.triple_count, 8);
+        assert_eq!(counts(&profile.class_instance_counts), [
+            ("http://example.com/Person".to_string(), 2),
+            ("http://example.com/Company".to_string(), 1),
+        ]);
+        assert_eq!(counts(&profile.datatype_distribution), [
+            (
+                "http://www.w3.org/1999/02/22-rdf-syntax-ns#langString"
+                    .to_string(),
+                3
+            ),
+            (
+                "http://www.w3.org/2001/XMLSchema#integer".to_string(),
+                1
+            ),
+            (
+                "http://www.w3.org/2001/XMLSchema#string".to_string(),
+                1
+            ),
+        ]);
+        assert_eq!(counts(&profile.language_distribution), [
+            ("en".to_string(), 2),
+            ("es".to_string(), 1),
+        ]);
+
+        // Bob has no age, so the age of a person is optional
+        let cardinalities: Vec<(&str, &str, usize, usize, usize)> = profile
+            .property_cardinalities
+            .iter()
+            .map(|c| {
+                (
+                    c.class.as_str(),
+                    c.property.as_str(),
+                    c.instances,
+                    c.min,
+                    c.max,
+                )
+            })
+            .collect();
+        assert_eq!(cardinalities, [
+            (
+                "http://example.com/Company",
+                "http://example.com/name",
+                1,
+                1,
+                1
+            ),
+            (
+                "http://example.com/Person",
+                "http://example.com/age",
+                2,
+                0,
+                1
+            ),
+            (
+                "http://example.com/Person",
+                "http://example.com/name",
+                2,
+                1,
+                2
+            ),
+        ]);
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+use {
+    super::solution::{iri_value, literal_value},
+    crate::{
+        rdf_const::{
+            classes::OXI_CLASS_DCTERMS_LICENSE_DOCUMENT,
+            graphs::{OXI_GRAPH_FILE_REGISTRY, RDF_GRAPH_FILE_REGISTRY},
+            namespaces::NS_CC,
+            predicates::OXI_DCTERMS_LICENSE,
+        },
+        store::LoaderStore,
+        util::SPDXLicense,
+    },
+    const_format::concatcp,
+    oxrdf::NamedNode,
+    std::path::PathBuf,
+};
+
+const LICENSE_PREFIXES: &str = concatcp!(
+    "PREFIX rdfs: <http://www.w3.org/2000/01/rdf-schema#>\n",
+    "PREFIX dcterms: <http://purl.org/dc/terms/>\n",
+    "PREFIX fr: <https://ekgf.org/ontology/file-registry#>\n",
+    "PREFIX cc: <",
+    NS_CC,
+    ">\n",
+);
+
+/// A license file from the sources, such as `LICENSE` or `COPYING`.
+#[derive(Debug, Clone)]
+pub struct LicenseFile {
+    pub file_name: String,
+    /// The license as identified by the text of the file, if known
+    pub license:   Option<&'static SPDXLicense>,
+}
+
+/// A license that is declared in the data with `dcterms:license` or
+/// `cc:license`, typically in the header of an ontology.
+#[derive(Debug, Clone)]
+pub struct DeclaredLicense {
+    /// The name of the file that holds the declaration, if any
+    pub file_name: Option<String>,
+    /// The IRI of the thing that the license is declared for
+    pub subject:   String,
+    /// The object of the declaration, an IRI or a literal like `MIT`
+    pub reference: String,
+    /// The license as identified by the reference, if known
+    pub license:   Option<&'static SPDXLicense>,
+}
+
+impl LoaderStore {
+    /// Registers the given license file in the file registry as a
+    /// `dcterms:LicenseDocument` and, if the license can be identified
+    /// by its text, links it with `dcterms:license` to the license at
+    /// SPDX.
+    pub async fn register_license_file(
+        &self,
+        file_name: PathBuf,
+    ) -> anyhow::Result<Option<&'static SPDXLicense>> {
+        let (content, file_content_node) =
+            self.register_file(file_name).await?;
+        let graph_name = OXI_GRAPH_FILE_REGISTRY.clone();
+        self.insert_node_of_type(
+            file_content_node.clone(),
+            OXI_CLASS_DCTERMS_LICENSE_DOCUMENT.clone(),
+            graph_name.clone(),
+        )
+        .await?;
+        let license = SPDXLicense::from_text(&content);
+        if let Some(license) = license {
+            self.insert_quad2(
+                file_content_node,
+                OXI_DCTERMS_LICENSE.clone(),
+                NamedNode::new(license.url())?,
+                graph_name,
+            )
+            .await?;
+        }
+        Ok(license)
+    }
+
+    /// Returns the license files that were registered with
+    /// `register_license_file`, ordered by file name.
+    pub fn find_license_files(&self) -> anyhow::Result<Vec<LicenseFile>> {
+        let query = format!(
+            r#"{LICENSE_PREFIXES}
+            SELECT DISTINCT ?fileName ?license WHERE {{
+                GRAPH <{RDF_GRAPH_FILE_REGISTRY}> {{
+                    ?content a dcterms:LicenseDocument ;
+                        fr:isContentForFile ?file .
+                    ?file rdfs:label ?fileName .
+                    OPTIONAL {{ ?content dcterms:license ?license }}
+                }}
+            }}
+            ORDER BY ?fileName
+            "#
+        );
+        Ok(self
+            .solutions(&query)?
+            .iter()
+            .filter_map(|solution| {
+                Some(LicenseFile {
+                    file_name: literal_value(solution, "fileName")?,
+                    license:   iri_value(solution, "license")
+                        .and_then(|iri| SPDXLicense::from_reference(&iri)),
+                })
+            })
+            .collect())
+    }
+
+    /// Returns the licenses that are declared with `dcterms:license` or
+    /// `cc:license` in the loaded data, ordered by file name.
+    pub fn find_declared_licenses(
+        &self,
+    ) -> anyhow::Result<Vec<DeclaredLicense>> {
+        let query = format!(
+            r#"{LICENSE_PREFIXES}
+            SELECT DISTINCT ?fileName ?subject ?license WHERE {{
+                GRAPH ?graph {{
+                    ?subject dcterms:license|cc:license ?license
+                }}
+                FILTER(!STRSTARTS(STR(?graph), "urn:GraphArch:"))
+                FILTER(isIRI(?subject))
+                OPTIONAL {{
+                    GRAPH <{RDF_GRAPH_FILE_REGISTRY}> {{
+                        ?graph fr:isContentForFile ?file .
+                        ?file rdfs:label ?fileName .
+                    }}
+                }}
+            }}
+            ORDER BY ?fileName ?subject ?license
+            "#
+        );
+        Ok(self
+            .solutions(&query)?
+            .iter()
+            .filter_map(|solution| {
+                let reference = iri_value(solution, "license")
+                    .or_else(|| literal_value(solution, "license"))?;
+                Some(DeclaredLicense {
+                    file_name: literal_value(solution, "fileName"),
+                    subject: iri_value(solution, "subject")?,
+                    license: SPDXLicense::from_reference(&reference),
+                    reference,
+                })
+            })
+            .collect())
+    }
+}
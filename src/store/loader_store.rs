@@ -1,28 +1,27 @@
 use {
-    super::file_registry::FileRegistryWriter,
+    super::{
+        PrefixMapping,
+        PrefixOrigin,
+        PrefixRegistry,
+        file_registry::FileRegistryWriter,
+    },
     crate::rdf_const::{
-        graphs::OXI_GRAPH_FILE_REGISTRY,
-        predicates::OXI_RDF_TYPE,
+        graphs::{
+            OXI_GRAPH_FILE_REGISTRY,
+            OXI_GRAPH_PREFIX_REGISTRY,
+            RDF_GRAPH_PREFIX_REGISTRY,
+        },
+        predicates::{OXI_RDF_TYPE, OXI_VANN_PREFERRED_NAMESPACE_PREFIX},
     },
     oxigraph::{
         model::{GraphName, NamedNode, Quad},
+        sparql::{Query, QueryResults, QuerySolution},
         store::Store,
     },
-    oxrdf::{Subject, Term, TermRef},
+    oxrdf::{Literal, Subject, Term, TermRef},
     std::{path::PathBuf, sync::Arc},
 };
 
-/// Represents an OWL class from the source data
-#[derive(Debug, Clone)]
-pub struct OWLClass {
-    /// The IRI of the OWL class
-    pub iri:     String,
-    /// The human-readable label of the class
-    pub label:   Option<String>,
-    /// A description or comment about the class
-    pub comment: Option<String>,
-}
-
 // Define the named graph URI
 #[allow(unused)]
 const FILE_REGISTRY_GRAPH: &str = "urn:GraphArch:file-registry";
@@ -120,67 +119,165 @@ impl LoaderStore {
         Ok(())
     }
 
-    /// Find all OWL classes in the store and return them as OWLClass structs.
-    pub fn find_owl_classes(&self) -> anyhow::Result<Vec<OWLClass>> {
-        let query = r#"
-            PREFIX owl: <http://www.w3.org/2002/07/owl#>
-            PREFIX rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#>
-            PREFIX rdfs: <http://www.w3.org/2000/01/rdf-schema#>
-
-            SELECT DISTINCT ?class ?label ?comment
-            WHERE {
-                ?class a owl:Class .
-                OPTIONAL { ?class rdfs:label ?label }
-                OPTIONAL { ?class rdfs:comment ?comment }
+    /// Executes the given SPARQL query against the store.
+    ///
+    /// The default graph of the query is the union of all named
+    /// graphs, since every loaded file ends up in a named graph and
+    /// callers should not have to know which one.
+    pub fn query(&self, sparql: &str) -> anyhow::Result<QueryResults> {
+        let mut query = Query::parse(sparql, None)?;
+        query.dataset_mut().set_default_graph_as_union();
+        Ok(self.store.query(query)?)
+    }
+
+    /// Executes the given SPARQL SELECT query (see `query`) and
+    /// returns all of its solutions.
+    pub(crate) fn solutions(
+        &self,
+        sparql: &str,
+    ) -> anyhow::Result<Vec<QuerySolution>> {
+        match self.query(sparql)? {
+            QueryResults::Solutions(solutions) => {
+                Ok(solutions.collect::<Result<Vec<_>, _>>()?)
+            },
+            _ => Err(anyhow::anyhow!("Unexpected query results type")),
+        }
+    }
+
+    /// Registers a prefix declaration that was found in one of the
+    /// sources in the prefix registry graph.
+    pub async fn register_prefix(
+        &self,
+        prefix: &str,
+        namespace: &str,
+    ) -> anyhow::Result<()> {
+        self.insert_quad2(
+            NamedNode::new(namespace)?,
+            OXI_VANN_PREFERRED_NAMESPACE_PREFIX.clone(),
+            Literal::new_simple_literal(prefix),
+            OXI_GRAPH_PREFIX_REGISTRY.clone(),
+        )
+        .await
+    }
+
+    /// Returns the `PrefixRegistry` with all the prefixes that are
+    /// known in this store: the `vann:preferredNamespacePrefix`
+    /// declarations in the data, the prefixes declared in the
+    /// sources (see `register_prefix`) and the well-known prefixes.
+    pub fn prefix_registry(&self) -> anyhow::Result<PrefixRegistry> {
+        let mut registry = PrefixRegistry::with_well_known_prefixes();
+
+        let preferred = r#"
+            PREFIX vann: <http://purl.org/vocab/vann/>
+            SELECT DISTINCT ?prefix (STR(?ns) AS ?namespace) WHERE {
+                ?ontology vann:preferredNamespacePrefix ?prefix ;
+                          vann:preferredNamespaceUri ?ns .
             }
-            ORDER BY ?class
+            ORDER BY ?prefix
         "#;
+        for (prefix, namespace) in self.query_prefixes(preferred)? {
+            registry.add(&prefix, &namespace, PrefixOrigin::Preferred);
+        }
 
-        let results = self.store.query(query)?;
-        let mut classes = Vec::new();
-
-        match results {
-            oxigraph::sparql::QueryResults::Solutions(solutions) => {
-                for solution in solutions {
-                    let solution = solution?;
-
-                    let class = solution
-                        .get("class")
-                        .and_then(|t| {
-                            match t.as_ref() {
-                                TermRef::NamedNode(n) => Some(n),
-                                _ => None,
-                            }
-                        })
-                        .ok_or_else(|| {
-                            anyhow::anyhow!("Expected named node for class")
-                        })?;
-
-                    let label = solution.get("label").and_then(|t| {
-                        match t.as_ref() {
-                            TermRef::Literal(l) => Some(l.value().to_string()),
-                            _ => None,
-                        }
-                    });
+        let declared = format!(
+            r#"
+            PREFIX vann: <http://purl.org/vocab/vann/>
+            SELECT DISTINCT ?prefix (STR(?ns) AS ?namespace) WHERE {{
+                GRAPH <{}> {{
+                    ?ns vann:preferredNamespacePrefix ?prefix .
+                }}
+            }}
+            ORDER BY ?namespace ?prefix
+            "#,
+            RDF_GRAPH_PREFIX_REGISTRY
+        );
+        for (prefix, namespace) in self.query_prefixes(&declared)? {
+            registry.add(&prefix, &namespace, PrefixOrigin::Source);
+        }
+
+        Ok(registry)
+    }
 
-                    let comment = solution.get("comment").and_then(|t| {
+    /// Returns the mappings of the given registry whose namespace is
+    /// used by an IRI in the given graph, ordered by prefix. An IRI
+    /// uses the mapping with the longest matching namespace only, see
+    /// `PrefixRegistry::mapping_for_iri`.
+    pub fn used_namespaces(
+        &self,
+        graph: &NamedNode,
+        registry: &PrefixRegistry,
+    ) -> anyhow::Result<Vec<PrefixMapping>> {
+        let query = format!(
+            r#"
+            SELECT DISTINCT ?iri WHERE {{
+                GRAPH {graph} {{
+                    {{ ?iri ?p ?o }} UNION {{ ?s ?iri ?o }} UNION {{ ?s ?p ?iri }}
+                }}
+                FILTER(isIRI(?iri))
+            }}
+            "#
+        );
+        let mut used: Vec<PrefixMapping> = Vec::new();
+        for solution in self.solutions(&query)? {
+            let Some(Term::NamedNode(iri)) = solution.get("iri") else {
+                continue;
+            };
+            if let Some(mapping) = registry.mapping_for_iri(iri.as_str()) {
+                if !used.contains(mapping) {
+                    used.push(mapping.clone());
+                }
+            }
+        }
+        used.sort_by(|a, b| a.prefix.cmp(&b.prefix));
+        Ok(used)
+    }
+
+    /// Runs a query that returns `?prefix` and `?namespace` literals.
+    fn query_prefixes(
+        &self,
+        query: &str,
+    ) -> anyhow::Result<Vec<(String, String)>> {
+        let mut prefixes = Vec::new();
+        if let QueryResults::Solutions(solutions) = self.query(query)? {
+            for solution in solutions {
+                let solution = solution?;
+                let value = |name: &str| {
+                    solution.get(name).and_then(|t| {
                         match t.as_ref() {
                             TermRef::Literal(l) => Some(l.value().to_string()),
                             _ => None,
                         }
-                    });
-
-                    classes.push(OWLClass {
-                        iri: class.to_string(),
-                        label,
-                        comment,
-                    });
+                    })
+                };
+                if let (Some(prefix), Some(namespace)) =
+                    (value("prefix"), value("namespace"))
+                {
+                    prefixes.push((prefix, namespace));
                 }
-            },
-            _ => return Err(anyhow::anyhow!("Unexpected query results type")),
+            }
         }
+        Ok(prefixes)
+    }
+}
 
-        Ok(classes)
+#[cfg(test)]
+impl LoaderStore {
+    /// Loads the given Turtle into the named graph with the given IRI
+    /// and returns the graph, like a loader does for a file.
+    pub(crate) fn load_turtle(
+        &self,
+        graph: &str,
+        turtle: &str,
+    ) -> anyhow::Result<NamedNode> {
+        let graph = NamedNode::new(graph)?;
+        self.store.load_from_reader(
+            oxigraph::io::RdfParser::from_format(
+                oxigraph::io::RdfFormat::Turtle,
+            )
+            .with_default_graph(graph.clone()),
+            turtle.as_bytes(),
+        )?;
+        Ok(graph)
     }
 }
 
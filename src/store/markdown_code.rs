@@ -0,0 +1,208 @@
+use {
+    crate::{
+        rdf_const::{
+            data_types::OXI_RDF_XSD_INTEGER,
+            graphs::OXI_GRAPH_FILE_REGISTRY,
+            namespaces::NS_IANA_MEDIA_TYPES,
+            predicates::{
+                OXI_DCAT_MEDIA_TYPE,
+                OXI_FILE_REGISTRY_LINE_NUMBER,
+                OXI_PROV_WAS_DERIVED_FROM,
+            },
+        },
+        store::LoaderStore,
+        util::MarkdownCodeBlock,
+    },
+    oxrdf::{Literal, NamedNode, Quad},
+    oxrdfio::RdfParser,
+    std::path::PathBuf,
+    tracing::warn,
+};
+
+impl LoaderStore {
+    /// Loads the RDF code blocks (Turtle, TriG, JSON-LD and the like)
+    /// of the given Markdown file into the store, each block in its
+    /// own named graph. The name of the graph is the file content node
+    /// (see `register_file`) with the line number of the block as its
+    /// fragment, like `urn:sha256:...#line-12`. It is also the base IRI
+    /// of the relative IRIs of the block, and the named graphs of a
+    /// TriG or N-Quads block are merged into it.
+    ///
+    /// The file registry records where each graph comes from with
+    /// `prov:wasDerivedFrom` (the file content node),
+    /// `fr:lineNumber` and `dcat:mediaType`. Blocks that are not
+    /// valid are reported with their file name and line number and
+    /// skipped.
+    ///
+    /// Returns the names of the graphs that were loaded.
+    pub async fn load_markdown_code_blocks(
+        &self,
+        file_name: PathBuf,
+    ) -> anyhow::Result<Vec<NamedNode>> {
+        let content = tokio::fs::read_to_string(&file_name).await?;
+        let blocks: Vec<MarkdownCodeBlock> =
+            MarkdownCodeBlock::extract(&content)
+                .into_iter()
+                .filter(|block| block.rdf_format().is_some())
+                .collect();
+        if blocks.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let (_, file_content_node) =
+            self.register_file(file_name.clone()).await?;
+        let registry = OXI_GRAPH_FILE_REGISTRY.clone();
+        let mut graphs = Vec::new();
+        for block in blocks {
+            let Some(format) = block.rdf_format() else {
+                continue;
+            };
+            let graph = NamedNode::new(format!(
+                "{}#line-{}",
+                file_content_node.as_str(),
+                block.line
+            ))?;
+            let mut parser = RdfParser::from_format(format)
+                .with_base_iri(graph.as_str())?
+                .with_default_graph(graph.as_ref())
+                .for_reader(block.code.as_bytes());
+            let quads: Result<Vec<_>, _> = parser
+                .by_ref()
+                .map(|quad| {
+                    quad.map(|quad| {
+                        Quad::new(
+                            quad.subject,
+                            quad.predicate,
+                            quad.object,
+                            graph.clone(),
+                        )
+                    })
+                })
+                .collect();
+            let quads = match quads {
+                Ok(quads) => quads,
+                Err(error) => {
+                    warn!(
+                        "Skipping the {} code block at {}:{}: {}",
+                        block.language,
+                        file_name.display(),
+                        block.line,
+                        error
+                    );
+                    continue;
+                },
+            };
+            let prefixes: Vec<(String, String)> = parser
+                .prefixes()
+                .map(|(prefix, namespace)| {
+                    (prefix.to_string(), namespace.to_string())
+                })
+                .collect();
+            self.store().bulk_loader().load_quads(quads)?;
+            for (prefix, namespace) in prefixes {
+                if let Err(err) =
+                    self.register_prefix(&prefix, &namespace).await
+                {
+                    warn!(
+                        "Ignoring prefix {}: <{}> of {}:{}: {}",
+                        prefix,
+                        namespace,
+                        file_name.display(),
+                        block.line,
+                        err
+                    );
+                }
+            }
+
+            self.insert_quad2(
+                graph.clone(),
+                OXI_PROV_WAS_DERIVED_FROM.clone(),
+                file_content_node.clone(),
+                registry.clone(),
+            )
+            .await?;
+            self.insert_quad2(
+                graph.clone(),
+                OXI_FILE_REGISTRY_LINE_NUMBER.clone(),
+                Literal::new_typed_literal(
+                    block.line.to_string(),
+                    OXI_RDF_XSD_INTEGER.clone(),
+                ),
+                registry.clone(),
+            )
+            .await?;
+            self.insert_quad2(
+                graph.clone(),
+                OXI_DCAT_MEDIA_TYPE.clone(),
+                NamedNode::new(format!(
+                    "{}{}",
+                    NS_IANA_MEDIA_TYPES,
+                    format.media_type()
+                ))?,
+                registry.clone(),
+            )
+            .await?;
+            graphs.push(graph);
+        }
+        Ok(graphs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, oxigraph::model::NamedNodeRef};
+
+    const PAGE: &str = r#"# Examples
+
+```turtle
+@prefix ex: <http://example.com/> .
+<#car> a ex:Car .
+```
+
+```trig
+@prefix ex: <http://example.com/> .
+ex:bike a ex:Bike .
+GRAPH ex:g { ex:boat a ex:Boat . }
+```
+"#;
+
+    #[tokio::test]
+    async fn test_load_markdown_code_blocks() -> anyhow::Result<()> {
+        let file_name =
+            std::env::temp_dir().join("grapharch-markdown-code-test.md");
+        std::fs::write(&file_name, PAGE)?;
+        let store = LoaderStore::new_in_memory()?;
+        let graphs = store.load_markdown_code_blocks(file_name.clone()).await;
+        std::fs::remove_file(&file_name)?;
+        let graphs = graphs?;
+        assert_eq!(graphs.len(), 2);
+        assert!(graphs[0].as_str().ends_with("#line-3"));
+
+        let subjects = |graph: &NamedNode| -> Vec<String> {
+            store
+                .store()
+                .quads_for_pattern(None, None, None, Some(graph.into()))
+                .map(|quad| quad.unwrap().subject.to_string())
+                .collect()
+        };
+        // Relative IRIs are resolved against the graph of the block
+        let car = graphs[0].as_str().replace("#line-3", "#car");
+        assert_eq!(subjects(&graphs[0]), [format!("<{car}>")]);
+        // The named graphs of a TriG block are merged into its graph
+        let mut trig = subjects(&graphs[1]);
+        trig.sort();
+        assert_eq!(trig, [
+            "<http://example.com/bike>",
+            "<http://example.com/boat>"
+        ]);
+        let named = NamedNodeRef::new("http://example.com/g")?;
+        assert!(
+            store
+                .store()
+                .quads_for_pattern(None, None, None, Some(named.into()))
+                .next()
+                .is_none()
+        );
+        Ok(())
+    }
+}
@@ -1,3 +1,60 @@
+mod dataset_description;
+mod ekgf;
 mod file_registry;
+mod graph_profile;
+mod license;
 mod loader_store;
-pub use loader_store::LoaderStore;
+mod markdown_code;
+mod owl;
+mod prefix_registry;
+mod shacl;
+mod skos;
+mod solution;
+mod traversal;
+
+pub use {
+    dataset_description::DATASET_DESCRIPTION_FILE_NAME,
+    ekgf::{EKGFDescription, EKGFKind, EKGFRelation, EKGFThing},
+    file_registry::RegisteredFile,
+    graph_profile::{
+        DataGraph,
+        ExampleValues,
+        GraphProfile,
+        PropertyCardinality,
+        TermCount,
+    },
+    license::{DeclaredLicense, LicenseFile},
+    loader_store::LoaderStore,
+    owl::{
+        AnnotationValue,
+        EnumerationMember,
+        ManchesterRenderer,
+        OWLClass,
+        OWLClassDescription,
+        OWLIndividual,
+        OWLIndividualDescription,
+        OWLOntology,
+        OWLProperty,
+        OWLPropertyDescription,
+        OWLPropertyKind,
+        PropertyValue,
+    },
+    prefix_registry::{PrefixMapping, PrefixOrigin, PrefixRegistry},
+    shacl::{
+        NodeShape,
+        PropertyShape,
+        ShaclPath,
+        ShaclViolation,
+        ShapeTarget,
+        read_node_shapes,
+        validate_shapes,
+    },
+    skos::{
+        ConceptHierarchy,
+        SKOSCollection,
+        SKOSConcept,
+        SKOSConceptScheme,
+        SKOSConceptSchemeDescription,
+        SKOSMapping,
+    },
+};
@@ -0,0 +1,440 @@
+use {
+    super::{EnumerationMember, ManchesterRenderer},
+    crate::{
+        store::{LoaderStore, PrefixRegistry, solution::iri_value},
+        util::{Localized, LocalizedText},
+    },
+    oxigraph::sparql::QuerySolution,
+    oxrdf::{NamedNode, Term, TermRef},
+};
+
+/// Represents an OWL class from the source data
+#[derive(Debug, Clone)]
+pub struct OWLClass {
+    /// The IRI of the OWL class
+    pub iri:      String,
+    /// The human-readable labels of the class, in all languages
+    pub labels:   Vec<LocalizedText>,
+    /// The descriptions or comments about the class, in all languages
+    pub comments: Vec<LocalizedText>,
+}
+
+/// A resource with its `rdfs:label`s and `rdfs:comment`s in all
+/// languages, see `LoaderStore::labelled_resources`.
+pub(crate) struct LabelledResource {
+    pub iri:      String,
+    pub labels:   Vec<LocalizedText>,
+    pub comments: Vec<LocalizedText>,
+}
+
+/// A literal value of an annotation property, such as a label or a
+/// definition.
+#[derive(Debug, Clone)]
+pub struct AnnotationValue {
+    /// The IRI of the annotation property
+    pub property: String,
+    /// The lexical value of the literal
+    pub value:    String,
+    /// The language tag of the literal, if any
+    pub language: Option<String>,
+}
+
+impl Localized for AnnotationValue {
+    fn language(&self) -> Option<&str> { self.language.as_deref() }
+}
+
+/// Everything that the ontology says about one OWL class, see
+/// `LoaderStore::describe_owl_class`.
+///
+/// Named classes and properties are given as IRIs, anonymous class
+/// expressions are rendered in Manchester syntax.
+#[derive(Debug, Clone)]
+pub struct OWLClassDescription {
+    pub class:                 OWLClass,
+    /// `rdfs:label`, `skos:prefLabel` and `skos:altLabel` values
+    pub labels:                Vec<AnnotationValue>,
+    /// `skos:definition`, `rdfs:comment` and `IAO:0000115` values
+    pub definitions:           Vec<AnnotationValue>,
+    pub direct_superclasses:   Vec<String>,
+    pub indirect_superclasses: Vec<String>,
+    pub direct_subclasses:     Vec<String>,
+    pub indirect_subclasses:   Vec<String>,
+    /// The `owl:equivalentClass` classes, in Manchester syntax
+    pub equivalent_classes:    Vec<String>,
+    /// The `owl:disjointWith` and `owl:AllDisjointClasses` classes, in
+    /// Manchester syntax
+    pub disjoint_classes:      Vec<String>,
+    /// The anonymous superclasses (mostly `owl:Restriction`s), in
+    /// Manchester syntax
+    pub restrictions:          Vec<String>,
+    /// The properties that have the class as their `rdfs:domain`
+    pub domain_of:             Vec<String>,
+    /// The properties that have the class as their `rdfs:range`
+    pub range_of:              Vec<String>,
+    /// The members of the `owl:oneOf` enumeration that defines the
+    /// class, if any
+    pub enumeration:           Vec<EnumerationMember>,
+}
+
+pub(super) const OWL_PREFIXES: &str = r#"
+    PREFIX owl: <http://www.w3.org/2002/07/owl#>
+    PREFIX rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#>
+    PREFIX rdfs: <http://www.w3.org/2000/01/rdf-schema#>
+    PREFIX skos: <http://www.w3.org/2004/02/skos/core#>
+    PREFIX obo: <http://purl.obolibrary.org/obo/>
+"#;
+
+impl LoaderStore {
+    /// Find all OWL classes in the given graph, or in all graphs if no
+    /// graph is given, and return them as OWLClass structs.
+    pub fn find_owl_classes(
+        &self,
+        graph: Option<&NamedNode>,
+    ) -> anyhow::Result<Vec<OWLClass>> {
+        Ok(self
+            .labelled_resources("?resource a owl:Class .", graph)?
+            .into_iter()
+            .map(|resource| {
+                OWLClass {
+                    iri:      resource.iri,
+                    labels:   resource.labels,
+                    comments: resource.comments,
+                }
+            })
+            .collect())
+    }
+
+    /// Returns all the axioms and annotations of the given class,
+    /// rendering anonymous class expressions with the given
+    /// prefixes.
+    pub fn describe_owl_class(
+        &self,
+        class: &OWLClass,
+        prefixes: &PrefixRegistry,
+    ) -> anyhow::Result<OWLClassDescription> {
+        let iri = &class.iri;
+        let store = self.store();
+        let renderer = ManchesterRenderer::new(&store, prefixes);
+        let render = |terms: Vec<Term>| -> Vec<String> {
+            terms.iter().map(|t| renderer.render(t)).collect()
+        };
+
+        Ok(OWLClassDescription {
+            class:                 class.clone(),
+            labels:                self.annotation_values(
+                iri,
+                "rdfs:label skos:prefLabel skos:altLabel",
+            )?,
+            definitions:           self.annotation_values(
+                iri,
+                "skos:definition rdfs:comment obo:IAO_0000115",
+            )?,
+            direct_superclasses:   self.iris(&format!(
+                "<{iri}> rdfs:subClassOf ?term . FILTER(isIRI(?term))"
+            ))?,
+            indirect_superclasses: self.iris(&format!(
+                "<{iri}> rdfs:subClassOf/rdfs:subClassOf+ ?term .
+                 FILTER(isIRI(?term) && ?term != <{iri}>)
+                 FILTER NOT EXISTS {{ <{iri}> rdfs:subClassOf ?term }}"
+            ))?,
+            direct_subclasses:     self.iris(&format!(
+                "?term rdfs:subClassOf <{iri}> . FILTER(isIRI(?term))"
+            ))?,
+            indirect_subclasses:   self.iris(&format!(
+                "?term rdfs:subClassOf+/rdfs:subClassOf <{iri}> .
+                 FILTER(isIRI(?term) && ?term != <{iri}>)
+                 FILTER NOT EXISTS {{ ?term rdfs:subClassOf <{iri}> }}"
+            ))?,
+            equivalent_classes:    render(self.terms(&format!(
+                "{{ <{iri}> owl:equivalentClass ?term }}
+                 UNION {{ ?term owl:equivalentClass <{iri}> }}
+                 FILTER(?term != <{iri}>)"
+            ))?),
+            disjoint_classes:      render(self.terms(&format!(
+                "{{ <{iri}> owl:disjointWith ?term }}
+                 UNION {{ ?term owl:disjointWith <{iri}> }}
+                 UNION {{
+                     ?all a owl:AllDisjointClasses ;
+                          owl:members ?members .
+                     ?members rdf:rest*/rdf:first <{iri}> .
+                     ?members rdf:rest*/rdf:first ?term .
+                 }}
+                 FILTER(?term != <{iri}>)"
+            ))?),
+            restrictions:          render(self.terms(&format!(
+                "<{iri}> rdfs:subClassOf ?term . FILTER(isBlank(?term))"
+            ))?),
+            domain_of:             self.iris(&format!(
+                "?term rdfs:domain <{iri}> . FILTER(isIRI(?term))"
+            ))?,
+            range_of:              self.iris(&format!(
+                "?term rdfs:range <{iri}> . FILTER(isIRI(?term))"
+            ))?,
+            enumeration:           self.owl_enumeration(iri, prefixes)?,
+        })
+    }
+
+    /// Returns the resources (with an IRI) that the given graph pattern
+    /// binds to `?resource` in the given graph, or in all graphs if no
+    /// graph is given, ordered by IRI, with their labels and comments
+    /// in all languages.
+    pub(crate) fn labelled_resources(
+        &self,
+        pattern: &str,
+        graph: Option<&NamedNode>,
+    ) -> anyhow::Result<Vec<LabelledResource>> {
+        let pattern = match graph {
+            Some(graph) => format!("GRAPH {graph} {{ {pattern} }}"),
+            None => pattern.to_string(),
+        };
+        let query = format!(
+            r#"{OWL_PREFIXES}
+            SELECT ?resource ?label ?comment WHERE {{
+                {{
+                    SELECT DISTINCT ?resource WHERE {{
+                        {pattern}
+                        FILTER(isIRI(?resource))
+                    }}
+                }}
+                OPTIONAL {{
+                    {{ ?resource rdfs:label ?label }}
+                    UNION
+                    {{ ?resource rdfs:comment ?comment }}
+                }}
+            }}
+            ORDER BY ?resource ?label ?comment
+            "#
+        );
+        let mut resources: Vec<LabelledResource> = Vec::new();
+        for solution in self.solutions(&query)? {
+            let Some(iri) = iri_value(&solution, "resource") else {
+                continue;
+            };
+            if resources.last().is_none_or(|resource| resource.iri != iri) {
+                resources.push(LabelledResource {
+                    iri,
+                    labels: Vec::new(),
+                    comments: Vec::new(),
+                });
+            }
+            let resource = resources.last_mut().unwrap();
+            if let Some(label) = localized_value(&solution, "label") {
+                resource.labels.push(label);
+            }
+            if let Some(comment) = localized_value(&solution, "comment") {
+                resource.comments.push(comment);
+            }
+        }
+        Ok(resources)
+    }
+
+    /// Returns the literal values of the given (space separated)
+    /// annotation properties of the given subject.
+    pub(crate) fn annotation_values(
+        &self,
+        iri: &str,
+        properties: &str,
+    ) -> anyhow::Result<Vec<AnnotationValue>> {
+        let query = format!(
+            r#"{OWL_PREFIXES}
+            SELECT DISTINCT ?property ?value WHERE {{
+                VALUES ?property {{ {properties} }}
+                <{iri}> ?property ?value .
+                FILTER(isLiteral(?value))
+            }}
+            ORDER BY ?property ?value
+            "#
+        );
+        Ok(self
+            .solutions(&query)?
+            .iter()
+            .filter_map(|solution| {
+                let Some(TermRef::Literal(value)) =
+                    solution.get("value").map(|t| t.as_ref())
+                else {
+                    return None;
+                };
+                Some(AnnotationValue {
+                    property: iri_value(solution, "property")?,
+                    value:    value.value().to_string(),
+                    language: value.language().map(|l| l.to_string()),
+                })
+            })
+            .collect())
+    }
+
+    /// Returns the distinct IRIs bound to `?term` by the given graph
+    /// pattern.
+    pub(crate) fn iris(&self, pattern: &str) -> anyhow::Result<Vec<String>> {
+        Ok(self
+            .term_solutions(pattern)?
+            .iter()
+            .filter_map(|solution| iri_value(solution, "term"))
+            .collect())
+    }
+
+    /// Returns the distinct terms bound to `?term` by the given graph
+    /// pattern.
+    pub(crate) fn terms(&self, pattern: &str) -> anyhow::Result<Vec<Term>> {
+        Ok(self
+            .term_solutions(pattern)?
+            .iter()
+            .filter_map(|solution| solution.get("term").cloned())
+            .collect())
+    }
+
+    fn term_solutions(
+        &self,
+        pattern: &str,
+    ) -> anyhow::Result<Vec<QuerySolution>> {
+        self.solutions(&format!(
+            "{OWL_PREFIXES}
+            SELECT DISTINCT ?term WHERE {{ {pattern} }}
+            ORDER BY ?term"
+        ))
+    }
+}
+
+/// Returns the literal bound to the given variable with its language
+/// tag, if any.
+pub(crate) fn localized_value(
+    solution: &QuerySolution,
+    name: &str,
+) -> Option<LocalizedText> {
+    match solution.get(name)?.as_ref() {
+        TermRef::Literal(literal) => {
+            Some(LocalizedText {
+                value:    literal.value().to_string(),
+                language: literal.language().map(|l| l.to_string()),
+            })
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::store::PrefixOrigin};
+
+    const VEHICLES: &str = r#"
+        @prefix ex: <http://example.com/> .
+        @prefix owl: <http://www.w3.org/2002/07/owl#> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+        @prefix skos: <http://www.w3.org/2004/02/skos/core#> .
+
+        ex:Vehicle a owl:Class ; rdfs:label "Vehicle"@en .
+        ex:Car a owl:Class ;
+            rdfs:label "Car"@en, "Auto"@nl ;
+            skos:altLabel "Automobile"@en ;
+            rdfs:comment "A car."@en ;
+            skos:definition "A road vehicle with four wheels."@en ;
+            rdfs:subClassOf ex:MotorVehicle, [
+                a owl:Restriction ;
+                owl:onProperty ex:hasPart ;
+                owl:someValuesFrom ex:Wheel
+            ] ;
+            owl:equivalentClass ex:Automobile ;
+            owl:disjointWith ex:Bike .
+        ex:MotorVehicle a owl:Class ; rdfs:subClassOf ex:Vehicle .
+        ex:SportsCar a owl:Class ; rdfs:subClassOf ex:Car .
+        ex:Bike a owl:Class .
+        ex:Boat a owl:Class .
+        [] a owl:AllDisjointClasses ; owl:members ( ex:Car ex:Boat ) .
+        ex:hasPart rdfs:domain ex:Car .
+    "#;
+
+    fn store() -> (LoaderStore, PrefixRegistry, NamedNode) {
+        let store = LoaderStore::new_in_memory().unwrap();
+        let graph = store.load_turtle("urn:test:vehicles", VEHICLES).unwrap();
+        store
+            .load_turtle(
+                "urn:test:other",
+                "<http://example.com/Other> a \
+                 <http://www.w3.org/2002/07/owl#Class> .",
+            )
+            .unwrap();
+        let mut prefixes = PrefixRegistry::with_well_known_prefixes();
+        prefixes.add("ex", "http://example.com/", PrefixOrigin::Source);
+        (store, prefixes, graph)
+    }
+
+    fn iris(classes: &[OWLClass]) -> Vec<&str> {
+        classes.iter().map(|class| class.iri.as_str()).collect()
+    }
+
+    #[test]
+    fn test_find_owl_classes() -> anyhow::Result<()> {
+        let (store, _, graph) = store();
+        assert_eq!(iris(&store.find_owl_classes(None)?), [
+            "http://example.com/Bike",
+            "http://example.com/Boat",
+            "http://example.com/Car",
+            "http://example.com/MotorVehicle",
+            "http://example.com/Other",
+            "http://example.com/SportsCar",
+            "http://example.com/Vehicle",
+        ]);
+        let classes = store.find_owl_classes(Some(&graph))?;
+        assert_eq!(iris(&classes), [
+            "http://example.com/Bike",
+            "http://example.com/Boat",
+            "http://example.com/Car",
+            "http://example.com/MotorVehicle",
+            "http://example.com/SportsCar",
+            "http://example.com/Vehicle",
+        ]);
+        let labels: Vec<(&str, Option<&str>)> = classes[2]
+            .labels
+            .iter()
+            .map(|l| (l.value.as_str(), l.language.as_deref()))
+            .collect();
+        assert_eq!(labels, [
+            ("Auto", Some("nl")),
+            ("Car", Some("en"))
+        ]);
+        assert_eq!(classes[2].comments[0].value, "A car.");
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_owl_class() -> anyhow::Result<()> {
+        let (store, prefixes, graph) = store();
+        let classes = store.find_owl_classes(Some(&graph))?;
+        let car = store.describe_owl_class(&classes[2], &prefixes)?;
+
+        let annotations = |values: &[AnnotationValue]| -> Vec<String> {
+            values.iter().map(|v| v.value.clone()).collect()
+        };
+        assert_eq!(annotations(&car.labels), [
+            "Auto",
+            "Car",
+            "Automobile"
+        ]);
+        assert_eq!(annotations(&car.definitions), [
+            "A car.",
+            "A road vehicle with four wheels."
+        ]);
+        assert_eq!(car.direct_superclasses, [
+            "http://example.com/MotorVehicle"
+        ]);
+        assert_eq!(car.indirect_superclasses, [
+            "http://example.com/Vehicle"
+        ]);
+        assert_eq!(car.direct_subclasses, [
+            "http://example.com/SportsCar"
+        ]);
+        assert!(car.indirect_subclasses.is_empty());
+        assert_eq!(car.equivalent_classes, ["ex:Automobile"]);
+        assert_eq!(car.disjoint_classes, ["ex:Bike", "ex:Boat"]);
+        assert_eq!(car.restrictions, ["ex:hasPart some ex:Wheel"]);
+        assert_eq!(car.domain_of, ["http://example.com/hasPart"]);
+        assert!(car.range_of.is_empty());
+
+        let vehicle = store.describe_owl_class(&classes[5], &prefixes)?;
+        assert_eq!(vehicle.indirect_subclasses, [
+            "http://example.com/Car",
+            "http://example.com/SportsCar"
+        ]);
+        Ok(())
+    }
+}
@@ -0,0 +1,357 @@
+use {
+    super::{
+        AnnotationValue,
+        ManchesterRenderer,
+        class::{OWL_PREFIXES, localized_value},
+    },
+    crate::{
+        store::{LoaderStore, PrefixRegistry, solution::iri_value},
+        util::{Languages, LocalizedText},
+    },
+    oxrdf::{NamedNode, NamedNodeRef, Term, TermRef},
+};
+
+/// Represents an `owl:NamedIndividual` from the source data
+#[derive(Debug, Clone)]
+pub struct OWLIndividual {
+    /// The IRI of the individual
+    pub iri:      String,
+    /// The human-readable labels of the individual, in all languages
+    pub labels:   Vec<LocalizedText>,
+    /// The descriptions or comments about the individual, in all
+    /// languages
+    pub comments: Vec<LocalizedText>,
+}
+
+/// An asserted property value of an individual, with the labels of
+/// the resource that the value refers to, see `PropertyValue::label`.
+#[derive(Debug, Clone)]
+pub struct PropertyValue {
+    /// The IRI of the property
+    pub property: String,
+    /// The rendered value
+    pub value:    String,
+    /// The labels of the value, in all languages
+    pub labels:   Vec<LocalizedText>,
+}
+
+impl PropertyValue {
+    /// Returns the label of the value in the most preferred of the
+    /// given languages, or the rendered value if it has no label.
+    pub fn label(&self, languages: &Languages) -> &str {
+        languages
+            .pick(&self.labels)
+            .map_or(self.value.as_str(), |label| label.value.as_str())
+    }
+}
+
+/// Everything that the ontology says about one individual, see
+/// `LoaderStore::describe_owl_individual`.
+#[derive(Debug, Clone)]
+pub struct OWLIndividualDescription {
+    pub individual:      OWLIndividual,
+    /// `rdfs:label`, `skos:prefLabel` and `skos:altLabel` values
+    pub labels:          Vec<AnnotationValue>,
+    /// `skos:definition`, `rdfs:comment` and `IAO:0000115` values
+    pub definitions:     Vec<AnnotationValue>,
+    /// The IRIs of the classes of the individual, other than
+    /// `owl:NamedIndividual`
+    pub types:           Vec<String>,
+    /// The other asserted property values, ordered by property
+    pub property_values: Vec<PropertyValue>,
+    pub same_as:         Vec<String>,
+    pub different_from:  Vec<String>,
+}
+
+/// A member of an `owl:oneOf` enumeration.
+#[derive(Debug, Clone)]
+pub struct EnumerationMember {
+    /// The IRI of the individual or the lexical value of the literal
+    pub value:        String,
+    /// The labels of the individual, in all languages
+    pub labels:       Vec<AnnotationValue>,
+    /// The definitions or comments of the individual, in all languages
+    pub descriptions: Vec<AnnotationValue>,
+}
+
+impl LoaderStore {
+    /// Find all `owl:NamedIndividual`s in the given graph, or in all
+    /// graphs if no graph is given.
+    pub fn find_owl_individuals(
+        &self,
+        graph: Option<&NamedNode>,
+    ) -> anyhow::Result<Vec<OWLIndividual>> {
+        Ok(self
+            .labelled_resources("?resource a owl:NamedIndividual .", graph)?
+            .into_iter()
+            .map(|resource| {
+                OWLIndividual {
+                    iri:      resource.iri,
+                    labels:   resource.labels,
+                    comments: resource.comments,
+                }
+            })
+            .collect())
+    }
+
+    /// Returns the types, property values and identity links of the
+    /// given individual, rendering values with the given prefixes.
+    pub fn describe_owl_individual(
+        &self,
+        individual: &OWLIndividual,
+        prefixes: &PrefixRegistry,
+    ) -> anyhow::Result<OWLIndividualDescription> {
+        let iri = &individual.iri;
+        let store = self.store();
+        let renderer = ManchesterRenderer::new(&store, prefixes);
+
+        let query = format!(
+            r#"{OWL_PREFIXES}
+            SELECT ?property ?value ?valueLabel WHERE {{
+                <{iri}> ?property ?value .
+                # Types, identity links, labels and definitions are
+                # shown separately
+                FILTER(?property NOT IN (
+                    rdf:type, owl:sameAs, owl:differentFrom,
+                    rdfs:label, skos:prefLabel, skos:altLabel,
+                    skos:definition, rdfs:comment, obo:IAO_0000115
+                ))
+                OPTIONAL {{ ?value rdfs:label ?valueLabel }}
+            }}
+            ORDER BY ?property ?value ?valueLabel
+            "#
+        );
+        let mut property_values: Vec<PropertyValue> = Vec::new();
+        let mut previous: Option<(String, Term)> = None;
+        for solution in self.solutions(&query)? {
+            let (Some(property), Some(value)) = (
+                iri_value(&solution, "property"),
+                solution.get("value"),
+            ) else {
+                continue;
+            };
+            let label = localized_value(&solution, "valueLabel");
+            // A value with several labels comes in a row per label
+            let key = (property.clone(), value.clone());
+            if previous.as_ref() == Some(&key) {
+                if let (Some(last), Some(label)) =
+                    (property_values.last_mut(), label)
+                {
+                    last.labels.push(label);
+                }
+                continue;
+            }
+            previous = Some(key);
+            let value = match value.as_ref() {
+                TermRef::Literal(literal) => literal.value().to_string(),
+                _ => renderer.render(value),
+            };
+            property_values.push(PropertyValue {
+                property,
+                value,
+                labels: label.into_iter().collect(),
+            });
+        }
+
+        Ok(OWLIndividualDescription {
+            individual: individual.clone(),
+            labels: self.annotation_values(
+                iri,
+                "rdfs:label skos:prefLabel skos:altLabel",
+            )?,
+            definitions: self.annotation_values(
+                iri,
+                "skos:definition rdfs:comment obo:IAO_0000115",
+            )?,
+            types: self.iris(&format!(
+                "<{iri}> a ?term .
+                 FILTER(isIRI(?term) && ?term != owl:NamedIndividual)"
+            ))?,
+            property_values,
+            same_as: self.iris(&format!(
+                "{{ <{iri}> owl:sameAs ?term }}
+                 UNION {{ ?term owl:sameAs <{iri}> }}
+                 FILTER(isIRI(?term) && ?term != <{iri}>)"
+            ))?,
+            different_from: self.iris(&format!(
+                "{{ <{iri}> owl:differentFrom ?term }}
+                 UNION {{ ?term owl:differentFrom <{iri}> }}
+                 UNION {{
+                     ?all a owl:AllDifferent ;
+                          owl:distinctMembers|owl:members ?members .
+                     ?members rdf:rest*/rdf:first <{iri}> .
+                     ?members rdf:rest*/rdf:first ?term .
+                 }}
+                 FILTER(isIRI(?term) && ?term != <{iri}>)"
+            ))?,
+        })
+    }
+
+    /// Returns the members of the `owl:oneOf` enumeration that defines
+    /// the given class, directly or through an `owl:equivalentClass`,
+    /// in the order of the list.
+    pub fn owl_enumeration(
+        &self,
+        class_iri: &str,
+        prefixes: &PrefixRegistry,
+    ) -> anyhow::Result<Vec<EnumerationMember>> {
+        let lists = self.terms(&format!(
+            "{{ <{class_iri}> owl:oneOf ?term }}
+             UNION {{
+                 <{class_iri}> owl:equivalentClass ?class .
+                 ?class owl:oneOf ?term .
+             }}"
+        ))?;
+        let store = self.store();
+        let renderer = ManchesterRenderer::new(&store, prefixes);
+        let mut members = Vec::new();
+        for list in lists {
+            for member in renderer.list_members(&list) {
+                members.push(match member.as_ref() {
+                    TermRef::NamedNode(node) => {
+                        self.enumeration_member(node)?
+                    },
+                    TermRef::Literal(literal) => {
+                        EnumerationMember {
+                            value:        literal.value().to_string(),
+                            labels:       Vec::new(),
+                            descriptions: Vec::new(),
+                        }
+                    },
+                    _ => continue,
+                });
+            }
+        }
+        Ok(members)
+    }
+
+    fn enumeration_member(
+        &self,
+        node: NamedNodeRef,
+    ) -> anyhow::Result<EnumerationMember> {
+        Ok(EnumerationMember {
+            value:        node.as_str().to_string(),
+            labels:       self.annotation_values(
+                node.as_str(),
+                "rdfs:label skos:prefLabel",
+            )?,
+            descriptions: self.annotation_values(
+                node.as_str(),
+                "skos:definition rdfs:comment obo:IAO_0000115",
+            )?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::store::PrefixOrigin};
+
+    const TURTLE: &str = r#"
+        @prefix ex: <http://example.com/> .
+        @prefix owl: <http://www.w3.org/2002/07/owl#> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+
+        ex:Color a owl:Class ;
+            owl:equivalentClass [ owl:oneOf ( ex:red ex:green "blue" ) ] .
+        ex:red a owl:NamedIndividual, ex:Color ;
+            rdfs:label "red"@en, "rouge"@fr ;
+            rdfs:comment "The color of blood."@en ;
+            ex:complement ex:green ;
+            ex:wavelength 700 .
+        ex:green a owl:NamedIndividual, ex:Color ;
+            rdfs:label "green"@en, "vert"@fr ;
+            owl:sameAs ex:vert .
+        [] a owl:AllDifferent ; owl:distinctMembers ( ex:red ex:green ) .
+    "#;
+
+    fn store() -> (LoaderStore, PrefixRegistry) {
+        let store = LoaderStore::new_in_memory().unwrap();
+        store.load_turtle("urn:test:colors", TURTLE).unwrap();
+        store
+            .load_turtle(
+                "urn:test:other",
+                "<http://example.com/other> a \
+                 <http://www.w3.org/2002/07/owl#NamedIndividual> .",
+            )
+            .unwrap();
+        let mut prefixes = PrefixRegistry::with_well_known_prefixes();
+        prefixes.add("ex", "http://example.com/", PrefixOrigin::Source);
+        (store, prefixes)
+    }
+
+    #[test]
+    fn test_find_owl_individuals() -> anyhow::Result<()> {
+        let (store, _) = store();
+        let iris = |individuals: Vec<OWLIndividual>| -> Vec<String> {
+            individuals.into_iter().map(|i| i.iri).collect()
+        };
+        assert_eq!(iris(store.find_owl_individuals(None)?), [
+            "http://example.com/green",
+            "http://example.com/other",
+            "http://example.com/red"
+        ]);
+        let graph = NamedNode::new("urn:test:colors")?;
+        assert_eq!(iris(store.find_owl_individuals(Some(&graph))?), [
+            "http://example.com/green",
+            "http://example.com/red"
+        ]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_owl_individual() -> anyhow::Result<()> {
+        let (store, prefixes) = store();
+        let individuals = store.find_owl_individuals(None)?;
+        let red = store.describe_owl_individual(&individuals[2], &prefixes)?;
+        assert_eq!(red.labels.len(), 2);
+        assert_eq!(red.definitions[0].value, "The color of blood.");
+        assert_eq!(red.types, ["http://example.com/Color"]);
+        let values = |languages: &Languages| -> Vec<(&str, &str)> {
+            red.property_values
+                .iter()
+                .map(|v| (v.property.as_str(), v.label(languages)))
+                .collect()
+        };
+        // A value with a label is shown as its label in the most
+        // preferred language
+        assert_eq!(values(&Languages::new(["en"])), [
+            ("http://example.com/complement", "green"),
+            ("http://example.com/wavelength", "700"),
+        ]);
+        assert_eq!(values(&Languages::new(["fr", "en"])), [
+            ("http://example.com/complement", "vert"),
+            ("http://example.com/wavelength", "700"),
+        ]);
+        assert_eq!(red.property_values[0].value, "ex:green");
+        assert_eq!(red.different_from, ["http://example.com/green"]);
+        assert!(red.same_as.is_empty());
+
+        let green =
+            store.describe_owl_individual(&individuals[0], &prefixes)?;
+        assert_eq!(green.same_as, ["http://example.com/vert"]);
+        assert_eq!(green.different_from, ["http://example.com/red"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_owl_enumeration() -> anyhow::Result<()> {
+        let (store, prefixes) = store();
+        let members =
+            store.owl_enumeration("http://example.com/Color", &prefixes)?;
+        let values: Vec<&str> =
+            members.iter().map(|m| m.value.as_str()).collect();
+        assert_eq!(values, [
+            "http://example.com/red",
+            "http://example.com/green",
+            "blue"
+        ]);
+        assert_eq!(members[0].labels.len(), 2);
+        assert_eq!(
+            members[0].descriptions[0].value,
+            "The color of blood."
+        );
+        assert!(members[2].labels.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,308 @@
+use {
+    crate::{
+        rdf_const::predicates::{
+            OXI_OWL_ALL_VALUES_FROM,
+            OXI_OWL_CARDINALITY,
+            OXI_OWL_COMPLEMENT_OF,
+            OXI_OWL_HAS_SELF,
+            OXI_OWL_HAS_VALUE,
+            OXI_OWL_INTERSECTION_OF,
+            OXI_OWL_INVERSE_OF,
+            OXI_OWL_MAX_CARDINALITY,
+            OXI_OWL_MAX_QUALIFIED_CARDINALITY,
+            OXI_OWL_MIN_CARDINALITY,
+            OXI_OWL_MIN_QUALIFIED_CARDINALITY,
+            OXI_OWL_ON_CLASS,
+            OXI_OWL_ON_DATA_RANGE,
+            OXI_OWL_ON_PROPERTY,
+            OXI_OWL_ONE_OF,
+            OXI_OWL_QUALIFIED_CARDINALITY,
+            OXI_OWL_SOME_VALUES_FROM,
+            OXI_OWL_UNION_OF,
+        },
+        store::{
+            PrefixRegistry,
+            traversal::{list_members, object},
+        },
+    },
+    oxigraph::store::Store,
+    oxrdf::{NamedNode, SubjectRef, Term, TermRef},
+};
+
+/// Nested class expressions deeper than this are rendered as `...`,
+/// which also protects against cyclic (malformed) expressions.
+const MAX_DEPTH: usize = 16;
+
+/// Renders OWL class expressions, such as the anonymous
+/// `owl:Restriction`s in `rdfs:subClassOf` axioms, in the OWL 2
+/// Manchester syntax, e.g. `ex:hasPart min 2 ex:Wheel`.
+///
+/// Works on any `Store`, looking at the triples in all of its graphs.
+/// Named classes, properties and individuals are rendered as CURIEs
+/// (or local names) using the given `PrefixRegistry`.
+pub struct ManchesterRenderer<'a> {
+    store:    &'a Store,
+    prefixes: &'a PrefixRegistry,
+}
+
+impl<'a> ManchesterRenderer<'a> {
+    pub fn new(store: &'a Store, prefixes: &'a PrefixRegistry) -> Self {
+        Self { store, prefixes }
+    }
+
+    /// Renders the given class expression.
+    pub fn render(&self, expression: &Term) -> String {
+        self.render_term(expression.as_ref(), 0)
+    }
+
+    fn render_term(&self, term: TermRef, depth: usize) -> String {
+        match term {
+            TermRef::NamedNode(node) => self.prefixes.display_name(node),
+            TermRef::Literal(literal) => {
+                if literal.is_plain() {
+                    format!("\"{}\"", literal.value())
+                } else {
+                    format!(
+                        "\"{}\"^^{}",
+                        literal.value(),
+                        self.prefixes.display_name(literal.datatype())
+                    )
+                }
+            },
+            TermRef::BlankNode(node) => {
+                if depth >= MAX_DEPTH {
+                    return "...".to_string();
+                }
+                self.render_anonymous(node.into(), depth + 1)
+                    .unwrap_or_else(|| "[]".to_string())
+            },
+            #[allow(unreachable_patterns)]
+            other => other.to_string(),
+        }
+    }
+
+    /// Renders a class expression that is used as part of another
+    /// one, in parentheses unless it is a simple name.
+    fn render_nested(&self, term: TermRef, depth: usize) -> String {
+        let rendered = self.render_term(term, depth);
+        if term.is_blank_node() && rendered.contains(' ') {
+            format!("({})", rendered)
+        } else {
+            rendered
+        }
+    }
+
+    fn render_anonymous(
+        &self,
+        node: SubjectRef,
+        depth: usize,
+    ) -> Option<String> {
+        if let Some(property) = self.object(node, &OXI_OWL_ON_PROPERTY) {
+            return Some(self.render_restriction(node, &property, depth));
+        }
+        if let Some(list) = self.object(node, &OXI_OWL_INTERSECTION_OF) {
+            return Some(self.render_list(&list, " and ", depth));
+        }
+        if let Some(list) = self.object(node, &OXI_OWL_UNION_OF) {
+            return Some(self.render_list(&list, " or ", depth));
+        }
+        if let Some(class) = self.object(node, &OXI_OWL_COMPLEMENT_OF) {
+            return Some(format!(
+                "not {}",
+                self.render_nested(class.as_ref(), depth)
+            ));
+        }
+        if let Some(list) = self.object(node, &OXI_OWL_ONE_OF) {
+            return Some(format!(
+                "{{{}}}",
+                self.render_list(&list, ", ", depth)
+            ));
+        }
+        if let Some(property) = self.object(node, &OXI_OWL_INVERSE_OF) {
+            return Some(format!(
+                "inverse {}",
+                self.render_term(property.as_ref(), depth)
+            ));
+        }
+        None
+    }
+
+    fn render_restriction(
+        &self,
+        node: SubjectRef,
+        property: &Term,
+        depth: usize,
+    ) -> String {
+        let property = self.render_term(property.as_ref(), depth);
+        let filler = self
+            .object(node, &OXI_OWL_ON_CLASS)
+            .or_else(|| self.object(node, &OXI_OWL_ON_DATA_RANGE))
+            .map(|f| format!(" {}", self.render_nested(f.as_ref(), depth)))
+            .unwrap_or_default();
+
+        if let Some(class) = self.object(node, &OXI_OWL_SOME_VALUES_FROM) {
+            return format!(
+                "{} some {}",
+                property,
+                self.render_nested(class.as_ref(), depth)
+            );
+        }
+        if let Some(class) = self.object(node, &OXI_OWL_ALL_VALUES_FROM) {
+            return format!(
+                "{} only {}",
+                property,
+                self.render_nested(class.as_ref(), depth)
+            );
+        }
+        if let Some(value) = self.object(node, &OXI_OWL_HAS_VALUE) {
+            return format!(
+                "{} value {}",
+                property,
+                self.render_term(value.as_ref(), depth)
+            );
+        }
+        if self.object(node, &OXI_OWL_HAS_SELF).is_some() {
+            return format!("{} Self", property);
+        }
+        let cardinalities = [
+            (&*OXI_OWL_MIN_CARDINALITY, "min"),
+            (&*OXI_OWL_MIN_QUALIFIED_CARDINALITY, "min"),
+            (&*OXI_OWL_MAX_CARDINALITY, "max"),
+            (&*OXI_OWL_MAX_QUALIFIED_CARDINALITY, "max"),
+            (&*OXI_OWL_CARDINALITY, "exactly"),
+            (&*OXI_OWL_QUALIFIED_CARDINALITY, "exactly"),
+        ];
+        for (predicate, keyword) in cardinalities {
+            if let Some(Term::Literal(n)) = self.object(node, predicate) {
+                return format!(
+                    "{} {} {}{}",
+                    property,
+                    keyword,
+                    n.value(),
+                    filler
+                );
+            }
+        }
+        property
+    }
+
+    /// Renders the members of the RDF list that starts at `list`.
+    fn render_list(
+        &self,
+        list: &Term,
+        separator: &str,
+        depth: usize,
+    ) -> String {
+        self.list_members(list)
+            .iter()
+            .map(|member| self.render_nested(member.as_ref(), depth))
+            .collect::<Vec<String>>()
+            .join(separator)
+    }
+
+    /// Returns the members of the RDF list that starts at `list`.
+    pub fn list_members(&self, list: &Term) -> Vec<Term> {
+        list_members(self.store, list, None)
+    }
+
+    /// Returns the first object of the given subject and predicate in
+    /// any graph of the store.
+    fn object(&self, node: SubjectRef, predicate: &NamedNode) -> Option<Term> {
+        object(self.store, node, predicate, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::ManchesterRenderer,
+        crate::store::{PrefixOrigin, PrefixRegistry},
+        oxigraph::{io::RdfFormat, store::Store},
+        oxrdf::{NamedNodeRef, Term},
+    };
+
+    const TURTLE: &str = r#"
+        @prefix ex: <http://example.com/> .
+        @prefix owl: <http://www.w3.org/2002/07/owl#> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        ex:Car rdfs:subClassOf [
+            a owl:Restriction ;
+            owl:onProperty ex:hasPart ;
+            owl:minQualifiedCardinality "4"^^xsd:nonNegativeInteger ;
+            owl:onClass ex:Wheel
+        ] .
+        ex:Bike rdfs:subClassOf [
+            a owl:Restriction ;
+            owl:onProperty ex:hasPart ;
+            owl:someValuesFrom [
+                owl:intersectionOf ( ex:Wheel [
+                    a owl:Restriction ;
+                    owl:onProperty ex:size ;
+                    owl:hasValue "28"
+                ] )
+            ]
+        ] .
+        ex:Unicycle rdfs:subClassOf [
+            a owl:Restriction ;
+            owl:onProperty [ owl:inverseOf ex:partOf ] ;
+            owl:allValuesFrom [ owl:complementOf ex:Engine ]
+        ] .
+    "#;
+
+    fn render_superclass_of(class: &str) -> String {
+        let store = Store::new().unwrap();
+        store
+            .load_from_reader(RdfFormat::Turtle, TURTLE.as_bytes())
+            .unwrap();
+        let mut prefixes = PrefixRegistry::with_well_known_prefixes();
+        prefixes.add("ex", "http://example.com/", PrefixOrigin::Source);
+        let superclass = store
+            .quads_for_pattern(
+                Some(NamedNodeRef::new(class).unwrap().into()),
+                None,
+                None,
+                None,
+            )
+            .map(|quad| quad.unwrap().object)
+            .next()
+            .unwrap();
+        ManchesterRenderer::new(&store, &prefixes).render(&superclass)
+    }
+
+    #[test]
+    fn test_qualified_cardinality() {
+        assert_eq!(
+            render_superclass_of("http://example.com/Car"),
+            "ex:hasPart min 4 ex:Wheel"
+        );
+    }
+
+    #[test]
+    fn test_nested_expressions() {
+        assert_eq!(
+            render_superclass_of("http://example.com/Bike"),
+            "ex:hasPart some (ex:Wheel and (ex:size value \"28\"))"
+        );
+        assert_eq!(
+            render_superclass_of("http://example.com/Unicycle"),
+            "inverse ex:partOf only (not ex:Engine)"
+        );
+    }
+
+    #[test]
+    fn test_named_class() {
+        let store = Store::new().unwrap();
+        let prefixes = PrefixRegistry::with_well_known_prefixes();
+        let class = Term::NamedNode(
+            NamedNodeRef::new("http://www.w3.org/2002/07/owl#Thing")
+                .unwrap()
+                .into_owned(),
+        );
+        assert_eq!(
+            ManchesterRenderer::new(&store, &prefixes).render(&class),
+            "owl:Thing"
+        );
+    }
+}
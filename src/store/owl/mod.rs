@@ -0,0 +1,18 @@
+mod class;
+mod individual;
+mod manchester;
+mod ontology;
+mod property;
+
+pub use {
+    class::{AnnotationValue, OWLClass, OWLClassDescription},
+    individual::{
+        EnumerationMember,
+        OWLIndividual,
+        OWLIndividualDescription,
+        PropertyValue,
+    },
+    manchester::ManchesterRenderer,
+    ontology::OWLOntology,
+    property::{OWLProperty, OWLPropertyDescription, OWLPropertyKind},
+};
@@ -0,0 +1,240 @@
+use {
+    super::class::{OWL_PREFIXES, localized_value},
+    crate::{
+        store::{
+            LoaderStore,
+            solution::{iri_value, literal_value},
+        },
+        util::LocalizedText,
+    },
+    oxigraph::sparql::QuerySolution,
+    oxrdf::{NamedNode, TermRef},
+};
+
+/// The header of an ontology: the metadata of its `owl:Ontology`
+/// node.
+#[derive(Debug, Clone, Default)]
+pub struct OWLOntology {
+    /// The IRI of the ontology
+    pub iri:                 String,
+    /// `owl:versionIRI`
+    pub version_iri:         Option<String>,
+    /// `owl:versionInfo`
+    pub version_info:        Option<String>,
+    /// `dcterms:title` (or `dc:title` or `rdfs:label`), in all
+    /// languages
+    pub title:               Vec<LocalizedText>,
+    /// `dcterms:description` (or `dc:description` or `rdfs:comment`),
+    /// in all languages
+    pub description:         Vec<LocalizedText>,
+    /// `dcterms:creator` values, by label if they are resources
+    pub creators:            Vec<String>,
+    /// `dcterms:contributor` values, by label if they are resources
+    pub contributors:        Vec<String>,
+    /// `dcterms:license`
+    pub license:             Option<String>,
+    /// `dcterms:issued`
+    pub issued:              Option<String>,
+    /// `dcterms:modified`
+    pub modified:            Option<String>,
+    /// The IRIs of the `owl:imports`
+    pub imports:             Vec<String>,
+    /// The IRIs of the `owl:priorVersion`s
+    pub prior_versions:      Vec<String>,
+    /// `vann:preferredNamespacePrefix`
+    pub preferred_prefix:    Option<String>,
+    /// `vann:preferredNamespaceUri`
+    pub preferred_namespace: Option<String>,
+}
+
+impl LoaderStore {
+    /// Returns the header of every `owl:Ontology` in the given graph,
+    /// or in all graphs if no graph is given.
+    pub fn find_owl_ontologies(
+        &self,
+        graph: Option<&NamedNode>,
+    ) -> anyhow::Result<Vec<OWLOntology>> {
+        let pattern = match graph {
+            Some(graph) => format!("GRAPH {graph} {{ ?term a owl:Ontology }}"),
+            None => "?term a owl:Ontology".to_string(),
+        };
+        let mut ontologies = Vec::new();
+        for iri in self.iris(&format!("{pattern} FILTER(isIRI(?term))"))? {
+            ontologies.push(self.owl_ontology(iri)?);
+        }
+        Ok(ontologies)
+    }
+
+    fn owl_ontology(&self, iri: String) -> anyhow::Result<OWLOntology> {
+        let first = |path: &str| -> anyhow::Result<Option<String>> {
+            Ok(self.ontology_values(&iri, path)?.into_iter().next())
+        };
+        // The texts of the first of the given paths that has any
+        let texts = |paths: &[&str]| -> anyhow::Result<Vec<LocalizedText>> {
+            for path in paths {
+                let texts = self.ontology_texts(&iri, path)?;
+                if !texts.is_empty() {
+                    return Ok(texts);
+                }
+            }
+            Ok(Vec::new())
+        };
+        Ok(OWLOntology {
+            version_iri: first("owl:versionIRI")?,
+            version_info: first("owl:versionInfo")?,
+            title: texts(&["dcterms:title|dc:title", "rdfs:label"])?,
+            description: texts(&[
+                "dcterms:description|dc:description",
+                "rdfs:comment",
+            ])?,
+            creators: self
+                .ontology_values(&iri, "dcterms:creator|dc:creator")?,
+            contributors: self
+                .ontology_values(&iri, "dcterms:contributor|dc:contributor")?,
+            license: first("dcterms:license|dc:rights")?,
+            issued: first("dcterms:issued")?,
+            modified: first("dcterms:modified")?,
+            imports: self.iris(&format!("<{iri}> owl:imports ?term"))?,
+            prior_versions: self
+                .iris(&format!("<{iri}> owl:priorVersion ?term"))?,
+            preferred_prefix: first("vann:preferredNamespacePrefix")?,
+            preferred_namespace: first("vann:preferredNamespaceUri")?,
+            iri,
+        })
+    }
+
+    /// Returns the values of the given property path of the ontology:
+    /// the lexical value of literals, and the label (or otherwise the
+    /// IRI) of resources.
+    fn ontology_values(
+        &self,
+        iri: &str,
+        path: &str,
+    ) -> anyhow::Result<Vec<String>> {
+        let query = format!(
+            r#"{OWL_PREFIXES}
+            PREFIX dcterms: <http://purl.org/dc/terms/>
+            PREFIX dc: <http://purl.org/dc/elements/1.1/>
+            PREFIX vann: <http://purl.org/vocab/vann/>
+            PREFIX foaf: <http://xmlns.com/foaf/0.1/>
+            SELECT ?value (SAMPLE(?l) AS ?label) WHERE {{
+                <{iri}> {path} ?value .
+                OPTIONAL {{
+                    ?value rdfs:label|skos:prefLabel|foaf:name ?l .
+                }}
+            }}
+            GROUP BY ?value
+            ORDER BY ?value
+            "#
+        );
+        Ok(self
+            .solutions(&query)?
+            .iter()
+            .filter_map(Self::rendered_value)
+            .collect())
+    }
+
+    /// Returns the literal values of the given property path of the
+    /// ontology with their language tags.
+    fn ontology_texts(
+        &self,
+        iri: &str,
+        path: &str,
+    ) -> anyhow::Result<Vec<LocalizedText>> {
+        let query = format!(
+            r#"{OWL_PREFIXES}
+            PREFIX dcterms: <http://purl.org/dc/terms/>
+            PREFIX dc: <http://purl.org/dc/elements/1.1/>
+            SELECT DISTINCT ?value WHERE {{
+                <{iri}> {path} ?value .
+                FILTER(isLiteral(?value))
+            }}
+            ORDER BY ?value
+            "#
+        );
+        Ok(self
+            .solutions(&query)?
+            .iter()
+            .filter_map(|solution| localized_value(solution, "value"))
+            .collect())
+    }
+
+    fn rendered_value(solution: &QuerySolution) -> Option<String> {
+        if let Some(label) = literal_value(solution, "label") {
+            return Some(label);
+        }
+        match solution.get("value")?.as_ref() {
+            TermRef::Literal(literal) => Some(literal.value().to_string()),
+            TermRef::NamedNode(_) => iri_value(solution, "value"),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VEHICLES: &str = r#"
+        @prefix owl: <http://www.w3.org/2002/07/owl#> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+        @prefix dcterms: <http://purl.org/dc/terms/> .
+        @prefix foaf: <http://xmlns.com/foaf/0.1/> .
+        @prefix vann: <http://purl.org/vocab/vann/> .
+
+        <http://example.com/vehicles> a owl:Ontology ;
+            dcterms:title "Vehicles", "Voertuigen"@nl ;
+            rdfs:label "The vehicles ontology" ;
+            rdfs:comment "Cars, bikes and their parts." ;
+            owl:versionInfo "1.2" ;
+            owl:imports <http://example.com/parts> ;
+            dcterms:creator <http://example.com/alice>, "Bob" ;
+            vann:preferredNamespacePrefix "veh" .
+        <http://example.com/alice> foaf:name "Alice" .
+    "#;
+
+    const PARTS: &str = r#"
+        @prefix owl: <http://www.w3.org/2002/07/owl#> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+
+        <http://example.com/parts> a owl:Ontology ;
+            rdfs:label "Parts" .
+    "#;
+
+    fn values(texts: &[LocalizedText]) -> Vec<&str> {
+        texts.iter().map(|text| text.value.as_str()).collect()
+    }
+
+    #[test]
+    fn test_owl_ontology() -> anyhow::Result<()> {
+        let store = LoaderStore::new_in_memory()?;
+        let vehicles = store.load_turtle("urn:test:vehicles", VEHICLES)?;
+        store.load_turtle("urn:test:parts", PARTS)?;
+
+        let ontologies = store.find_owl_ontologies(Some(&vehicles))?;
+        assert_eq!(ontologies.len(), 1);
+        let ontology = &ontologies[0];
+        assert_eq!(ontology.iri, "http://example.com/vehicles");
+        assert_eq!(values(&ontology.title), [
+            "Vehicles",
+            "Voertuigen"
+        ]);
+        assert_eq!(ontology.title[1].language.as_deref(), Some("nl"));
+        assert_eq!(values(&ontology.description), ["Cars, bikes \
+                                                    and their parts.\
+                                                    "]);
+        assert_eq!(ontology.version_info.as_deref(), Some("1.2"));
+        assert_eq!(ontology.imports, ["http://example.com/parts"]);
+        // Creators are given by name if they are resources
+        assert_eq!(ontology.creators, ["Alice", "Bob"]);
+        assert_eq!(ontology.preferred_prefix.as_deref(), Some("veh"));
+
+        // Without a title the label is used
+        let ontologies = store.find_owl_ontologies(None)?;
+        assert_eq!(ontologies.len(), 2);
+        assert_eq!(ontologies[0].iri, "http://example.com/parts");
+        assert_eq!(values(&ontologies[0].title), ["Parts"]);
+        assert!(ontologies[0].description.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,378 @@
+use {
+    super::{AnnotationValue, ManchesterRenderer},
+    crate::{
+        store::{LoaderStore, PrefixRegistry, solution::count_value},
+        util::LocalizedText,
+    },
+    oxrdf::{NamedNode, Term},
+};
+
+/// The kinds of properties that are documented, in the order in
+/// which they appear in the ontology book.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OWLPropertyKind {
+    /// `owl:ObjectProperty`
+    Object,
+    /// `owl:DatatypeProperty`
+    Datatype,
+    /// `owl:AnnotationProperty`
+    Annotation,
+    /// A plain `rdf:Property` that is none of the above
+    Rdf,
+}
+
+impl OWLPropertyKind {
+    pub const ALL: [OWLPropertyKind; 4] = [
+        OWLPropertyKind::Object,
+        OWLPropertyKind::Datatype,
+        OWLPropertyKind::Annotation,
+        OWLPropertyKind::Rdf,
+    ];
+
+    /// Returns the IRI of the class of this kind of property.
+    pub fn class_iri(&self) -> &'static str {
+        match self {
+            OWLPropertyKind::Object => {
+                "http://www.w3.org/2002/07/owl#ObjectProperty"
+            },
+            OWLPropertyKind::Datatype => {
+                "http://www.w3.org/2002/07/owl#DatatypeProperty"
+            },
+            OWLPropertyKind::Annotation => {
+                "http://www.w3.org/2002/07/owl#AnnotationProperty"
+            },
+            OWLPropertyKind::Rdf => {
+                "http://www.w3.org/1999/02/22-rdf-syntax-ns#Property"
+            },
+        }
+    }
+
+    /// Returns the name of this kind of property in the glossary.
+    pub fn term_kind(&self) -> &'static str {
+        match self {
+            OWLPropertyKind::Object => "Object Property",
+            OWLPropertyKind::Datatype => "Datatype Property",
+            OWLPropertyKind::Annotation => "Annotation Property",
+            OWLPropertyKind::Rdf => "Property",
+        }
+    }
+
+    /// Returns the title of the section with properties of this kind.
+    pub fn section_title(&self) -> &'static str {
+        match self {
+            OWLPropertyKind::Object => "Object Properties",
+            OWLPropertyKind::Datatype => "Datatype Properties",
+            OWLPropertyKind::Annotation => "Annotation Properties",
+            OWLPropertyKind::Rdf => "RDF Properties",
+        }
+    }
+}
+
+/// Represents a property from the source data
+#[derive(Debug, Clone)]
+pub struct OWLProperty {
+    /// The IRI of the property
+    pub iri:      String,
+    pub kind:     OWLPropertyKind,
+    /// The human-readable labels of the property, in all languages
+    pub labels:   Vec<LocalizedText>,
+    /// The descriptions or comments about the property, in all
+    /// languages
+    pub comments: Vec<LocalizedText>,
+}
+
+/// Everything that the ontology says about one property, see
+/// `LoaderStore::describe_owl_property`.
+///
+/// Named properties are given as IRIs, domains, ranges and inverses
+/// are rendered in Manchester syntax.
+#[derive(Debug, Clone)]
+pub struct OWLPropertyDescription {
+    pub property:         OWLProperty,
+    /// `rdfs:label`, `skos:prefLabel` and `skos:altLabel` values
+    pub labels:           Vec<AnnotationValue>,
+    /// `skos:definition`, `rdfs:comment` and `IAO:0000115` values
+    pub definitions:      Vec<AnnotationValue>,
+    pub domains:          Vec<String>,
+    pub ranges:           Vec<String>,
+    /// The IRIs of the named classes among the domains
+    pub domain_iris:      Vec<String>,
+    /// The IRIs of the named classes among the ranges
+    pub range_iris:       Vec<String>,
+    pub super_properties: Vec<String>,
+    pub sub_properties:   Vec<String>,
+    /// The `owl:inverseOf` properties, in either direction
+    pub inverses:         Vec<String>,
+    /// The characteristics such as "Functional" or "Transitive"
+    pub characteristics:  Vec<String>,
+    /// The `owl:propertyChainAxiom`s, like `ex:hasParent o ex:hasParent`
+    pub property_chains:  Vec<String>,
+    /// The number of triples that use the property as predicate
+    pub usage_count:      usize,
+}
+
+impl LoaderStore {
+    /// Find all properties of the given kind in the given graph, or in
+    /// all graphs if no graph is given.
+    ///
+    /// Properties that are typed as both `rdf:Property` and one of the
+    /// OWL property classes are only returned for the OWL kind.
+    pub fn find_owl_properties(
+        &self,
+        kind: OWLPropertyKind,
+        graph: Option<&NamedNode>,
+    ) -> anyhow::Result<Vec<OWLProperty>> {
+        let exclude_owl_kinds = if kind == OWLPropertyKind::Rdf {
+            r#"FILTER NOT EXISTS {
+                VALUES ?owlKind {
+                    owl:ObjectProperty owl:DatatypeProperty
+                    owl:AnnotationProperty
+                }
+                ?resource a ?owlKind .
+            }"#
+        } else {
+            ""
+        };
+        Ok(self
+            .labelled_resources(
+                &format!(
+                    "?resource a <{}> . {exclude_owl_kinds}",
+                    kind.class_iri()
+                ),
+                graph,
+            )?
+            .into_iter()
+            .map(|resource| {
+                OWLProperty {
+                    iri: resource.iri,
+                    kind,
+                    labels: resource.labels,
+                    comments: resource.comments,
+                }
+            })
+            .collect())
+    }
+
+    /// Returns all the axioms and annotations of the given property,
+    /// rendering anonymous expressions with the given prefixes.
+    pub fn describe_owl_property(
+        &self,
+        property: &OWLProperty,
+        prefixes: &PrefixRegistry,
+    ) -> anyhow::Result<OWLPropertyDescription> {
+        let iri = &property.iri;
+        let store = self.store();
+        let renderer = ManchesterRenderer::new(&store, prefixes);
+        let render = |terms: Vec<Term>| -> Vec<String> {
+            terms.iter().map(|t| renderer.render(t)).collect()
+        };
+
+        let characteristics = self
+            .iris(&format!(
+                "VALUES ?term {{
+                     owl:FunctionalProperty owl:InverseFunctionalProperty
+                     owl:TransitiveProperty owl:SymmetricProperty
+                     owl:AsymmetricProperty owl:ReflexiveProperty
+                     owl:IrreflexiveProperty
+                 }}
+                 <{iri}> a ?term ."
+            ))?
+            .iter()
+            .map(|iri| {
+                iri.trim_start_matches("http://www.w3.org/2002/07/owl#")
+                    .trim_end_matches("Property")
+                    .to_string()
+            })
+            .collect();
+
+        let property_chains = self
+            .terms(&format!("<{iri}> owl:propertyChainAxiom ?term ."))?
+            .iter()
+            .map(|chain| {
+                renderer
+                    .list_members(chain)
+                    .iter()
+                    .map(|member| renderer.render(member))
+                    .collect::<Vec<String>>()
+                    .join(" o ")
+            })
+            .collect();
+
+        let usage_count = self
+            .solutions(&format!(
+                "SELECT (COUNT(*) AS ?count) WHERE {{ ?s <{iri}> ?o }}"
+            ))?
+            .first()
+            .map(|solution| count_value(solution, "count"))
+            .unwrap_or_default();
+
+        Ok(OWLPropertyDescription {
+            property: property.clone(),
+            labels: self.annotation_values(
+                iri,
+                "rdfs:label skos:prefLabel skos:altLabel",
+            )?,
+            definitions: self.annotation_values(
+                iri,
+                "skos:definition rdfs:comment obo:IAO_0000115",
+            )?,
+            domains: render(
+                self.terms(&format!("<{iri}> rdfs:domain ?term ."))?,
+            ),
+            ranges: render(self.terms(&format!("<{iri}> rdfs:range ?term ."))?),
+            domain_iris: self.iris(&format!(
+                "<{iri}> rdfs:domain ?term . FILTER(isIRI(?term))"
+            ))?,
+            range_iris: self.iris(&format!(
+                "<{iri}> rdfs:range ?term . FILTER(isIRI(?term))"
+            ))?,
+            super_properties: self.iris(&format!(
+                "<{iri}> rdfs:subPropertyOf ?term . FILTER(isIRI(?term))"
+            ))?,
+            sub_properties: self.iris(&format!(
+                "?term rdfs:subPropertyOf <{iri}> . FILTER(isIRI(?term))"
+            ))?,
+            inverses: render(self.terms(&format!(
+                "{{ <{iri}> owl:inverseOf ?term }}
+                 UNION {{ ?term owl:inverseOf <{iri}> }}
+                 FILTER(?term != <{iri}>)"
+            ))?),
+            characteristics,
+            property_chains,
+            usage_count,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::store::PrefixOrigin};
+
+    const PROPERTIES: &str = r#"
+        @prefix ex: <http://example.com/> .
+        @prefix owl: <http://www.w3.org/2002/07/owl#> .
+        @prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        ex:hasPart a owl:ObjectProperty, owl:TransitiveProperty ;
+            rdfs:label "has part"@en ;
+            rdfs:domain [ owl:unionOf ( ex:Car ex:Bike ) ] ;
+            rdfs:range ex:Part ;
+            owl:inverseOf ex:partOf .
+        ex:hasWheel a owl:ObjectProperty ;
+            rdfs:subPropertyOf ex:hasPart .
+        ex:serialNumber a owl:DatatypeProperty, owl:FunctionalProperty,
+                rdf:Property ;
+            rdfs:domain ex:Part ;
+            rdfs:range xsd:string .
+        ex:note a rdf:Property .
+        ex:designer a owl:AnnotationProperty .
+
+        ex:car1 ex:hasPart ex:wheel1, ex:wheel2 .
+    "#;
+
+    fn property_store() -> (LoaderStore, PrefixRegistry) {
+        let store = LoaderStore::new_in_memory().unwrap();
+        store.load_turtle("urn:test:parts", PROPERTIES).unwrap();
+        store
+            .load_turtle(
+                "urn:test:other",
+                "<http://example.com/other> a \
+                 <http://www.w3.org/2002/07/owl#ObjectProperty> .",
+            )
+            .unwrap();
+        let mut prefixes = PrefixRegistry::with_well_known_prefixes();
+        prefixes.add("ex", "http://example.com/", PrefixOrigin::Source);
+        (store, prefixes)
+    }
+
+    fn property_iris(
+        store: &LoaderStore,
+        kind: OWLPropertyKind,
+        graph: Option<&str>,
+    ) -> Vec<String> {
+        let graph = graph.map(|graph| NamedNode::new(graph).unwrap());
+        store
+            .find_owl_properties(kind, graph.as_ref())
+            .unwrap()
+            .into_iter()
+            .map(|property| property.iri)
+            .collect()
+    }
+
+    #[test]
+    fn test_property_kinds() {
+        let (store, _) = property_store();
+        assert_eq!(
+            property_iris(&store, OWLPropertyKind::Object, None),
+            [
+                "http://example.com/hasPart",
+                "http://example.com/hasWheel",
+                "http://example.com/other"
+            ]
+        );
+        assert_eq!(
+            property_iris(
+                &store,
+                OWLPropertyKind::Object,
+                Some("urn:test:parts")
+            ),
+            ["http://example.com/hasPart", "http://example.com/hasWheel"]
+        );
+        assert_eq!(
+            property_iris(&store, OWLPropertyKind::Datatype, None),
+            ["http://example.com/serialNumber"]
+        );
+        assert_eq!(
+            property_iris(&store, OWLPropertyKind::Annotation, None),
+            ["http://example.com/designer"]
+        );
+        // A property that is also an OWL property is not a plain one
+        assert_eq!(
+            property_iris(&store, OWLPropertyKind::Rdf, None),
+            ["http://example.com/note"]
+        );
+    }
+
+    #[test]
+    fn test_property_description() {
+        let (store, prefixes) = property_store();
+        let properties = store
+            .find_owl_properties(OWLPropertyKind::Object, None)
+            .unwrap();
+        let has_part = store
+            .describe_owl_property(&properties[0], &prefixes)
+            .unwrap();
+        assert_eq!(has_part.property.labels[0].value, "has part");
+        assert_eq!(has_part.domains, ["ex:Car or ex:Bike"]);
+        assert!(has_part.domain_iris.is_empty());
+        assert_eq!(has_part.ranges, ["ex:Part"]);
+        assert_eq!(has_part.range_iris, ["http://example.com/Part"]);
+        assert_eq!(has_part.sub_properties, [
+            "http://example.com/hasWheel"
+        ]);
+        assert_eq!(has_part.inverses, ["ex:partOf"]);
+        assert_eq!(has_part.characteristics, ["Transitive"]);
+        assert_eq!(has_part.usage_count, 2);
+
+        let has_wheel = store
+            .describe_owl_property(&properties[1], &prefixes)
+            .unwrap();
+        assert_eq!(has_wheel.super_properties, [
+            "http://example.com/hasPart"
+        ]);
+        assert!(has_wheel.characteristics.is_empty());
+
+        let properties = store
+            .find_owl_properties(OWLPropertyKind::Datatype, None)
+            .unwrap();
+        let serial_number = store
+            .describe_owl_property(&properties[0], &prefixes)
+            .unwrap();
+        assert_eq!(serial_number.domains, ["ex:Part"]);
+        assert_eq!(serial_number.ranges, ["xsd:string"]);
+        assert_eq!(serial_number.characteristics, ["Functional"]);
+        assert_eq!(serial_number.usage_count, 0);
+    }
+}
@@ -0,0 +1,4 @@
+mod this;
+mod well_known;
+
+pub use this::{PrefixMapping, PrefixOrigin, PrefixRegistry};
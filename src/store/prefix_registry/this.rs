@@ -0,0 +1,214 @@
+use {
+    super::well_known::WELL_KNOWN_PREFIXES,
+    crate::util::extract_local_name,
+    oxrdf::NamedNodeRef,
+    std::collections::BTreeMap,
+};
+
+/// Where a prefix in the `PrefixRegistry` came from, in order of
+/// precedence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PrefixOrigin {
+    /// Declared with `vann:preferredNamespacePrefix` in the data.
+    Preferred,
+    /// Declared in the header of a parsed source file.
+    Source,
+    /// Part of the built-in list of well-known prefixes.
+    WellKnown,
+}
+
+/// A prefix and the namespace IRI that it stands for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrefixMapping {
+    pub prefix:    String,
+    pub namespace: String,
+    pub origin:    PrefixOrigin,
+}
+
+/// The prefix/namespace registry, harvested from the prefix
+/// declarations of all parsed sources, the
+/// `vann:preferredNamespacePrefix` declarations in the data and a
+/// built-in list of well-known prefixes.
+///
+/// Each namespace gets exactly one prefix and each prefix is used
+/// for exactly one namespace. When sources disagree, the
+/// `PrefixOrigin` with the highest precedence wins, and within the
+/// same origin the first one added wins.
+///
+/// Use `PrefixRegistry::curie` or `PrefixRegistry::display_name` to
+/// render IRIs like `fibo-fnd:Agent` instead of full IRIs.
+#[derive(Debug, Clone, Default)]
+pub struct PrefixRegistry {
+    /// All mappings by namespace IRI
+    by_namespace: BTreeMap<String, PrefixMapping>,
+}
+
+impl PrefixRegistry {
+    /// Creates a registry with only the well-known prefixes.
+    pub fn with_well_known_prefixes() -> Self {
+        let mut registry = Self::default();
+        for (prefix, namespace) in WELL_KNOWN_PREFIXES {
+            registry.add(prefix, namespace, PrefixOrigin::WellKnown);
+        }
+        registry
+    }
+
+    /// Adds the given mapping unless the namespace or the prefix is
+    /// already taken by a mapping with the same or a higher
+    /// precedence. Empty prefixes (like in `@prefix : <...>`) are
+    /// ignored since they don't make readable CURIEs.
+    pub fn add(&mut self, prefix: &str, namespace: &str, origin: PrefixOrigin) {
+        if prefix.is_empty() || namespace.is_empty() {
+            return;
+        }
+        if let Some(existing) = self.by_namespace.get(namespace) {
+            if existing.origin <= origin {
+                return;
+            }
+        }
+        if let Some(existing) = self.mapping_for_prefix(prefix) {
+            if existing.origin <= origin {
+                return;
+            }
+            let namespace = existing.namespace.clone();
+            self.by_namespace.remove(&namespace);
+        }
+        self.by_namespace
+            .insert(namespace.to_string(), PrefixMapping {
+                prefix: prefix.to_string(),
+                namespace: namespace.to_string(),
+                origin,
+            });
+    }
+
+    /// Returns the mapping that uses the given prefix, if any.
+    pub fn mapping_for_prefix(&self, prefix: &str) -> Option<&PrefixMapping> {
+        self.by_namespace.values().find(|m| m.prefix == prefix)
+    }
+
+    /// Returns the namespace IRI for the given prefix, if known.
+    pub fn namespace(&self, prefix: &str) -> Option<&str> {
+        self.mapping_for_prefix(prefix)
+            .map(|m| m.namespace.as_str())
+    }
+
+    /// Returns all mappings ordered by prefix.
+    pub fn mappings(&self) -> Vec<&PrefixMapping> {
+        let mut mappings: Vec<&PrefixMapping> =
+            self.by_namespace.values().collect();
+        mappings.sort_by(|a, b| a.prefix.cmp(&b.prefix));
+        mappings
+    }
+
+    /// Returns the mappings that were harvested from the sources
+    /// (so not the built-in well-known ones), ordered by prefix.
+    pub fn declared_mappings(&self) -> Vec<&PrefixMapping> {
+        self.mappings()
+            .into_iter()
+            .filter(|m| m.origin != PrefixOrigin::WellKnown)
+            .collect()
+    }
+
+    /// Returns the mapping with the longest namespace that the given
+    /// IRI starts with, if any.
+    pub fn mapping_for_iri(&self, iri: &str) -> Option<&PrefixMapping> {
+        self.by_namespace
+            .values()
+            .filter(|m| iri.starts_with(&m.namespace))
+            .max_by_key(|m| m.namespace.len())
+    }
+
+    /// Returns the CURIE for the given IRI, using the longest
+    /// matching namespace, or `None` if no namespace matches or if
+    /// the remaining local name would not be a valid CURIE.
+    pub fn curie(&self, iri: &str) -> Option<String> {
+        self.mapping_for_iri(iri).and_then(|m| {
+            let local_name = &iri[m.namespace.len()..];
+            if local_name.contains(['/', '#', '?', ' ']) {
+                None
+            } else {
+                Some(format!("{}:{}", m.prefix, local_name))
+            }
+        })
+    }
+
+    /// Returns the CURIE for the given IRI, or the IRI itself if no
+    /// CURIE can be made.
+    pub fn curie_or_iri(&self, iri: &str) -> String {
+        self.curie(iri).unwrap_or_else(|| iri.to_string())
+    }
+
+    /// Returns the best human-readable name for the given IRI when
+    /// there is no label: its CURIE, or otherwise its local name.
+    pub fn display_name(&self, iri: NamedNodeRef) -> String {
+        self.curie(iri.as_str()).unwrap_or_else(|| {
+            let local_name = extract_local_name(iri);
+            if local_name.is_empty() {
+                iri.as_str().to_string()
+            } else {
+                local_name
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PrefixOrigin, PrefixRegistry};
+
+    #[test]
+    fn test_curie() {
+        let mut registry = PrefixRegistry::with_well_known_prefixes();
+        registry.add(
+            "fibo-fnd",
+            "https://spec.edmcouncil.org/fibo/ontology/FND/",
+            PrefixOrigin::Source,
+        );
+        registry.add(
+            "fibo-fnd-agt",
+            "https://spec.edmcouncil.org/fibo/ontology/FND/AgentsAndPeople/",
+            PrefixOrigin::Source,
+        );
+        assert_eq!(
+            registry.curie("http://www.w3.org/2002/07/owl#Class"),
+            Some("owl:Class".to_string())
+        );
+        assert_eq!(
+            registry.curie(
+                "https://spec.edmcouncil.org/fibo/ontology/FND/AgentsAndPeople/\
+                 Agent"
+            ),
+            Some("fibo-fnd-agt:Agent".to_string())
+        );
+        assert_eq!(registry.curie("http://example.com/a/b"), None);
+    }
+
+    #[test]
+    fn test_precedence() {
+        let mut registry = PrefixRegistry::with_well_known_prefixes();
+        registry.add(
+            "skos-core",
+            "http://www.w3.org/2004/02/skos/core#",
+            PrefixOrigin::Source,
+        );
+        registry.add("ex", "http://example.com/", PrefixOrigin::Source);
+        registry.add("ex", "http://example.org/", PrefixOrigin::Source);
+        registry.add(
+            "my",
+            "http://example.com/",
+            PrefixOrigin::Preferred,
+        );
+
+        assert_eq!(
+            registry.namespace("skos-core"),
+            Some("http://www.w3.org/2004/02/skos/core#")
+        );
+        assert_eq!(registry.namespace("skos"), None);
+        assert_eq!(registry.namespace("ex"), None);
+        assert_eq!(
+            registry.namespace("my"),
+            Some("http://example.com/")
+        );
+        assert_eq!(registry.declared_mappings().len(), 2);
+    }
+}
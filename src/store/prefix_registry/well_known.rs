@@ -0,0 +1,40 @@
+/// Prefixes that are so common that we always know them, even when
+/// none of the sources declares them.
+pub(super) static WELL_KNOWN_PREFIXES: &[(&str, &str)] = &[
+    (
+        "rdf",
+        "http://www.w3.org/1999/02/22-rdf-syntax-ns#",
+    ),
+    ("rdfs", "http://www.w3.org/2000/01/rdf-schema#"),
+    ("owl", "http://www.w3.org/2002/07/owl#"),
+    ("xsd", "http://www.w3.org/2001/XMLSchema#"),
+    ("skos", "http://www.w3.org/2004/02/skos/core#"),
+    ("skosxl", "http://www.w3.org/2008/05/skos-xl#"),
+    ("sh", "http://www.w3.org/ns/shacl#"),
+    ("dcterms", "http://purl.org/dc/terms/"),
+    ("dc", "http://purl.org/dc/elements/1.1/"),
+    ("dcat", "http://www.w3.org/ns/dcat#"),
+    ("void", "http://rdfs.org/ns/void#"),
+    ("vann", "http://purl.org/vocab/vann/"),
+    ("prov", "http://www.w3.org/ns/prov#"),
+    ("foaf", "http://xmlns.com/foaf/0.1/"),
+    ("schema", "https://schema.org/"),
+    ("org", "http://www.w3.org/ns/org#"),
+    ("time", "http://www.w3.org/2006/time#"),
+    ("odrl", "http://www.w3.org/ns/odrl/2/"),
+    ("cc", "http://creativecommons.org/ns#"),
+    ("obo", "http://purl.obolibrary.org/obo/"),
+    ("dprod", "https://ekgf.github.io/dprod/"),
+    ("use-case", "https://ekgf.org/ontology/use-case/"),
+    ("persona", "https://ekgf.org/ontology/persona/"),
+    ("outcome", "https://ekgf.org/ontology/outcome/"),
+    (
+        "user-story",
+        "https://ekgf.org/ontology/user-story/",
+    ),
+    ("workflow", "https://ekgf.org/ontology/workflow/"),
+    (
+        "doc",
+        "https://ekgf.org/ontology/GraphArch/documentation#",
+    ),
+];
@@ -0,0 +1,9 @@
+mod path;
+mod shape;
+mod validate;
+
+pub use {
+    path::ShaclPath,
+    shape::{NodeShape, PropertyShape, ShapeTarget, read_node_shapes},
+    validate::{ShaclViolation, validate_shapes},
+};
@@ -0,0 +1,399 @@
+use {
+    crate::{
+        rdf_const::predicates::{
+            OXI_SHACL_ALTERNATIVE_PATH,
+            OXI_SHACL_INVERSE_PATH,
+            OXI_SHACL_ONE_OR_MORE_PATH,
+            OXI_SHACL_ZERO_OR_MORE_PATH,
+            OXI_SHACL_ZERO_OR_ONE_PATH,
+        },
+        store::{
+            PrefixRegistry,
+            traversal::{as_subject, list_members, object, objects, subjects},
+        },
+    },
+    oxigraph::store::Store,
+    oxrdf::{GraphNameRef, NamedNode, Term},
+    std::collections::HashSet,
+};
+
+/// Nested paths deeper than this are not read, which also protects
+/// against cyclic (malformed) paths.
+const MAX_DEPTH: usize = 16;
+
+/// A SHACL property path, the value of `sh:path`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShaclPath {
+    /// A single predicate
+    Predicate(NamedNode),
+    /// An RDF list of paths, `a/b`
+    Sequence(Vec<ShaclPath>),
+    /// `sh:alternativePath`, `a|b`
+    Alternative(Vec<ShaclPath>),
+    /// `sh:inversePath`, `^a`
+    Inverse(Box<ShaclPath>),
+    /// `sh:zeroOrMorePath`, `a*`
+    ZeroOrMore(Box<ShaclPath>),
+    /// `sh:oneOrMorePath`, `a+`
+    OneOrMore(Box<ShaclPath>),
+    /// `sh:zeroOrOnePath`, `a?`
+    ZeroOrOne(Box<ShaclPath>),
+}
+
+impl ShaclPath {
+    /// Reads the path that starts at the given `sh:path` value from
+    /// the given graph (or all graphs) of the store.
+    pub fn read(
+        store: &Store,
+        path: &Term,
+        graph: Option<GraphNameRef>,
+    ) -> Option<Self> {
+        Self::read_nested(store, path, graph, 0)
+    }
+
+    fn read_nested(
+        store: &Store,
+        path: &Term,
+        graph: Option<GraphNameRef>,
+        depth: usize,
+    ) -> Option<Self> {
+        if depth >= MAX_DEPTH {
+            return None;
+        }
+        let node = match path {
+            Term::NamedNode(predicate) => {
+                return Some(ShaclPath::Predicate(predicate.clone()));
+            },
+            _ => as_subject(path)?,
+        };
+        let read =
+            |term: &Term| Self::read_nested(store, term, graph, depth + 1);
+        let read_all = |list: &Term| -> Option<Vec<ShaclPath>> {
+            list_members(store, list, graph).iter().map(read).collect()
+        };
+        let unary = [
+            (
+                &*OXI_SHACL_INVERSE_PATH,
+                ShaclPath::Inverse as fn(_) -> _,
+            ),
+            (
+                &*OXI_SHACL_ZERO_OR_MORE_PATH,
+                ShaclPath::ZeroOrMore,
+            ),
+            (&*OXI_SHACL_ONE_OR_MORE_PATH, ShaclPath::OneOrMore),
+            (&*OXI_SHACL_ZERO_OR_ONE_PATH, ShaclPath::ZeroOrOne),
+        ];
+        for (predicate, constructor) in unary {
+            if let Some(inner) = object(store, node.as_ref(), predicate, graph)
+            {
+                return Some(constructor(Box::new(read(&inner)?)));
+            }
+        }
+        if let Some(list) = object(
+            store,
+            node.as_ref(),
+            &OXI_SHACL_ALTERNATIVE_PATH,
+            graph,
+        ) {
+            return Some(ShaclPath::Alternative(read_all(&list)?));
+        }
+        let sequence = read_all(path)?;
+        if sequence.is_empty() {
+            None
+        } else {
+            Some(ShaclPath::Sequence(sequence))
+        }
+    }
+
+    /// Returns the values of the path for the given focus node in the
+    /// given graph (or all graphs) of the store, the value nodes of a
+    /// property shape, without duplicates.
+    pub fn values(
+        &self,
+        store: &Store,
+        focus: &Term,
+        graph: Option<GraphNameRef>,
+    ) -> Vec<Term> {
+        self.evaluate(store, std::slice::from_ref(focus), graph, false)
+    }
+
+    /// Returns the nodes that can be reached from the given nodes by
+    /// following the path, or the path in reverse if `inverse` is set.
+    fn evaluate(
+        &self,
+        store: &Store,
+        nodes: &[Term],
+        graph: Option<GraphNameRef>,
+        inverse: bool,
+    ) -> Vec<Term> {
+        match self {
+            ShaclPath::Predicate(predicate) => {
+                let mut values = Vec::new();
+                for node in nodes {
+                    if inverse {
+                        values.extend(
+                            subjects(store, predicate, node.as_ref(), graph)
+                                .into_iter()
+                                .map(Term::from),
+                        );
+                    } else if let Some(subject) = as_subject(node) {
+                        values.extend(objects(
+                            store,
+                            subject.as_ref(),
+                            predicate,
+                            graph,
+                        ));
+                    }
+                }
+                unique(values)
+            },
+            ShaclPath::Sequence(paths) => {
+                let mut nodes = nodes.to_vec();
+                let mut step = |path: &ShaclPath| {
+                    nodes = path.evaluate(store, &nodes, graph, inverse);
+                };
+                if inverse {
+                    paths.iter().rev().for_each(&mut step);
+                } else {
+                    paths.iter().for_each(&mut step);
+                }
+                nodes
+            },
+            ShaclPath::Alternative(paths) => {
+                unique(
+                    paths
+                        .iter()
+                        .flat_map(|path| {
+                            path.evaluate(store, nodes, graph, inverse)
+                        })
+                        .collect(),
+                )
+            },
+            ShaclPath::Inverse(path) => {
+                path.evaluate(store, nodes, graph, !inverse)
+            },
+            ShaclPath::ZeroOrMore(path) => {
+                path.closure(store, nodes.to_vec(), graph, inverse)
+            },
+            ShaclPath::OneOrMore(path) => {
+                let first = path.evaluate(store, nodes, graph, inverse);
+                path.closure(store, first, graph, inverse)
+            },
+            ShaclPath::ZeroOrOne(path) => {
+                let mut values = nodes.to_vec();
+                values.extend(path.evaluate(store, nodes, graph, inverse));
+                unique(values)
+            },
+        }
+    }
+
+    /// Returns the given nodes and all nodes that can be reached from
+    /// them by following the path any number of times.
+    fn closure(
+        &self,
+        store: &Store,
+        nodes: Vec<Term>,
+        graph: Option<GraphNameRef>,
+        inverse: bool,
+    ) -> Vec<Term> {
+        let mut reached = unique(nodes);
+        let mut seen: HashSet<Term> = reached.iter().cloned().collect();
+        let mut frontier = reached.clone();
+        while !frontier.is_empty() {
+            frontier = self
+                .evaluate(store, &frontier, graph, inverse)
+                .into_iter()
+                .filter(|node| seen.insert(node.clone()))
+                .collect();
+            reached.extend(frontier.iter().cloned());
+        }
+        reached
+    }
+
+    /// Renders the path as a SPARQL property path, using CURIEs where
+    /// possible if prefixes are given and full IRIs otherwise (so that
+    /// the result can be used in a query without prefix declarations).
+    pub fn to_sparql(&self, prefixes: Option<&PrefixRegistry>) -> String {
+        match self {
+            ShaclPath::Predicate(predicate) => {
+                prefixes
+                    .and_then(|p| p.curie(predicate.as_str()))
+                    .unwrap_or_else(|| predicate.to_string())
+            },
+            ShaclPath::Sequence(paths) => {
+                paths
+                    .iter()
+                    .map(|path| {
+                        match path {
+                            ShaclPath::Alternative(_) |
+                            ShaclPath::Sequence(_) => {
+                                format!("({})", path.to_sparql(prefixes))
+                            },
+                            _ => path.to_sparql(prefixes),
+                        }
+                    })
+                    .collect::<Vec<String>>()
+                    .join("/")
+            },
+            ShaclPath::Alternative(paths) => {
+                paths
+                    .iter()
+                    .map(|path| path.to_sparql(prefixes))
+                    .collect::<Vec<String>>()
+                    .join("|")
+            },
+            ShaclPath::Inverse(path) => {
+                format!("^{}", path.operand(prefixes))
+            },
+            ShaclPath::ZeroOrMore(path) => {
+                format!("{}*", path.operand(prefixes))
+            },
+            ShaclPath::OneOrMore(path) => {
+                format!("{}+", path.operand(prefixes))
+            },
+            ShaclPath::ZeroOrOne(path) => {
+                format!("{}?", path.operand(prefixes))
+            },
+        }
+    }
+
+    /// Renders the path as the operand of a unary operator, in
+    /// parentheses unless it is a single predicate.
+    fn operand(&self, prefixes: Option<&PrefixRegistry>) -> String {
+        match self {
+            ShaclPath::Predicate(_) => self.to_sparql(prefixes),
+            _ => format!("({})", self.to_sparql(prefixes)),
+        }
+    }
+}
+
+/// Removes the duplicates from the given nodes, keeping the first
+/// occurrence of each.
+fn unique(nodes: Vec<Term>) -> Vec<Term> {
+    let mut seen = HashSet::new();
+    nodes
+        .into_iter()
+        .filter(|node| seen.insert(node.clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::ShaclPath,
+        crate::{
+            rdf_const::predicates::OXI_SHACL_PATH,
+            store::{PrefixOrigin, PrefixRegistry},
+        },
+        oxigraph::{io::RdfFormat, store::Store},
+        oxrdf::{NamedNodeRef, Term},
+    };
+
+    const TURTLE: &str = r#"
+        @prefix ex: <http://example.com/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:simple sh:path ex:name .
+        ex:sequence sh:path ( ex:address [ sh:inversePath ex:city ] ) .
+        ex:alternative sh:path (
+            ex:parent
+            [ sh:alternativePath ( ex:name ex:label ) ]
+        ) .
+        ex:repeated sh:path [
+            sh:oneOrMorePath [ sh:alternativePath ( ex:a ex:b ) ]
+        ] .
+    "#;
+
+    fn render(subject: &str, with_prefixes: bool) -> String {
+        let store = Store::new().unwrap();
+        store
+            .load_from_reader(RdfFormat::Turtle, TURTLE.as_bytes())
+            .unwrap();
+        let mut prefixes = PrefixRegistry::with_well_known_prefixes();
+        prefixes.add("ex", "http://example.com/", PrefixOrigin::Source);
+        let subject =
+            NamedNodeRef::new(&format!("http://example.com/{}", subject))
+                .unwrap()
+                .into_owned();
+        let path = store
+            .quads_for_pattern(
+                Some(subject.as_ref().into()),
+                Some(OXI_SHACL_PATH.as_ref()),
+                None,
+                None,
+            )
+            .map(|quad| quad.unwrap().object)
+            .next()
+            .unwrap();
+        ShaclPath::read(&store, &path, None)
+            .unwrap()
+            .to_sparql(with_prefixes.then_some(&prefixes))
+    }
+
+    #[test]
+    fn test_predicate_path() {
+        assert_eq!(render("simple", true), "ex:name");
+        assert_eq!(
+            render("simple", false),
+            "<http://example.com/name>"
+        );
+    }
+
+    #[test]
+    fn test_complex_paths() {
+        assert_eq!(render("sequence", true), "ex:address/^ex:city");
+        assert_eq!(
+            render("alternative", true),
+            "ex:parent/(ex:name|ex:label)"
+        );
+        assert_eq!(render("repeated", true), "(ex:a|ex:b)+");
+    }
+
+    #[test]
+    fn test_values() {
+        let store = Store::new().unwrap();
+        store
+            .load_from_reader(
+                RdfFormat::Turtle,
+                r#"
+                @prefix ex: <http://example.com/> .
+                ex:a ex:next ex:b .
+                ex:b ex:next ex:c .
+                ex:c ex:next ex:a ; ex:name "C" .
+                "#
+                .as_bytes(),
+            )
+            .unwrap();
+        let node = |name: &str| {
+            Term::from(
+                NamedNodeRef::new(&format!("http://example.com/{}", name))
+                    .unwrap()
+                    .into_owned(),
+            )
+        };
+        let next = || {
+            ShaclPath::Predicate(
+                NamedNodeRef::new("http://example.com/next")
+                    .unwrap()
+                    .into_owned(),
+            )
+        };
+        let values = |path: ShaclPath| path.values(&store, &node("a"), None);
+
+        assert_eq!(values(next()), [node("b")]);
+        assert_eq!(values(ShaclPath::Inverse(Box::new(next()))), [
+            node("c")
+        ]);
+        assert_eq!(values(ShaclPath::OneOrMore(Box::new(next()))), [
+            node("b"),
+            node("c"),
+            node("a")
+        ]);
+        assert_eq!(
+            values(ShaclPath::Inverse(Box::new(ShaclPath::Sequence(
+                vec![next(), next()]
+            )))),
+            [node("b")]
+        );
+    }
+}
@@ -0,0 +1,351 @@
+use {
+    super::ShaclPath,
+    crate::{
+        rdf_const::{
+            classes::{
+                OXI_CLASS_OWL_CLASS,
+                OXI_CLASS_RDFS_CLASS,
+                OXI_CLASS_SHACL_NODE_SHAPE,
+            },
+            predicates::{
+                OXI_RDF_TYPE,
+                OXI_RDFS_LABEL,
+                OXI_SHACL_CLASS,
+                OXI_SHACL_CLOSED,
+                OXI_SHACL_DATATYPE,
+                OXI_SHACL_DEACTIVATED,
+                OXI_SHACL_DESCRIPTION,
+                OXI_SHACL_FLAGS,
+                OXI_SHACL_HAS_VALUE,
+                OXI_SHACL_IGNORED_PROPERTIES,
+                OXI_SHACL_IN,
+                OXI_SHACL_MAX_COUNT,
+                OXI_SHACL_MAX_LENGTH,
+                OXI_SHACL_MESSAGE,
+                OXI_SHACL_MIN_COUNT,
+                OXI_SHACL_MIN_LENGTH,
+                OXI_SHACL_NAME,
+                OXI_SHACL_NODE,
+                OXI_SHACL_NODE_KIND,
+                OXI_SHACL_ORDER,
+                OXI_SHACL_PATH,
+                OXI_SHACL_PATTERN,
+                OXI_SHACL_PROPERTY,
+                OXI_SHACL_SEVERITY,
+                OXI_SHACL_TARGET_CLASS,
+                OXI_SHACL_TARGET_NODE,
+                OXI_SHACL_TARGET_OBJECTS_OF,
+                OXI_SHACL_TARGET_SUBJECTS_OF,
+            },
+        },
+        store::{
+            LoaderStore,
+            traversal::{as_subject, list_members, object, objects},
+        },
+    },
+    oxigraph::store::Store,
+    oxrdf::{GraphNameRef, NamedNode, Subject, SubjectRef, Term},
+};
+
+/// A target of a node shape, the nodes that it validates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShapeTarget {
+    /// `sh:targetClass`, all instances of the class
+    Class(NamedNode),
+    /// The shape is a class itself (an implicit class target)
+    ImplicitClass(NamedNode),
+    /// `sh:targetNode`
+    Node(Term),
+    /// `sh:targetSubjectsOf`, all subjects of the predicate
+    SubjectsOf(NamedNode),
+    /// `sh:targetObjectsOf`, all objects of the predicate
+    ObjectsOf(NamedNode),
+}
+
+impl ShapeTarget {
+    /// Returns the targeted class, if this is a class target.
+    pub fn class(&self) -> Option<&NamedNode> {
+        match self {
+            ShapeTarget::Class(class) | ShapeTarget::ImplicitClass(class) => {
+                Some(class)
+            },
+            _ => None,
+        }
+    }
+}
+
+/// A property shape, the value of `sh:property` of a node shape.
+#[derive(Debug, Clone)]
+pub struct PropertyShape {
+    pub node:        Subject,
+    pub path:        Option<ShaclPath>,
+    /// `sh:name`
+    pub name:        Option<String>,
+    /// `sh:description`
+    pub description: Option<String>,
+    pub datatype:    Option<NamedNode>,
+    pub class:       Option<NamedNode>,
+    pub node_kind:   Option<NamedNode>,
+    /// `sh:node`, the shape that the values have to conform to
+    pub node_shape:  Option<Term>,
+    pub min_count:   Option<usize>,
+    pub max_count:   Option<usize>,
+    pub min_length:  Option<usize>,
+    pub max_length:  Option<usize>,
+    pub pattern:     Option<String>,
+    pub flags:       Option<String>,
+    /// The members of the `sh:in` list, in list order
+    pub in_values:   Vec<Term>,
+    pub has_value:   Option<Term>,
+    pub severity:    Option<NamedNode>,
+    pub messages:    Vec<String>,
+    /// `sh:order`, used to sort the property shapes of a node shape
+    pub order:       Option<f64>,
+}
+
+/// A node shape with its targets and property shapes.
+#[derive(Debug, Clone)]
+pub struct NodeShape {
+    pub node:               Subject,
+    /// `rdfs:label` or `sh:name`
+    pub label:              Option<String>,
+    /// `sh:description`
+    pub description:        Option<String>,
+    pub targets:            Vec<ShapeTarget>,
+    pub closed:             bool,
+    pub ignored_properties: Vec<NamedNode>,
+    pub deactivated:        bool,
+    pub severity:           Option<NamedNode>,
+    pub messages:           Vec<String>,
+    /// The property shapes, ordered by `sh:order` and then by path
+    pub properties:         Vec<PropertyShape>,
+}
+
+impl LoaderStore {
+    /// Returns the SHACL node shapes in the given graph, or in all
+    /// graphs if no graph is given.
+    pub fn find_shacl_node_shapes(
+        &self,
+        graph: Option<&NamedNode>,
+    ) -> Vec<NodeShape> {
+        read_node_shapes(&self.store(), graph.map(|g| g.as_ref().into()))
+    }
+}
+
+/// Reads all node shapes from the given graph of the store, or from all
+/// graphs if no graph is given, ordered by their IRI.
+///
+/// Besides the nodes typed `sh:NodeShape` this includes every node
+/// that has a target, as the SHACL spec does not require the type.
+pub fn read_node_shapes(
+    store: &Store,
+    graph: Option<GraphNameRef>,
+) -> Vec<NodeShape> {
+    let mut nodes: Vec<Subject> = store
+        .quads_for_pattern(
+            None,
+            Some(OXI_RDF_TYPE.as_ref()),
+            Some(OXI_CLASS_SHACL_NODE_SHAPE.as_ref().into()),
+            graph,
+        )
+        .filter_map(Result::ok)
+        .map(|quad| quad.subject)
+        .collect();
+    for predicate in [
+        &*OXI_SHACL_TARGET_CLASS,
+        &*OXI_SHACL_TARGET_NODE,
+        &*OXI_SHACL_TARGET_SUBJECTS_OF,
+        &*OXI_SHACL_TARGET_OBJECTS_OF,
+    ] {
+        nodes.extend(
+            store
+                .quads_for_pattern(None, Some(predicate.as_ref()), None, graph)
+                .filter_map(Result::ok)
+                .map(|quad| quad.subject),
+        );
+    }
+    nodes.sort_by_key(|node| node.to_string());
+    nodes.dedup();
+    nodes
+        .into_iter()
+        .map(|node| read_node_shape(store, node, graph))
+        .collect()
+}
+
+fn read_node_shape(
+    store: &Store,
+    node: Subject,
+    graph: Option<GraphNameRef>,
+) -> NodeShape {
+    let subject = node.as_ref();
+    let named_nodes = |predicate: &NamedNode| -> Vec<NamedNode> {
+        objects(store, subject, predicate, graph)
+            .into_iter()
+            .filter_map(named_node)
+            .collect()
+    };
+
+    let mut targets: Vec<ShapeTarget> = named_nodes(&OXI_SHACL_TARGET_CLASS)
+        .into_iter()
+        .map(ShapeTarget::Class)
+        .collect();
+    if let Subject::NamedNode(class) = &node {
+        if is_a(store, subject, &OXI_CLASS_RDFS_CLASS, graph) ||
+            is_a(store, subject, &OXI_CLASS_OWL_CLASS, graph)
+        {
+            targets.push(ShapeTarget::ImplicitClass(class.clone()));
+        }
+    }
+    targets.extend(
+        objects(store, subject, &OXI_SHACL_TARGET_NODE, graph)
+            .into_iter()
+            .map(ShapeTarget::Node),
+    );
+    targets.extend(
+        named_nodes(&OXI_SHACL_TARGET_SUBJECTS_OF)
+            .into_iter()
+            .map(ShapeTarget::SubjectsOf),
+    );
+    targets.extend(
+        named_nodes(&OXI_SHACL_TARGET_OBJECTS_OF)
+            .into_iter()
+            .map(ShapeTarget::ObjectsOf),
+    );
+
+    let mut properties: Vec<PropertyShape> =
+        objects(store, subject, &OXI_SHACL_PROPERTY, graph)
+            .iter()
+            .filter_map(as_subject)
+            .map(|property| read_property_shape(store, property, graph))
+            .collect();
+    properties.sort_by(|a, b| {
+        let path = |p: &PropertyShape| {
+            p.path.as_ref().map(|path| path.to_sparql(None))
+        };
+        a.order
+            .unwrap_or(f64::MAX)
+            .total_cmp(&b.order.unwrap_or(f64::MAX))
+            .then_with(|| path(a).cmp(&path(b)))
+    });
+
+    NodeShape {
+        label: string(store, subject, &OXI_RDFS_LABEL, graph)
+            .or_else(|| string(store, subject, &OXI_SHACL_NAME, graph)),
+        description: string(store, subject, &OXI_SHACL_DESCRIPTION, graph),
+        targets,
+        closed: boolean(store, subject, &OXI_SHACL_CLOSED, graph),
+        ignored_properties: object(
+            store,
+            subject,
+            &OXI_SHACL_IGNORED_PROPERTIES,
+            graph,
+        )
+        .map(|list| {
+            list_members(store, &list, graph)
+                .into_iter()
+                .filter_map(named_node)
+                .collect()
+        })
+        .unwrap_or_default(),
+        deactivated: boolean(store, subject, &OXI_SHACL_DEACTIVATED, graph),
+        severity: object(store, subject, &OXI_SHACL_SEVERITY, graph)
+            .and_then(named_node),
+        messages: strings(store, subject, &OXI_SHACL_MESSAGE, graph),
+        properties,
+        node,
+    }
+}
+
+fn read_property_shape(
+    store: &Store,
+    node: Subject,
+    graph: Option<GraphNameRef>,
+) -> PropertyShape {
+    let subject = node.as_ref();
+    let named = |predicate: &NamedNode| {
+        object(store, subject, predicate, graph).and_then(named_node)
+    };
+    let number = |predicate: &NamedNode| {
+        string(store, subject, predicate, graph)
+            .and_then(|value| value.parse::<usize>().ok())
+    };
+    PropertyShape {
+        path: object(store, subject, &OXI_SHACL_PATH, graph)
+            .and_then(|path| ShaclPath::read(store, &path, graph)),
+        name: string(store, subject, &OXI_SHACL_NAME, graph),
+        description: string(store, subject, &OXI_SHACL_DESCRIPTION, graph),
+        datatype: named(&OXI_SHACL_DATATYPE),
+        class: named(&OXI_SHACL_CLASS),
+        node_kind: named(&OXI_SHACL_NODE_KIND),
+        node_shape: object(store, subject, &OXI_SHACL_NODE, graph),
+        min_count: number(&OXI_SHACL_MIN_COUNT),
+        max_count: number(&OXI_SHACL_MAX_COUNT),
+        min_length: number(&OXI_SHACL_MIN_LENGTH),
+        max_length: number(&OXI_SHACL_MAX_LENGTH),
+        pattern: string(store, subject, &OXI_SHACL_PATTERN, graph),
+        flags: string(store, subject, &OXI_SHACL_FLAGS, graph),
+        in_values: object(store, subject, &OXI_SHACL_IN, graph)
+            .map(|list| list_members(store, &list, graph))
+            .unwrap_or_default(),
+        has_value: object(store, subject, &OXI_SHACL_HAS_VALUE, graph),
+        severity: named(&OXI_SHACL_SEVERITY),
+        messages: strings(store, subject, &OXI_SHACL_MESSAGE, graph),
+        order: string(store, subject, &OXI_SHACL_ORDER, graph)
+            .and_then(|value| value.parse::<f64>().ok()),
+        node,
+    }
+}
+
+fn is_a(
+    store: &Store,
+    subject: SubjectRef,
+    class: &NamedNode,
+    graph: Option<GraphNameRef>,
+) -> bool {
+    objects(store, subject, &OXI_RDF_TYPE, graph)
+        .iter()
+        .any(|term| matches!(term, Term::NamedNode(node) if node == class))
+}
+
+fn named_node(term: Term) -> Option<NamedNode> {
+    match term {
+        Term::NamedNode(node) => Some(node),
+        _ => None,
+    }
+}
+
+/// Returns the lexical values of the literal objects.
+fn strings(
+    store: &Store,
+    subject: SubjectRef,
+    predicate: &NamedNode,
+    graph: Option<GraphNameRef>,
+) -> Vec<String> {
+    objects(store, subject, predicate, graph)
+        .into_iter()
+        .filter_map(|term| {
+            match term {
+                Term::Literal(literal) => Some(literal.value().to_string()),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+fn string(
+    store: &Store,
+    subject: SubjectRef,
+    predicate: &NamedNode,
+    graph: Option<GraphNameRef>,
+) -> Option<String> {
+    strings(store, subject, predicate, graph).into_iter().next()
+}
+
+fn boolean(
+    store: &Store,
+    subject: SubjectRef,
+    predicate: &NamedNode,
+    graph: Option<GraphNameRef>,
+) -> bool {
+    string(store, subject, predicate, graph).as_deref() == Some("true")
+}
@@ -0,0 +1,460 @@
+use {
+    super::{NodeShape, PropertyShape, ShaclPath, ShapeTarget},
+    crate::{
+        rdf_const::{
+            namespaces::NS_SHACL,
+            predicates::{OXI_RDF_TYPE, OXI_RDFS_SUB_CLASS_OF},
+        },
+        store::traversal::as_subject,
+    },
+    oxigraph::{sparql::QueryResults, store::Store},
+    oxrdf::{GraphNameRef, Literal, NamedNode, Subject, Term},
+    std::{collections::HashSet, fmt::Display},
+};
+
+/// A value that does not conform to a constraint of a shape, a
+/// `sh:ValidationResult` of a SHACL validation report.
+#[derive(Debug, Clone)]
+pub struct ShaclViolation {
+    /// `sh:focusNode`
+    pub focus_node: Term,
+    /// `sh:resultPath`, not set for the constraints of a node shape
+    pub path:       Option<ShaclPath>,
+    /// `sh:value`, not set for cardinality constraints
+    pub value:      Option<Term>,
+    /// `sh:sourceShape`, the node shape or property shape
+    pub shape:      Subject,
+    /// The local name of `sh:sourceConstraintComponent`, like
+    /// `MinCountConstraintComponent`
+    pub component:  &'static str,
+    /// `sh:resultSeverity`, `sh:Violation` unless the shape says
+    /// otherwise
+    pub severity:   NamedNode,
+    /// `sh:resultMessage`
+    pub message:    String,
+}
+
+impl Display for ShaclViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.focus_node)?;
+        if let Some(path) = &self.path {
+            write!(f, " {}", path.to_sparql(None))?;
+        }
+        if let Some(value) = &self.value {
+            write!(f, " {}", value)?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// Validates the given graph (or all graphs) of the store against the
+/// given node shapes and returns the violations, grouped by shape.
+///
+/// Supported are all targets and the core constraints `sh:class`,
+/// `sh:datatype`, `sh:nodeKind`, `sh:minCount`, `sh:maxCount`,
+/// `sh:minLength`, `sh:maxLength`, `sh:pattern`, `sh:in`,
+/// `sh:hasValue` and `sh:closed`. Other constraints, such as `sh:node`
+/// and the logical constraints, are ignored.
+pub fn validate_shapes(
+    shapes: &[NodeShape],
+    data: &Store,
+    graph: Option<GraphNameRef>,
+) -> anyhow::Result<Vec<ShaclViolation>> {
+    let mut violations = Vec::new();
+    for shape in shapes.iter().filter(|shape| !shape.deactivated) {
+        for focus in focus_nodes(shape, data, graph) {
+            validate_closed(shape, &focus, data, graph, &mut violations);
+            for property in &shape.properties {
+                validate_property(
+                    shape,
+                    property,
+                    &focus,
+                    data,
+                    graph,
+                    &mut violations,
+                )?;
+            }
+        }
+    }
+    Ok(violations)
+}
+
+/// Returns the focus nodes of the targets of the given shape, in the
+/// order of the targets.
+fn focus_nodes(
+    shape: &NodeShape,
+    data: &Store,
+    graph: Option<GraphNameRef>,
+) -> Vec<Term> {
+    let mut seen = HashSet::new();
+    let mut nodes = Vec::new();
+    for target in &shape.targets {
+        let found = match target {
+            ShapeTarget::Class(class) | ShapeTarget::ImplicitClass(class) => {
+                ShaclPath::Inverse(Box::new(instance_of_path())).values(
+                    data,
+                    &class.clone().into(),
+                    graph,
+                )
+            },
+            ShapeTarget::Node(node) => vec![node.clone()],
+            ShapeTarget::SubjectsOf(predicate) => {
+                data.quads_for_pattern(
+                    None,
+                    Some(predicate.as_ref()),
+                    None,
+                    graph,
+                )
+                .filter_map(Result::ok)
+                .map(|quad| quad.subject.into())
+                .collect()
+            },
+            ShapeTarget::ObjectsOf(predicate) => {
+                data.quads_for_pattern(
+                    None,
+                    Some(predicate.as_ref()),
+                    None,
+                    graph,
+                )
+                .filter_map(Result::ok)
+                .map(|quad| quad.object)
+                .collect()
+            },
+        };
+        nodes
+            .extend(found.into_iter().filter(|node| seen.insert(node.clone())));
+    }
+    nodes
+}
+
+/// The path `rdf:type/rdfs:subClassOf*` from an instance to its
+/// classes.
+fn instance_of_path() -> ShaclPath {
+    ShaclPath::Sequence(vec![
+        ShaclPath::Predicate(OXI_RDF_TYPE.clone()),
+        ShaclPath::ZeroOrMore(Box::new(ShaclPath::Predicate(
+            OXI_RDFS_SUB_CLASS_OF.clone(),
+        ))),
+    ])
+}
+
+/// Checks `sh:closed`, the focus node may only have the properties
+/// that are the (predicate) paths of the property shapes, or that are
+/// listed in `sh:ignoredProperties`.
+fn validate_closed(
+    shape: &NodeShape,
+    focus: &Term,
+    data: &Store,
+    graph: Option<GraphNameRef>,
+    violations: &mut Vec<ShaclViolation>,
+) {
+    if !shape.closed {
+        return;
+    }
+    let Some(subject) = as_subject(focus) else {
+        return;
+    };
+    let allowed: HashSet<&NamedNode> = shape
+        .properties
+        .iter()
+        .filter_map(|property| {
+            match &property.path {
+                Some(ShaclPath::Predicate(predicate)) => Some(predicate),
+                _ => None,
+            }
+        })
+        .chain(shape.ignored_properties.iter())
+        .collect();
+    for quad in data
+        .quads_for_pattern(Some(subject.as_ref()), None, None, graph)
+        .filter_map(Result::ok)
+    {
+        if !allowed.contains(&quad.predicate) {
+            violations.push(ShaclViolation {
+                focus_node: focus.clone(),
+                path:       Some(ShaclPath::Predicate(quad.predicate.clone())),
+                value:      Some(quad.object),
+                shape:      shape.node.clone(),
+                component:  "ClosedConstraintComponent",
+                severity:   severity(shape.severity.as_ref()),
+                message:    shape.messages.first().cloned().unwrap_or_else(
+                    || {
+                        format!(
+                            "Predicate {} is not allowed (closed shape)",
+                            quad.predicate
+                        )
+                    },
+                ),
+            });
+        }
+    }
+}
+
+fn validate_property(
+    shape: &NodeShape,
+    property: &PropertyShape,
+    focus: &Term,
+    data: &Store,
+    graph: Option<GraphNameRef>,
+    violations: &mut Vec<ShaclViolation>,
+) -> anyhow::Result<()> {
+    let Some(path) = &property.path else {
+        return Ok(());
+    };
+    let values = path.values(data, focus, graph);
+    let mut report = |component, value: Option<&Term>, message: String| {
+        violations.push(ShaclViolation {
+            focus_node: focus.clone(),
+            path: Some(path.clone()),
+            value: value.cloned(),
+            shape: property.node.clone(),
+            component,
+            severity: severity(
+                property.severity.as_ref().or(shape.severity.as_ref()),
+            ),
+            message: property.messages.first().cloned().unwrap_or(message),
+        });
+    };
+
+    if let Some(min_count) = property.min_count {
+        if values.len() < min_count {
+            report(
+                "MinCountConstraintComponent",
+                None,
+                format!(
+                    "Less than {} values ({} values)",
+                    min_count,
+                    values.len()
+                ),
+            );
+        }
+    }
+    if let Some(max_count) = property.max_count {
+        if values.len() > max_count {
+            report(
+                "MaxCountConstraintComponent",
+                None,
+                format!(
+                    "More than {} values ({} values)",
+                    max_count,
+                    values.len()
+                ),
+            );
+        }
+    }
+    if let Some(has_value) = &property.has_value {
+        if !values.contains(has_value) {
+            report(
+                "HasValueConstraintComponent",
+                None,
+                format!("Missing the value {}", has_value),
+            );
+        }
+    }
+
+    for value in &values {
+        if let Some(datatype) = &property.datatype {
+            let conforms = matches!(
+                value,
+                Term::Literal(literal) if literal.datatype() == datatype.as_ref()
+            );
+            if !conforms {
+                report(
+                    "DatatypeConstraintComponent",
+                    Some(value),
+                    format!("Value does not have datatype {}", datatype),
+                );
+            }
+        }
+        if let Some(class) = &property.class {
+            let conforms = instance_of_path()
+                .values(data, value, graph)
+                .iter()
+                .any(|term| matches!(term, Term::NamedNode(node) if node == class));
+            if !conforms {
+                report(
+                    "ClassConstraintComponent",
+                    Some(value),
+                    format!("Value is not an instance of {}", class),
+                );
+            }
+        }
+        if let Some(node_kind) = &property.node_kind {
+            if !has_node_kind(value, node_kind) {
+                report(
+                    "NodeKindConstraintComponent",
+                    Some(value),
+                    format!("Value does not have node kind {}", node_kind),
+                );
+            }
+        }
+        let length = match value {
+            Term::NamedNode(node) => Some(node.as_str().chars().count()),
+            Term::Literal(literal) => Some(literal.value().chars().count()),
+            _ => None,
+        };
+        if let Some(min_length) = property.min_length {
+            if length.is_none_or(|length| length < min_length) {
+                report(
+                    "MinLengthConstraintComponent",
+                    Some(value),
+                    format!("Value is shorter than {} characters", min_length),
+                );
+            }
+        }
+        if let Some(max_length) = property.max_length {
+            if length.is_none_or(|length| length > max_length) {
+                report(
+                    "MaxLengthConstraintComponent",
+                    Some(value),
+                    format!("Value is longer than {} characters", max_length),
+                );
+            }
+        }
+        if let Some(pattern) = &property.pattern {
+            if !matches_pattern(data, value, pattern, property.flags.as_ref())?
+            {
+                report(
+                    "PatternConstraintComponent",
+                    Some(value),
+                    format!("Value does not match the pattern {}", pattern),
+                );
+            }
+        }
+        if !property.in_values.is_empty() && !property.in_values.contains(value)
+        {
+            report(
+                "InConstraintComponent",
+                Some(value),
+                "Value is not one of the allowed values".to_string(),
+            );
+        }
+    }
+    Ok(())
+}
+
+fn severity(severity: Option<&NamedNode>) -> NamedNode {
+    severity.cloned().unwrap_or_else(|| {
+        NamedNode::new_unchecked(format!("{}Violation", NS_SHACL))
+    })
+}
+
+/// Returns true if the value has the given `sh:nodeKind`, like
+/// `sh:IRI` or `sh:BlankNodeOrLiteral`.
+fn has_node_kind(value: &Term, node_kind: &NamedNode) -> bool {
+    let kind = node_kind
+        .as_str()
+        .strip_prefix(NS_SHACL)
+        .unwrap_or_default();
+    match value {
+        Term::NamedNode(_) => {
+            matches!(kind, "IRI" | "BlankNodeOrIRI" | "IRIOrLiteral")
+        },
+        Term::BlankNode(_) => {
+            matches!(
+                kind,
+                "BlankNode" | "BlankNodeOrIRI" | "BlankNodeOrLiteral"
+            )
+        },
+        Term::Literal(_) => {
+            matches!(
+                kind,
+                "Literal" | "BlankNodeOrLiteral" | "IRIOrLiteral"
+            )
+        },
+        #[allow(unreachable_patterns)]
+        _ => false,
+    }
+}
+
+/// Returns true if the string value of the given value matches the
+/// given `sh:pattern`, evaluated with the SPARQL `REGEX` function as
+/// the SHACL spec prescribes. Blank nodes never match.
+fn matches_pattern(
+    data: &Store,
+    value: &Term,
+    pattern: &str,
+    flags: Option<&String>,
+) -> anyhow::Result<bool> {
+    let text = match value {
+        Term::NamedNode(node) => node.as_str(),
+        Term::Literal(literal) => literal.value(),
+        _ => return Ok(false),
+    };
+    let query = format!(
+        "ASK {{ FILTER(REGEX({}, {}, {})) }}",
+        Literal::new_simple_literal(text),
+        Literal::new_simple_literal(pattern),
+        Literal::new_simple_literal(flags.map(String::as_str).unwrap_or(""))
+    );
+    match data.query(query.as_str())? {
+        QueryResults::Boolean(result) => Ok(result),
+        _ => Ok(false),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::store::read_node_shapes, oxigraph::io::RdfFormat};
+
+    #[test]
+    fn test_validate() -> anyhow::Result<()> {
+        let shapes = Store::new()?;
+        shapes.load_from_reader(
+            RdfFormat::Turtle,
+            r#"
+            @prefix ex: <http://example.com/> .
+            @prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+            @prefix sh: <http://www.w3.org/ns/shacl#> .
+            @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+            ex:PersonShape a sh:NodeShape ;
+                sh:targetClass ex:Person ;
+                sh:closed true ;
+                sh:ignoredProperties ( rdf:type ) ;
+                sh:property [
+                    sh:path ex:name ;
+                    sh:datatype xsd:string ;
+                    sh:minCount 1 ;
+                    sh:maxCount 1 ;
+                    sh:pattern "^[A-Z]" ;
+                ], [
+                    sh:path ex:knows ;
+                    sh:class ex:Person ;
+                ] .
+            "#
+            .as_bytes(),
+        )?;
+        let data = Store::new()?;
+        data.load_from_reader(
+            RdfFormat::Turtle,
+            r#"
+            @prefix ex: <http://example.com/> .
+            @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+
+            ex:Employee rdfs:subClassOf ex:Person .
+            ex:alice a ex:Person ; ex:name "Alice" ; ex:knows ex:bob .
+            ex:bob a ex:Employee ; ex:name "bob", "Bob" ; ex:knows ex:carol .
+            ex:carol ex:name "Carol" ; ex:age 42 .
+            "#
+            .as_bytes(),
+        )?;
+
+        let violations =
+            validate_shapes(&read_node_shapes(&shapes, None), &data, None)?;
+        let mut components: Vec<&str> = violations
+            .iter()
+            .map(|violation| violation.component)
+            .collect();
+        components.sort();
+        assert_eq!(components, [
+            "ClassConstraintComponent",
+            "MaxCountConstraintComponent",
+            "PatternConstraintComponent",
+        ]);
+        assert!(violations.iter().all(|violation| {
+            violation.focus_node.to_string() == "<http://example.com/bob>"
+        }));
+        Ok(())
+    }
+}
@@ -0,0 +1,202 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The `skos:broader`/`skos:narrower` hierarchy of the concepts of one
+/// concept scheme.
+///
+/// Relations to concepts outside of the scheme are ignored.
+#[derive(Debug, Clone, Default)]
+pub struct ConceptHierarchy {
+    /// The broader concepts per concept
+    broader:  BTreeMap<String, BTreeSet<String>>,
+    /// The narrower concepts per concept
+    narrower: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl ConceptHierarchy {
+    /// Creates the hierarchy from the given concepts with their
+    /// broader concepts.
+    pub fn new<'a>(
+        concepts: impl IntoIterator<Item = (&'a str, &'a [String])>,
+    ) -> Self {
+        let concepts: Vec<(&str, &[String])> = concepts.into_iter().collect();
+        let known: BTreeSet<&str> =
+            concepts.iter().map(|(concept, _)| *concept).collect();
+        let mut hierarchy = Self::default();
+        for (concept, broader_concepts) in concepts {
+            hierarchy.broader.entry(concept.to_string()).or_default();
+            hierarchy.narrower.entry(concept.to_string()).or_default();
+            for broader in broader_concepts {
+                if !known.contains(broader.as_str()) {
+                    continue;
+                }
+                hierarchy
+                    .broader
+                    .entry(concept.to_string())
+                    .or_default()
+                    .insert(broader.clone());
+                hierarchy
+                    .narrower
+                    .entry(broader.clone())
+                    .or_default()
+                    .insert(concept.to_string());
+            }
+        }
+        hierarchy
+    }
+
+    /// Returns the concepts that are not one of the given top concepts
+    /// and have no broader concept, so that they hang loose from the
+    /// hierarchy.
+    pub fn orphans(&self, top_concepts: &[String]) -> Vec<String> {
+        self.broader
+            .iter()
+            .filter(|(concept, broader)| {
+                broader.is_empty() && !top_concepts.contains(concept)
+            })
+            .map(|(concept, _)| concept.clone())
+            .collect()
+    }
+
+    /// Returns the cycles in `skos:broader`, each starting at its
+    /// smallest concept IRI.
+    pub fn cycles(&self) -> Vec<Vec<String>> {
+        let mut cycles = BTreeSet::new();
+        let mut done = BTreeSet::new();
+        for concept in self.broader.keys() {
+            let mut path = Vec::new();
+            self.find_cycles(concept, &mut path, &mut done, &mut cycles);
+        }
+        cycles.into_iter().collect()
+    }
+
+    fn find_cycles<'a>(
+        &'a self,
+        concept: &'a String,
+        path: &mut Vec<&'a String>,
+        done: &mut BTreeSet<&'a String>,
+        cycles: &mut BTreeSet<Vec<String>>,
+    ) {
+        if let Some(start) = path.iter().position(|c| *c == concept) {
+            let mut cycle: Vec<String> =
+                path[start..].iter().map(|c| c.to_string()).collect();
+            let smallest = (0..cycle.len())
+                .min_by_key(|i| &cycle[*i])
+                .unwrap_or_default();
+            cycle.rotate_left(smallest);
+            cycles.insert(cycle);
+            return;
+        }
+        if done.contains(concept) {
+            return;
+        }
+        path.push(concept);
+        for broader in self.broader.get(concept).into_iter().flatten() {
+            self.find_cycles(broader, path, done, cycles);
+        }
+        path.pop();
+        done.insert(concept);
+    }
+
+    /// Returns the narrower-tree below the given roots as
+    /// `(depth, concept)` pairs in depth-first order. Concepts that
+    /// would repeat one of their ancestors (a cycle) are left out.
+    pub fn tree(&self, roots: &[String]) -> Vec<(usize, String)> {
+        let mut lines = Vec::new();
+        for root in roots {
+            let mut ancestors = Vec::new();
+            self.push_subtree(root, 0, &mut ancestors, &mut lines);
+        }
+        lines
+    }
+
+    fn push_subtree<'a>(
+        &'a self,
+        concept: &'a String,
+        depth: usize,
+        ancestors: &mut Vec<&'a String>,
+        lines: &mut Vec<(usize, String)>,
+    ) {
+        if ancestors.contains(&concept) {
+            return;
+        }
+        lines.push((depth, concept.clone()));
+        ancestors.push(concept);
+        for narrower in self.narrower.get(concept).into_iter().flatten() {
+            self.push_subtree(narrower, depth + 1, ancestors, lines);
+        }
+        ancestors.pop();
+    }
+
+    /// Returns the roots of the tree: the given top concepts followed
+    /// by the other concepts without a broader concept.
+    pub fn roots(&self, top_concepts: &[String]) -> Vec<String> {
+        let mut roots = top_concepts.to_vec();
+        roots.extend(self.orphans(top_concepts));
+        roots
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConceptHierarchy;
+
+    fn hierarchy(concepts: &[(&str, &[&str])]) -> ConceptHierarchy {
+        let concepts: Vec<(String, Vec<String>)> = concepts
+            .iter()
+            .map(|(concept, broader)| {
+                (
+                    concept.to_string(),
+                    broader.iter().map(|b| b.to_string()).collect(),
+                )
+            })
+            .collect();
+        ConceptHierarchy::new(
+            concepts.iter().map(|(concept, broader)| {
+                (concept.as_str(), broader.as_slice())
+            }),
+        )
+    }
+
+    #[test]
+    fn test_tree_and_orphans() {
+        let hierarchy = hierarchy(&[
+            ("animal", &[]),
+            ("cat", &["animal"]),
+            ("dog", &["animal", "outside"]),
+            ("puppy", &["dog"]),
+            ("plant", &[]),
+        ]);
+        let top_concepts = vec!["animal".to_string()];
+        assert_eq!(hierarchy.orphans(&top_concepts), vec!["plant"]);
+        assert_eq!(
+            hierarchy.tree(&hierarchy.roots(&top_concepts)),
+            vec![
+                (0, "animal".to_string()),
+                (1, "cat".to_string()),
+                (1, "dog".to_string()),
+                (2, "puppy".to_string()),
+                (0, "plant".to_string()),
+            ]
+        );
+        assert!(hierarchy.cycles().is_empty());
+    }
+
+    #[test]
+    fn test_cycles() {
+        let hierarchy = hierarchy(&[
+            ("a", &["c"]),
+            ("b", &["a"]),
+            ("c", &["b"]),
+            ("self", &["self"]),
+            ("top", &[]),
+        ]);
+        assert_eq!(hierarchy.cycles(), vec![
+            vec!["a".to_string(), "c".to_string(), "b".to_string()],
+            vec!["self".to_string()],
+        ]);
+        assert_eq!(hierarchy.tree(&["self".to_string()]), vec![(
+            0,
+            "self".to_string()
+        )]);
+    }
+}
@@ -0,0 +1,13 @@
+mod hierarchy;
+mod scheme;
+
+pub use {
+    hierarchy::ConceptHierarchy,
+    scheme::{
+        SKOSCollection,
+        SKOSConcept,
+        SKOSConceptScheme,
+        SKOSConceptSchemeDescription,
+        SKOSMapping,
+    },
+};
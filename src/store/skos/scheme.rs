@@ -0,0 +1,369 @@
+use {
+    super::ConceptHierarchy,
+    crate::{
+        store::{
+            AnnotationValue,
+            LoaderStore,
+            solution::{iri_value, literal_value},
+            traversal::list_members,
+        },
+        util::Languages,
+    },
+    oxrdf::{NamedNode, Term},
+};
+
+const SKOS_PREFIXES: &str = r#"
+    PREFIX rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#>
+    PREFIX rdfs: <http://www.w3.org/2000/01/rdf-schema#>
+    PREFIX skos: <http://www.w3.org/2004/02/skos/core#>
+    PREFIX dcterms: <http://purl.org/dc/terms/>
+"#;
+
+/// The SKOS mapping properties, in the order in which they are shown.
+const SKOS_MAPPING_PROPERTIES: [&str; 5] = [
+    "exactMatch",
+    "closeMatch",
+    "broadMatch",
+    "narrowMatch",
+    "relatedMatch",
+];
+
+/// Represents a `skos:ConceptScheme` from the source data
+#[derive(Debug, Clone)]
+pub struct SKOSConceptScheme {
+    /// The IRI of the concept scheme
+    pub iri:         String,
+    /// `skos:prefLabel`, `dcterms:title` or `rdfs:label`
+    pub label:       Option<String>,
+    /// `skos:definition`, `dcterms:description` or `rdfs:comment`
+    pub description: Option<String>,
+}
+
+/// A link from a concept to a concept in another scheme, such as
+/// `skos:exactMatch`.
+#[derive(Debug, Clone)]
+pub struct SKOSMapping {
+    /// The local name of the mapping property, like `exactMatch`
+    pub property: String,
+    /// The IRI of the matching concept
+    pub target:   String,
+}
+
+/// A `skos:Concept` with its labels, documentation and relations.
+#[derive(Debug, Clone)]
+pub struct SKOSConcept {
+    /// The IRI of the concept
+    pub iri:         String,
+    /// `skos:prefLabel`, `skos:altLabel` and `skos:hiddenLabel` values
+    pub labels:      Vec<AnnotationValue>,
+    pub notations:   Vec<String>,
+    /// `skos:definition` values
+    pub definitions: Vec<AnnotationValue>,
+    /// `skos:scopeNote` values
+    pub scope_notes: Vec<AnnotationValue>,
+    /// `skos:broader` concepts, including inverse `skos:narrower` links
+    pub broader:     Vec<String>,
+    /// `skos:narrower` concepts, including inverse `skos:broader` links
+    pub narrower:    Vec<String>,
+    /// `skos:related` concepts, in either direction
+    pub related:     Vec<String>,
+    pub mappings:    Vec<SKOSMapping>,
+    /// The collections that the concept is a member of
+    pub collections: Vec<String>,
+}
+
+impl SKOSConcept {
+    /// Returns the preferred label in the most preferred of the given
+    /// languages.
+    pub fn pref_label(&self, languages: &Languages) -> Option<&str> {
+        let pref_labels: Vec<&AnnotationValue> = self
+            .labels
+            .iter()
+            .filter(|l| l.property.ends_with("#prefLabel"))
+            .collect();
+        languages
+            .pick(&pref_labels)
+            .copied()
+            .map(|l| l.value.as_str())
+    }
+}
+
+/// A `skos:Collection` or `skos:OrderedCollection`.
+#[derive(Debug, Clone)]
+pub struct SKOSCollection {
+    /// The IRI of the collection
+    pub iri:     String,
+    pub label:   Option<String>,
+    pub ordered: bool,
+    /// The IRIs of the members, in list order for ordered collections
+    pub members: Vec<String>,
+}
+
+/// Everything that is documented about one concept scheme, see
+/// `LoaderStore::describe_skos_concept_scheme`.
+#[derive(Debug, Clone)]
+pub struct SKOSConceptSchemeDescription {
+    pub scheme:       SKOSConceptScheme,
+    pub top_concepts: Vec<String>,
+    /// The concepts in the scheme, ordered by IRI
+    pub concepts:     Vec<SKOSConcept>,
+    /// The collections with at least one member in the scheme
+    pub collections:  Vec<SKOSCollection>,
+    pub hierarchy:    ConceptHierarchy,
+    /// The concepts that are neither a top concept nor have a broader
+    /// concept in the scheme
+    pub orphans:      Vec<String>,
+    /// The cycles in `skos:broader`
+    pub cycles:       Vec<Vec<String>>,
+}
+
+impl SKOSConceptSchemeDescription {
+    /// Returns the concept with the given IRI, if it is in the scheme.
+    pub fn concept(&self, iri: &str) -> Option<&SKOSConcept> {
+        self.concepts.iter().find(|c| c.iri == iri)
+    }
+}
+
+impl LoaderStore {
+    /// Returns the concept schemes in the given graph, or in all graphs
+    /// if no graph is given.
+    pub fn find_skos_concept_schemes(
+        &self,
+        graph: Option<&NamedNode>,
+    ) -> anyhow::Result<Vec<SKOSConceptScheme>> {
+        let pattern = match graph {
+            Some(graph) => {
+                format!("GRAPH {graph} {{ ?scheme a skos:ConceptScheme }}")
+            },
+            None => "?scheme a skos:ConceptScheme".to_string(),
+        };
+        let query = format!(
+            r#"{SKOS_PREFIXES}
+            SELECT ?scheme (SAMPLE(?l) AS ?label) (SAMPLE(?d) AS ?description)
+            WHERE {{
+                {pattern}
+                FILTER(isIRI(?scheme))
+                OPTIONAL {{
+                    ?scheme skos:prefLabel|dcterms:title|rdfs:label ?l
+                }}
+                OPTIONAL {{
+                    ?scheme skos:definition|dcterms:description|rdfs:comment ?d
+                }}
+            }}
+            GROUP BY ?scheme
+            ORDER BY ?scheme
+            "#
+        );
+        Ok(self
+            .solutions(&query)?
+            .iter()
+            .filter_map(|solution| {
+                Some(SKOSConceptScheme {
+                    iri:         iri_value(solution, "scheme")?,
+                    label:       literal_value(solution, "label"),
+                    description: literal_value(solution, "description"),
+                })
+            })
+            .collect())
+    }
+
+    /// Returns the concepts, collections and hierarchy of the given
+    /// concept scheme.
+    pub fn describe_skos_concept_scheme(
+        &self,
+        scheme: &SKOSConceptScheme,
+    ) -> anyhow::Result<SKOSConceptSchemeDescription> {
+        let iri = &scheme.iri;
+        let top_concepts = self.iris(&format!(
+            "{{ <{iri}> skos:hasTopConcept ?term }}
+             UNION {{ ?term skos:topConceptOf <{iri}> }}
+             FILTER(isIRI(?term))"
+        ))?;
+        let concept_iris = self.iris(&format!(
+            "{{ ?term skos:inScheme|skos:topConceptOf <{iri}> }}
+             UNION {{ <{iri}> skos:hasTopConcept ?term }}
+             FILTER(isIRI(?term))"
+        ))?;
+        let collections = self
+            .skos_collections()?
+            .into_iter()
+            .filter(|c| c.members.iter().any(|m| concept_iris.contains(m)))
+            .collect::<Vec<SKOSCollection>>();
+
+        let mut concepts = Vec::new();
+        for concept in &concept_iris {
+            let mut concept = self.skos_concept(concept)?;
+            concept.collections = collections
+                .iter()
+                .filter(|c| c.members.contains(&concept.iri))
+                .map(|c| c.iri.clone())
+                .collect();
+            concepts.push(concept);
+        }
+
+        let hierarchy = ConceptHierarchy::new(
+            concepts
+                .iter()
+                .map(|c| (c.iri.as_str(), c.broader.as_slice())),
+        );
+        Ok(SKOSConceptSchemeDescription {
+            scheme: scheme.clone(),
+            orphans: hierarchy.orphans(&top_concepts),
+            cycles: hierarchy.cycles(),
+            hierarchy,
+            top_concepts,
+            concepts,
+            collections,
+        })
+    }
+
+    fn skos_concept(&self, iri: &str) -> anyhow::Result<SKOSConcept> {
+        let mut mappings = Vec::new();
+        for property in SKOS_MAPPING_PROPERTIES {
+            for target in self.iris(&format!(
+                "<{iri}> skos:{property} ?term . FILTER(isIRI(?term))"
+            ))? {
+                mappings.push(SKOSMapping {
+                    property: property.to_string(),
+                    target,
+                });
+            }
+        }
+        Ok(SKOSConcept {
+            iri: iri.to_string(),
+            labels: self.annotation_values(
+                iri,
+                "skos:prefLabel skos:altLabel skos:hiddenLabel",
+            )?,
+            notations: self
+                .annotation_values(iri, "skos:notation")?
+                .into_iter()
+                .map(|notation| notation.value)
+                .collect(),
+            definitions: self.annotation_values(iri, "skos:definition")?,
+            scope_notes: self.annotation_values(iri, "skos:scopeNote")?,
+            broader: self.iris(&format!(
+                "{{ <{iri}> skos:broader ?term }}
+                 UNION {{ ?term skos:narrower <{iri}> }}
+                 FILTER(isIRI(?term))"
+            ))?,
+            narrower: self.iris(&format!(
+                "{{ <{iri}> skos:narrower ?term }}
+                 UNION {{ ?term skos:broader <{iri}> }}
+                 FILTER(isIRI(?term))"
+            ))?,
+            related: self.iris(&format!(
+                "{{ <{iri}> skos:related ?term }}
+                 UNION {{ ?term skos:related <{iri}> }}
+                 FILTER(isIRI(?term) && ?term != <{iri}>)"
+            ))?,
+            mappings,
+            collections: Vec::new(),
+        })
+    }
+
+    /// Returns all collections in the store with their members.
+    fn skos_collections(&self) -> anyhow::Result<Vec<SKOSCollection>> {
+        let query = format!(
+            r#"{SKOS_PREFIXES}
+            SELECT ?collection (SAMPLE(?l) AS ?label) (SAMPLE(?o) AS ?ordered)
+                   (SAMPLE(?m) AS ?memberList)
+            WHERE {{
+                ?collection a ?type .
+                VALUES ?type {{ skos:Collection skos:OrderedCollection }}
+                FILTER(isIRI(?collection))
+                BIND(EXISTS {{
+                    ?collection a skos:OrderedCollection
+                }} AS ?o)
+                OPTIONAL {{ ?collection skos:prefLabel|rdfs:label ?l }}
+                OPTIONAL {{ ?collection skos:memberList ?m }}
+            }}
+            GROUP BY ?collection
+            ORDER BY ?collection
+            "#
+        );
+        let store = self.store();
+        let mut collections = Vec::new();
+        for solution in self.solutions(&query)? {
+            let Some(iri) = iri_value(&solution, "collection") else {
+                continue;
+            };
+            let members = match solution.get("memberList") {
+                Some(list) => {
+                    list_members(&store, list, None)
+                        .into_iter()
+                        .filter_map(|member| {
+                            match member {
+                                Term::NamedNode(node) => {
+                                    Some(node.as_str().to_string())
+                                },
+                                _ => None,
+                            }
+                        })
+                        .collect()
+                },
+                None => {
+                    self.iris(&format!(
+                        "<{iri}> skos:member ?term . FILTER(isIRI(?term))"
+                    ))?
+                },
+            };
+            collections.push(SKOSCollection {
+                label: literal_value(&solution, "label"),
+                ordered: literal_value(&solution, "ordered").as_deref() ==
+                    Some("true"),
+                members,
+                iri,
+            });
+        }
+        Ok(collections)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pref_label() {
+        let label = |property: &str, value: &str, language: &str| {
+            AnnotationValue {
+                property: format!(
+                    "http://www.w3.org/2004/02/skos/core#{}",
+                    property
+                ),
+                value:    value.to_string(),
+                language: Some(language.to_string()),
+            }
+        };
+        let concept = SKOSConcept {
+            iri:         "http://example.com/car".to_string(),
+            labels:      vec![
+                label("altLabel", "automobile", "en"),
+                label("prefLabel", "car", "en"),
+                label("prefLabel", "auto", "nl"),
+            ],
+            notations:   Vec::new(),
+            definitions: Vec::new(),
+            scope_notes: Vec::new(),
+            broader:     Vec::new(),
+            narrower:    Vec::new(),
+            related:     Vec::new(),
+            mappings:    Vec::new(),
+            collections: Vec::new(),
+        };
+        assert_eq!(
+            concept.pref_label(&Languages::new(["nl", "en"])),
+            Some("auto")
+        );
+        assert_eq!(
+            concept.pref_label(&Languages::new(["de", "en"])),
+            Some("car")
+        );
+        // Without a label in a preferred language, the first one
+        assert_eq!(
+            concept.pref_label(&Languages::new(["de"])),
+            Some("car")
+        );
+    }
+}
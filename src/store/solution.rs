@@ -0,0 +1,31 @@
+use {oxigraph::sparql::QuerySolution, oxrdf::TermRef};
+
+/// Returns the lexical value of the literal bound to the given
+/// variable, if any.
+pub(crate) fn literal_value(
+    solution: &QuerySolution,
+    name: &str,
+) -> Option<String> {
+    match solution.get(name)?.as_ref() {
+        TermRef::Literal(l) => Some(l.value().to_string()),
+        _ => None,
+    }
+}
+
+/// Returns the IRI bound to the given variable, if any.
+pub(crate) fn iri_value(
+    solution: &QuerySolution,
+    name: &str,
+) -> Option<String> {
+    match solution.get(name)?.as_ref() {
+        TermRef::NamedNode(n) => Some(n.as_str().to_string()),
+        _ => None,
+    }
+}
+
+/// Returns the integer literal bound to the given variable, or zero.
+pub(crate) fn count_value(solution: &QuerySolution, name: &str) -> usize {
+    literal_value(solution, name)
+        .and_then(|v| v.parse().ok())
+        .unwrap_or_default()
+}
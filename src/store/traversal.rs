@@ -0,0 +1,98 @@
+use {
+    crate::rdf_const::predicates::{OXI_RDF_FIRST, OXI_RDF_REST},
+    oxigraph::store::Store,
+    oxrdf::{GraphNameRef, NamedNode, Subject, SubjectRef, Term, TermRef},
+};
+
+/// RDF lists longer than this are cut off, which also protects
+/// against cyclic (malformed) lists.
+const MAX_LIST_LENGTH: usize = 1024;
+
+/// Returns the first object of the given subject and predicate in the
+/// given graph, or in any graph of the store if no graph is given.
+pub(crate) fn object(
+    store: &Store,
+    subject: SubjectRef,
+    predicate: &NamedNode,
+    graph: Option<GraphNameRef>,
+) -> Option<Term> {
+    objects(store, subject, predicate, graph).into_iter().next()
+}
+
+/// Returns all objects of the given subject and predicate in the
+/// given graph, or in any graph of the store if no graph is given.
+pub(crate) fn objects(
+    store: &Store,
+    subject: SubjectRef,
+    predicate: &NamedNode,
+    graph: Option<GraphNameRef>,
+) -> Vec<Term> {
+    let mut objects: Vec<Term> = store
+        .quads_for_pattern(
+            Some(subject),
+            Some(predicate.as_ref()),
+            None,
+            graph,
+        )
+        .filter_map(Result::ok)
+        .map(|quad| quad.object)
+        .collect();
+    objects.dedup();
+    objects
+}
+
+/// Returns all subjects of the given predicate and object in the
+/// given graph, or in any graph of the store if no graph is given.
+pub(crate) fn subjects(
+    store: &Store,
+    predicate: &NamedNode,
+    object: TermRef,
+    graph: Option<GraphNameRef>,
+) -> Vec<Subject> {
+    let mut subjects: Vec<Subject> = store
+        .quads_for_pattern(
+            None,
+            Some(predicate.as_ref()),
+            Some(object),
+            graph,
+        )
+        .filter_map(Result::ok)
+        .map(|quad| quad.subject)
+        .collect();
+    subjects.dedup();
+    subjects
+}
+
+/// Returns the members of the RDF list that starts at `list`.
+pub(crate) fn list_members(
+    store: &Store,
+    list: &Term,
+    graph: Option<GraphNameRef>,
+) -> Vec<Term> {
+    let mut members = Vec::new();
+    let mut current = list.clone();
+    while members.len() < MAX_LIST_LENGTH {
+        let Some(node) = as_subject(&current) else {
+            break;
+        };
+        let Some(first) = object(store, node.as_ref(), &OXI_RDF_FIRST, graph)
+        else {
+            break;
+        };
+        members.push(first);
+        match object(store, node.as_ref(), &OXI_RDF_REST, graph) {
+            Some(rest) => current = rest,
+            None => break,
+        }
+    }
+    members
+}
+
+/// Returns the given term as a subject, unless it is a literal.
+pub(crate) fn as_subject(term: &Term) -> Option<Subject> {
+    match term {
+        Term::NamedNode(node) => Some(node.clone().into()),
+        Term::BlankNode(node) => Some(node.clone().into()),
+        _ => None,
+    }
+}
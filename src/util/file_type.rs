@@ -8,6 +8,19 @@ pub type FileTypeSliceStatic = FileTypeSlice<'static>;
 
 const JEKYLL_CONFIG_FILE_NAME: &str = "_config.yml";
 
+/// The (lower case) stems of the names of license files, such as
+/// `LICENSE`, `LICENSE.md`, `LICENSE-MIT` or `COPYING.txt`.
+const LICENSE_FILE_STEMS: [&str; 4] =
+    ["license", "licence", "copying", "unlicense"];
+
+/// The extensions that a license file can have, if any.
+const LICENSE_FILE_EXTENSIONS: [&str; 3] = ["md", "txt", "markdown"];
+
+/// The glob for the ignore crate that selects license files, see
+/// `is_license_file_name` for the exact check.
+const LICENSE_FILE_GLOB: &str =
+    "{LICEN[CS]E,[Ll]icen[cs]e,COPYING,[Cc]opying,UNLICENSE}*";
+
 /// Enum representing various file types.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub enum FileType {
@@ -23,6 +36,7 @@ pub enum FileType {
     YAML,
     JSON,
     JekyllConfig,
+    License,
 }
 
 impl FileType {
@@ -45,6 +59,7 @@ impl FileType {
                     .unwrap_or("Unknown")
             },
             FileType::JekyllConfig => "Jekyll Configuration",
+            FileType::License => "License",
         }
     }
 
@@ -66,6 +81,7 @@ impl FileType {
                     .map(|f| OsStr::new(f.file_extension()))
             },
             FileType::JekyllConfig => Some(OsStr::new("yml")),
+            FileType::License => None,
         }
     }
 
@@ -100,6 +116,7 @@ impl FileType {
             },
             FileType::JSON => Some("application/json"),
             FileType::JSONLD => Some("application/ld+json"),
+            FileType::License => Some("text/plain"),
             FileType::N3 |
             FileType::NQuads |
             FileType::NTriples |
@@ -115,6 +132,13 @@ impl FileType {
     pub fn is_of_type<P: AsRef<Path>>(&self, file_name: P) -> bool {
         let path = file_name.as_ref();
         if path.is_file() {
+            // License files are only of the License type, even when
+            // they have an extension like `.md`
+            if *self == FileType::License {
+                return is_license_file_name(path);
+            } else if is_license_file_name(path) {
+                return false;
+            }
             if let Some(file_name) = self.file_name() {
                 return file_name == path.file_name().unwrap();
             } else if let Some(extension) = self.extension() {
@@ -155,6 +179,9 @@ impl FileType {
                 return Some(&FileType::JekyllConfig);
             }
         }
+        if is_license_file_name(path) {
+            return Some(&FileType::License);
+        }
         // Then check the extension.
         if let Some(extension) = path.extension() {
             if let Some(file_type) = FileType::from_extension(extension) {
@@ -201,7 +228,13 @@ impl FileType {
     }
 
     pub fn to_ignore_crate_type_globs(&self) -> Option<String> {
-        if let Some(file_name) = self.file_name() {
+        if *self == FileType::License {
+            Some(format!(
+                "{:}:{:}",
+                self.ignore_crate_type_name(),
+                LICENSE_FILE_GLOB
+            ))
+        } else if let Some(file_name) = self.file_name() {
             Some(format!(
                 "{:}:{:}",
                 self.ignore_crate_type_name(),
@@ -235,14 +268,10 @@ impl FileType {
 impl std::fmt::Display for FileType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{:}", self.name())?;
-        if self.is_file_name() {
-            write!(f, " ({:?})", self.file_name().unwrap())?;
-        } else {
-            write!(
-                f,
-                " (*.{:})",
-                self.extension().unwrap_or_default().to_string_lossy()
-            )?;
+        if let Some(file_name) = self.file_name() {
+            write!(f, " ({:?})", file_name)?;
+        } else if let Some(extension) = self.extension() {
+            write!(f, " (*.{:})", extension.to_string_lossy())?;
         };
         let globs = self.to_ignore_crate_type_globs();
         if let Some(globs) = globs {
@@ -260,6 +289,31 @@ impl From<&Path> for &'static FileType {
     fn from(path: &Path) -> Self { FileType::from_path(path).unwrap() }
 }
 
+/// Returns true if the given path is the path of a license file such as
+/// `LICENSE`, `LICENSE.md`, `LICENSE-APACHE` or `COPYING`.
+fn is_license_file_name(path: &Path) -> bool {
+    let Some(file_name) = path.file_name().and_then(|n| n.to_str()) else {
+        return false;
+    };
+    let file_name = file_name.to_lowercase();
+    let (stem, extension) = match file_name.rsplit_once('.') {
+        Some((stem, extension))
+            if LICENSE_FILE_EXTENSIONS.contains(&extension) =>
+        {
+            (stem, Some(extension))
+        },
+        _ => (file_name.as_str(), None),
+    };
+    if extension.is_none() && stem.contains('.') {
+        return false;
+    }
+    LICENSE_FILE_STEMS.iter().any(|license_stem| {
+        stem.strip_prefix(license_stem).is_some_and(|rest| {
+            rest.is_empty() || rest.starts_with('-') || rest.starts_with('_')
+        })
+    })
+}
+
 static FILE_TYPE_MAP: LazyLock<HashMap<&'static OsStr, &'static FileType>> =
     LazyLock::new(|| {
         let mut map = HashMap::new();
@@ -0,0 +1,175 @@
+/// A text in a language, like a label with a language tag.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LocalizedText {
+    pub value:    String,
+    /// The language tag of the text, like `en` or `nl-BE`, if any
+    pub language: Option<String>,
+}
+
+/// Something with a language tag, see `Languages::pick`.
+pub trait Localized {
+    fn language(&self) -> Option<&str>;
+}
+
+impl Localized for LocalizedText {
+    fn language(&self) -> Option<&str> { self.language.as_deref() }
+}
+
+impl<T: Localized> Localized for &T {
+    fn language(&self) -> Option<&str> { (*self).language() }
+}
+
+/// The languages that the documentation is written in, in order of
+/// preference: a label in the first language is shown if there is
+/// one, otherwise a label in the second language and so on.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Languages {
+    preferred:         Vec<String>,
+    /// Whether to generate one book per preferred language instead of
+    /// one book in the first language that each text is available in
+    book_per_language: bool,
+}
+
+impl Languages {
+    pub fn new(preferred: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            preferred:         preferred
+                .into_iter()
+                .map(|language| language.into().to_lowercase())
+                .collect(),
+            book_per_language: false,
+        }
+    }
+
+    pub fn with_book_per_language(mut self, book_per_language: bool) -> Self {
+        self.book_per_language = book_per_language;
+        self
+    }
+
+    pub fn preferred(&self) -> &[String] { &self.preferred }
+
+    pub fn primary(&self) -> Option<&str> {
+        self.preferred.first().map(String::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool { self.preferred.is_empty() }
+
+    pub fn book_per_language(&self) -> bool { self.book_per_language }
+
+    /// Returns the languages of the books to generate: one book per
+    /// preferred language, falling back to the other languages in
+    /// order, if `book_per_language` is set and otherwise one book in
+    /// these languages.
+    pub fn books(&self) -> Vec<Languages> {
+        if !self.book_per_language || self.preferred.len() < 2 {
+            return vec![self.clone()];
+        }
+        self.preferred
+            .iter()
+            .map(|primary| {
+                let mut preferred = vec![primary.clone()];
+                preferred.extend(
+                    self.preferred.iter().filter(|l| *l != primary).cloned(),
+                );
+                Languages { preferred, book_per_language: true }
+            })
+            .collect()
+    }
+
+    /// Returns the languages that every text should be available in:
+    /// the language of the book if there is one book per language and
+    /// otherwise all preferred languages.
+    pub fn required(&self) -> Languages {
+        if self.book_per_language {
+            Languages::new(self.primary())
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Returns whether the given language tag is in the given
+    /// language, like `en-GB` in `en`.
+    pub fn matches(tag: &str, language: &str) -> bool {
+        tag.eq_ignore_ascii_case(language) ||
+            tag.as_bytes().get(language.len()) == Some(&b'-') &&
+                tag.get(..language.len()).is_some_and(|prefix| {
+                    prefix.eq_ignore_ascii_case(language)
+                })
+    }
+
+    /// Returns the position of the given language tag in the order of
+    /// preference: the preferred languages first, then texts without a
+    /// language tag and then all other languages.
+    fn rank(&self, language: Option<&str>) -> usize {
+        match language {
+            Some(tag) => {
+                self.preferred
+                    .iter()
+                    .position(|language| Self::matches(tag, language))
+                    .unwrap_or(self.preferred.len() + 1)
+            },
+            None => self.preferred.len(),
+        }
+    }
+
+    /// Returns the item in the most preferred language, the first one
+    /// of the items in that language.
+    pub fn pick<'a, T: Localized>(&self, items: &'a [T]) -> Option<&'a T> {
+        items
+            .iter()
+            .enumerate()
+            .min_by_key(|(i, item)| (self.rank(item.language()), *i))
+            .map(|(_, item)| item)
+    }
+
+    /// Returns the preferred languages that none of the given language
+    /// tags is in.
+    pub fn missing(&self, tags: &[&str]) -> Vec<String> {
+        self.preferred
+            .iter()
+            .filter(|language| {
+                !tags.iter().any(|tag| Self::matches(tag, language))
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(value: &str, language: Option<&str>) -> LocalizedText {
+        LocalizedText {
+            value:    value.to_string(),
+            language: language.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_pick() {
+        let labels = [
+            text("Car", Some("en-GB")),
+            text("Voiture", Some("fr")),
+            text("Auto", Some("nl")),
+            text("car", None),
+        ];
+        let pick = |preferred: &[&str]| {
+            Languages::new(preferred.iter().copied())
+                .pick(&labels)
+                .map(|label| label.value.clone())
+        };
+        assert_eq!(pick(&["nl", "en"]), Some("Auto".to_string()));
+        assert_eq!(pick(&["de", "fr"]), Some("Voiture".to_string()));
+        assert_eq!(pick(&["EN"]), Some("Car".to_string()));
+        // Without a preferred language the untagged label wins
+        assert_eq!(pick(&["de"]), Some("car".to_string()));
+        assert_eq!(pick(&[]), Some("car".to_string()));
+
+        let languages = Languages::new(["en", "nl", "fr"]);
+        assert_eq!(languages.missing(&["en-GB", "fr"]), ["nl"]);
+        assert_eq!(languages.books().len(), 1);
+        let books = languages.with_book_per_language(true).books();
+        assert_eq!(books[1].preferred(), ["nl", "en", "fr"]);
+    }
+}
@@ -0,0 +1,118 @@
+use {
+    oxrdfio::RdfFormat,
+    pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag, TagEnd},
+};
+
+/// A fenced code block of a Markdown file, like
+///
+/// ````markdown
+/// ```turtle
+/// :a :b :c .
+/// ```
+/// ````
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarkdownCodeBlock {
+    /// The first word of the info string, like `turtle` or `sparql`
+    pub language:   String,
+    /// The other words of the info string, like `run` in
+    /// ```` ```sparql run ````
+    pub attributes: Vec<String>,
+    pub code:       String,
+    /// The (1-based) line number of the opening fence
+    pub line:       usize,
+}
+
+impl MarkdownCodeBlock {
+    /// Returns the fenced code blocks of the given Markdown, in the
+    /// order in which they occur.
+    pub fn extract(markdown: &str) -> Vec<MarkdownCodeBlock> {
+        let options =
+            Options::ENABLE_TABLES | Options::ENABLE_YAML_STYLE_METADATA_BLOCKS;
+        let mut blocks = Vec::new();
+        let mut current: Option<MarkdownCodeBlock> = None;
+        for (event, range) in
+            Parser::new_ext(markdown, options).into_offset_iter()
+        {
+            match event {
+                Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info))) => {
+                    let mut words = info
+                        .split(|c: char| c.is_whitespace() || c == ',')
+                        .filter(|word| !word.is_empty())
+                        .map(str::to_string);
+                    current = Some(MarkdownCodeBlock {
+                        language:   words.next().unwrap_or_default(),
+                        attributes: words.collect(),
+                        code:       String::new(),
+                        line:       markdown[..range.start]
+                            .matches('\n')
+                            .count() +
+                            1,
+                    });
+                },
+                Event::Text(text) => {
+                    if let Some(block) = current.as_mut() {
+                        block.code.push_str(&text);
+                    }
+                },
+                Event::End(TagEnd::CodeBlock) => {
+                    blocks.extend(current.take());
+                },
+                _ => {},
+            }
+        }
+        blocks
+    }
+
+    /// Returns the RDF format of the code block, if it is written in
+    /// Turtle, TriG, JSON-LD, N-Triples, N-Quads, N3 or RDF/XML.
+    pub fn rdf_format(&self) -> Option<RdfFormat> { rdf_format(&self.language) }
+
+    /// Returns true if the code block is a SPARQL query.
+    pub fn is_sparql(&self) -> bool {
+        self.language.eq_ignore_ascii_case("sparql") ||
+            self.language.eq_ignore_ascii_case("rq")
+    }
+
+    /// Returns true if the code block is a SPARQL query that should be
+    /// executed, which is marked with a `run` attribute like in
+    /// ```` ```sparql run ````.
+    pub fn runs(&self) -> bool {
+        self.is_sparql() && self.attributes.iter().any(|a| a == "run")
+    }
+}
+
+/// Returns the RDF format for the given language of a code block.
+fn rdf_format(language: &str) -> Option<RdfFormat> {
+    let language = language.to_ascii_lowercase();
+    let extension = match language.as_str() {
+        "turtle" => "ttl",
+        "json-ld" => "jsonld",
+        "ntriples" | "n-triples" => "nt",
+        "nquads" | "n-quads" => "nq",
+        "rdf/xml" | "rdfxml" | "rdf-xml" => "rdf",
+        "ttl" | "trig" | "jsonld" | "nt" | "nq" | "n3" => language.as_str(),
+        _ => return None,
+    };
+    RdfFormat::from_extension(extension)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract() {
+        let blocks = MarkdownCodeBlock::extract(
+            "# Example\n\n```turtle\n:a :b :c .\n```\n\nText\n\n```sparql \
+             run\nSELECT * WHERE { ?s ?p ?o }\n```\n\n    indented\n",
+        );
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].language, "turtle");
+        assert_eq!(blocks[0].code, ":a :b :c .\n");
+        assert_eq!(blocks[0].line, 3);
+        assert_eq!(blocks[0].rdf_format(), Some(RdfFormat::Turtle));
+        assert_eq!(blocks[1].line, 9);
+        assert!(blocks[1].runs());
+        assert_eq!(blocks[1].rdf_format(), None);
+    }
+}
@@ -1,14 +1,22 @@
 mod file;
 mod file_type;
+mod git;
+mod language;
+mod markdown_code;
 mod paths;
 mod rdf_load;
+mod spdx;
 mod tracing;
 
 pub use {
     file::contents_of_local_file,
     file_type::{FileType, FileTypeSlice, FileTypeSliceStatic},
+    git::GitWebRepository,
+    language::{Languages, Localized, LocalizedText},
+    markdown_code::MarkdownCodeBlock,
     paths::{derive_root_directory, relative_path},
     rdf_load::rdf_load,
+    spdx::SPDXLicense,
     tracing::setup_tracing,
 };
 use {oxigraph::model::NamedNode, oxrdf::NamedNodeRef, uuid::Uuid};
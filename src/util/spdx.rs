@@ -0,0 +1,310 @@
+/// A license from the [SPDX License List](https://spdx.org/licenses/)
+/// that GraphArch can recognize, either by the text of a license file
+/// or by a reference to it such as `https://spdx.org/licenses/MIT` or
+/// `http://www.apache.org/licenses/LICENSE-2.0`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SPDXLicense {
+    /// The SPDX license identifier, like `Apache-2.0`
+    pub id:   &'static str,
+    /// The full name of the license
+    pub name: &'static str,
+    /// Phrases (normalized, see `normalize`) that all occur in the
+    /// text of the license and, together, in no license that comes
+    /// before it in `SPDX_LICENSES`.
+    phrases:  &'static [&'static str],
+}
+
+/// The recognized licenses, the more specific ones first since some
+/// license texts contain phrases of others. The GNU licenses for
+/// instance refer to each other, but only their own title is
+/// followed by their own version.
+static SPDX_LICENSES: &[SPDXLicense] = &[
+    SPDXLicense {
+        id:      "AGPL-3.0-only",
+        name:    "GNU Affero General Public License v3.0",
+        phrases: &["gnu affero general public license version 3"],
+    },
+    SPDXLicense {
+        id:      "LGPL-3.0-only",
+        name:    "GNU Lesser General Public License v3.0",
+        phrases: &["gnu lesser general public license version 3"],
+    },
+    SPDXLicense {
+        id:      "LGPL-2.1-only",
+        name:    "GNU Lesser General Public License v2.1",
+        phrases: &["gnu lesser general public license version 2.1"],
+    },
+    SPDXLicense {
+        id:      "GPL-3.0-only",
+        name:    "GNU General Public License v3.0",
+        phrases: &["gnu general public license version 3"],
+    },
+    SPDXLicense {
+        id:      "GPL-2.0-only",
+        name:    "GNU General Public License v2.0",
+        phrases: &["gnu general public license version 2"],
+    },
+    SPDXLicense {
+        id:      "MPL-2.0",
+        name:    "Mozilla Public License 2.0",
+        phrases: &["mozilla public license version 2.0"],
+    },
+    SPDXLicense {
+        id:      "Apache-2.0",
+        name:    "Apache License 2.0",
+        phrases: &["apache license", "version 2.0"],
+    },
+    SPDXLicense {
+        id:      "EPL-2.0",
+        name:    "Eclipse Public License 2.0",
+        phrases: &["eclipse public license v 2.0"],
+    },
+    SPDXLicense {
+        id:      "BSL-1.0",
+        name:    "Boost Software License 1.0",
+        phrases: &["boost software license version 1.0"],
+    },
+    SPDXLicense {
+        id:      "Unlicense",
+        name:    "The Unlicense",
+        phrases: &["this is free and unencumbered software released into the \
+                    public domain"],
+    },
+    SPDXLicense {
+        id:      "CC0-1.0",
+        name:    "Creative Commons Zero v1.0 Universal",
+        phrases: &["cc0 1.0 universal"],
+    },
+    SPDXLicense {
+        id:      "CC-BY-SA-4.0",
+        name:    "Creative Commons Attribution Share Alike 4.0 International",
+        phrases: &["attribution sharealike 4.0 international"],
+    },
+    SPDXLicense {
+        id:      "CC-BY-4.0",
+        name:    "Creative Commons Attribution 4.0 International",
+        phrases: &["attribution 4.0 international"],
+    },
+    SPDXLicense {
+        id:      "ISC",
+        name:    "ISC License",
+        phrases: &["permission to use copy modify and or distribute this \
+                    software for any purpose with or without fee is hereby \
+                    granted"],
+    },
+    SPDXLicense {
+        id:      "MIT",
+        name:    "MIT License",
+        phrases: &["permission is hereby granted free of charge to any \
+                    person obtaining a copy"],
+    },
+    SPDXLicense {
+        id:      "BSD-3-Clause",
+        name:    "BSD 3-Clause \"New\" or \"Revised\" License",
+        phrases: &[
+            "redistribution and use in source and binary forms",
+            "neither the name",
+        ],
+    },
+    SPDXLicense {
+        id:      "BSD-2-Clause",
+        name:    "BSD 2-Clause \"Simplified\" License",
+        phrases: &["redistribution and use in source and binary forms"],
+    },
+];
+
+impl SPDXLicense {
+    /// Identifies the license by the full text of a license file.
+    pub fn from_text(text: &str) -> Option<&'static SPDXLicense> {
+        let text = normalize(text);
+        SPDX_LICENSES.iter().find(|license| {
+            license.phrases.iter().all(|phrase| text.contains(phrase))
+        })
+    }
+
+    /// Identifies the license by a reference to it, as found in the
+    /// object of a `dcterms:license` or `cc:license` triple. This can be
+    /// an SPDX identifier or name, or the URL of the license at SPDX,
+    /// the Open Source Initiative, Creative Commons, Apache or GNU.
+    pub fn from_reference(reference: &str) -> Option<&'static SPDXLicense> {
+        let reference = reference.trim().trim_end_matches('/');
+        let lower = reference.to_lowercase();
+        let path = lower
+            .split_once("://")
+            .map(|(_, rest)| rest.trim_start_matches("www."));
+        let id = match path {
+            Some(path) => {
+                if let Some(rest) = path.strip_prefix("spdx.org/licenses/") {
+                    strip_file_extension(rest).to_string()
+                } else if let Some(rest) =
+                    path.strip_prefix("opensource.org/licenses/")
+                {
+                    strip_file_extension(rest)
+                        .trim_end_matches("-license")
+                        .to_string()
+                } else if path.starts_with("apache.org/licenses/license-2.0") {
+                    "apache-2.0".to_string()
+                } else if path
+                    .starts_with("creativecommons.org/publicdomain/zero/1.0")
+                {
+                    "cc0-1.0".to_string()
+                } else if let Some(rest) =
+                    path.strip_prefix("creativecommons.org/licenses/")
+                {
+                    // Like `by-sa/4.0/legalcode`
+                    let mut parts = rest.split('/');
+                    match (parts.next(), parts.next()) {
+                        (Some(code), Some(version)) => {
+                            format!("cc-{}-{}", code, version)
+                        },
+                        _ => return None,
+                    }
+                } else {
+                    let rest = path.strip_prefix("gnu.org/licenses/")?;
+                    strip_file_extension(rest).to_string()
+                }
+            },
+            None => lower.clone(),
+        };
+        let id = family(&id);
+        SPDX_LICENSES.iter().find(|license| {
+            family(&license.id.to_lowercase()) == id ||
+                license.name.to_lowercase() == lower
+        })
+    }
+
+    /// Returns the URL of the license at SPDX.
+    pub fn url(&self) -> String {
+        format!("https://spdx.org/licenses/{}.html", self.id)
+    }
+}
+
+impl std::fmt::Display for SPDXLicense {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.name, self.id)
+    }
+}
+
+/// Lower cases the given text and replaces all punctuation and
+/// whitespace by single spaces, except for the dots in version numbers
+/// like `2.0`.
+fn normalize(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut normalized = String::with_capacity(text.len());
+    for (i, c) in chars.iter().enumerate() {
+        let in_number = *c == '.' &&
+            i > 0 &&
+            chars[i - 1].is_ascii_digit() &&
+            chars.get(i + 1).is_some_and(|c| c.is_ascii_digit());
+        if c.is_alphanumeric() || in_number {
+            normalized.extend(c.to_lowercase());
+        } else if !normalized.is_empty() && !normalized.ends_with(' ') {
+            normalized.push(' ');
+        }
+    }
+    normalized.trim_end().to_string()
+}
+
+/// Strips extensions like `.html` or `.json` from the last segment of
+/// a license URL.
+fn strip_file_extension(segment: &str) -> &str {
+    [".en.html", ".html", ".json", ".txt", ".php"]
+        .iter()
+        .find_map(|extension| segment.strip_suffix(extension))
+        .unwrap_or(segment)
+}
+
+/// Returns the given lower case license identifier without the
+/// `-only`, `-or-later` or `+` suffix, since the text of a license
+/// file does not tell which of them applies.
+fn family(id: &str) -> &str {
+    id.trim_end_matches('+')
+        .trim_end_matches("-only")
+        .trim_end_matches("-or-later")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_text() {
+        let mit = "MIT License\n\nCopyright (c) 2024 OMG\n\nPermission is \
+                   hereby granted, free of charge, to any person obtaining a \
+                   copy\nof this software";
+        assert_eq!(
+            SPDXLicense::from_text(mit).map(|l| l.id),
+            Some("MIT")
+        );
+
+        let lgpl = "GNU LESSER GENERAL PUBLIC LICENSE\n   Version 3, 29 June \
+                    2007\n\nThis version of the GNU Lesser General Public \
+                    License incorporates\nthe terms and conditions of version \
+                    3 of the GNU General\nPublic License";
+        assert_eq!(
+            SPDXLicense::from_text(lgpl).map(|l| l.id),
+            Some("LGPL-3.0-only")
+        );
+
+        let bsd2 = "Redistribution and use in source and binary forms, with \
+                    or without\nmodification, are permitted";
+        assert_eq!(
+            SPDXLicense::from_text(bsd2).map(|l| l.id),
+            Some("BSD-2-Clause")
+        );
+        let bsd3 = format!("{bsd2}\n3. Neither the name of the copyright");
+        assert_eq!(
+            SPDXLicense::from_text(&bsd3).map(|l| l.id),
+            Some("BSD-3-Clause")
+        );
+
+        assert_eq!(
+            SPDXLicense::from_text("All rights reserved."),
+            None
+        );
+    }
+
+    #[test]
+    fn test_from_reference() {
+        for (reference, id) in [
+            ("https://spdx.org/licenses/MIT.html", "MIT"),
+            ("MIT", "MIT"),
+            ("Apache License 2.0", "Apache-2.0"),
+            (
+                "http://www.apache.org/licenses/LICENSE-2.0",
+                "Apache-2.0",
+            ),
+            (
+                "https://opensource.org/licenses/mit-license.php",
+                "MIT",
+            ),
+            (
+                "https://creativecommons.org/licenses/by/4.0/",
+                "CC-BY-4.0",
+            ),
+            (
+                "http://creativecommons.org/licenses/by-sa/4.0/legalcode",
+                "CC-BY-SA-4.0",
+            ),
+            (
+                "http://creativecommons.org/publicdomain/zero/1.0/",
+                "CC0-1.0",
+            ),
+            ("GPL-3.0-or-later", "GPL-3.0-only"),
+            (
+                "https://www.gnu.org/licenses/gpl-3.0.html",
+                "GPL-3.0-only",
+            ),
+        ] {
+            assert_eq!(
+                SPDXLicense::from_reference(reference).map(|l| l.id),
+                Some(id),
+                "{reference}"
+            );
+        }
+        assert_eq!(
+            SPDXLicense::from_reference("https://example.com/license"),
+            None
+        );
+    }
+}